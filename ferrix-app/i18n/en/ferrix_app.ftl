@@ -5,11 +5,14 @@
 sidebar-export = Export
 sidebar-settings = Settings
 sidebar-about = About
+sidebar-refresh = Refresh now
 sidebar-basic = Basic
 sidebar-hardware = Hardware
 sidebar-admin = Administration
 sidebar-system = System
 sidebar-manage = Management
+sidebar-search = Search pages...
+sidebar-not-found = Nothing found
 
 # PAGES
 page-dashboard = Dashboard
@@ -47,6 +50,7 @@ about-flib = ferrix-lib version
 about-author-hdr = Author:
 about-feedback-hdr = Feedback:
 about-source-hdr = Source code:
+about-license-hdr = License:
 about-blog = Blog:
 about-author = (C) 2025, 2026 Michail Krasnov
 about-donate = Can you support me?
@@ -75,11 +79,16 @@ bat-lvl-cri = Critical!
 bat-lvl-non = None
 bat-lvl-unk = Unknown ({$lbl})
 bat-health = Health level, %
+bat-charge-start = Start charging at
+bat-charge-stop = Stop charging at
+bat-charge-limit = Limit charging to
+bat-charge-limit-error = Failed to set the charge limit: {$error}
 bat-tech = Technology
 bat-cycle-cnt = Cycle count
 bat-volt-min-des = Minimal designed voltage, V
 bat-volt-now = Current voltage, V
 bat-power-now = Current power
+bat-remaining = ~{$time} remaining
 bat-energy-full-des = Full designed energy, Wh
 bat-energy-full = Full energy, Wh
 bat-energy-now = Current energy, Wh
@@ -87,6 +96,8 @@ bat-model = Battery model
 bat-manufact = Manufacturer
 bat-serial = Serial number
 bat-not-found = There are no connected batteries
+bat-on-ac = Powered from the mains ({$adapter})
+bat-on-bat = Running on battery
 
 # TABLE HEADERS
 hdr-param = Parameter
@@ -96,6 +107,15 @@ hdr-value = Value
 bool-true = YES
 bool-false = NO
 
+# Missing values
+missing-not-supported = not supported
+missing-permission-denied = requires root
+missing-not-present = not present
+
+# Table filter
+table-filter = Filter...
+table-filter-empty = Nothing found
+
 # LOADING PAGE
 ldr-page-tooltip = Loading data...
 
@@ -106,6 +126,7 @@ err-page-tooltip = Data loading error!
 cpu-vendor = Vendor
 cpu-family = Family
 cpu-model = Model
+cpu-model-id = Model ID
 cpu-stepping = Stepping
 cpu-microcode = Microcode
 cpu-freq = Frequency
@@ -133,7 +154,9 @@ cpu-arch = Architecture
 cpu-var = Variant
 cpu-part = Part
 cpu-rev = Revision
+cpu-isa = Instruction set extensions
 cpu-see-freq = See "CPU Frequencies" page
+cpu-freq-chart = Current frequency of CPU cores
 
 # CPU FREQUENCY PAGE
 cpufreq-tboost = CPU Turbo Boost support
@@ -150,10 +173,13 @@ cpufreq-scaling_max = Scaling max.
 cpufreq-scaling_cur = Current Frequency
 cpufreq-scaling_gov = Governor
 cpufreq-avail_gov = Available Governors
+cpufreq-set-gov = Set governor
+cpufreq-set-gov-error = Failed to set the frequency governor: {$error}
 cpufreq-avail_freq = Available Frequencies
 cpufreq-scaling_drv = Scaling Driver
 cpufreq-trans_lat = Transition Latency
 cpufreq-set_speed = Set Speed
+cpufreq-affected = Affected CPUs
 cpufreq-policy = Frequency Policy for CPU #{$cpu}
 
 # DASHBOARD PAGE
@@ -166,9 +192,12 @@ dash-mem-used = Used: {$used}
 dash-mem-total = Total: {$total}
 dash-proc-usage = CPU Usage
 dash-proc-usg_label = Total usage: {$usage}%
+dash-load = Load Average
+dash-load-info = {$load} ({$ratio}% of CPUs)
 dash-swap = Swap
 dash-bat = Battery
 dash-unk-bat = No name
+dash-power = Power source
 dash-root-part = Root Partition
 dash-home-part = Home Partition
 dash-unk-part = Unknown Partition
@@ -192,22 +221,39 @@ distro-privacy-policy = Privacy policy
 distro-logo = Logo
 distro-def-host = Default hostname
 distro-sysext-lvl = System extensions level
+distro-ansi-color = Console color (ANSI)
+distro-boot-hdr = Boot time
+distro-boot-firmware = Firmware
+distro-boot-loader = Boot loader
+distro-boot-kernel = Kernel
+distro-boot-initrd = Initrd
+distro-boot-userspace = Userspace
+distro-boot-total = Total
+distro-boot-not-finished = Boot isn't finished yet
+distro-boot-error = Boot time is unavailable: {$error}
 
 # DRM PAGE
 drm-title = Screen #{$idx}
+drm-gpu-title = Video card {$name}
+drm-gpu-driver = Driver
+drm-gpu-pci-id = PCI ID
+drm-gpu-vram = Video memory
 drm-summary = Summary
 drm-vparams = Video params
 drm-edid-not-found = EDID data for screen #{$idx} not found!
 drm-not-enabled = Screen #{$idx} isn't enabled!
 drm-modes = Support modes
 drm-mode = Mode
+drm-current-mode = Current mode
 drm-manufacturer = Manufacturer
+drm-model = Model
 drm-pcode = Product code
 drm-snum = Serial number
 drm-date = Week/Year
 drm-edid-ver = EDID Version
 drm-edid-rev = EDID Revision
 drm-size = Screen size, cm
+drm-size-mm = Image size, mm
 drm-gamma = Display gamma (default)
 drm-signal = Signal type
 drm-digital = Digital
@@ -226,14 +272,21 @@ groups-members = Group members
 kmod-name = Name
 kmod-size = Size
 kmod-instances = Inst.
-kmod-depends = Dependencies
+kmod-used-by = Used by
 kmod-state = State
 kmod-addrs = Addresses
+kmod-params = Parameters of the {$name} module
+kmod-params-close = Close
+kmod-params-empty = The module has no parameters
+kmod-filter = Filter modules by name...
 kernel-summary = Summary
 kernel-cmdline = Command line
 kernel-arch = Architecture
 kernel-version = Version
 kernel-build = Build
+kernel-build-date = Build date
+kernel-compiler = Compiler
+kernel-config = Kernel config available
 kernel-pid-max = Processes, max.
 kernel-threads-max = Threads, max.
 kernel-user-evs = User events, max.
@@ -276,31 +329,76 @@ ram-nfs-unstable = NFS Unstable
 ram-bounce = Bounce buffers
 ram-writeback-tmp = Temporary buffers (for FUSE)
 ram-commit-limit = Commit limit (max.)
+ram-advanced = Advanced (huge pages)
+ram-hp-total = Huge pages (total)
+ram-hp-free = Huge pages (free)
+ram-hp-rsvd = Huge pages (reserved)
+ram-hp-surp = Huge pages (surplus)
+ram-hp-size = Huge page size
+ram-hp-tlb = Memory used by huge pages (HugeTLB)
+ram-thp-mode = Transparent huge pages mode
+ram-thp-anon = Anonymous transparent huge pages
+ram-thp-shmem = Shmem/tmpfs huge pages
+ram-thp-file = File huge pages
 ram-swp = Swap {$name}
 ram-swp-size = Total size
 ram-swp-used = Used
 ram-swp-prior = Priority
+ram-swp-kind = Type
+ram-swp-partition = Partition
+ram-swp-file = File
+ram-swp-zram = zram (compressed in RAM)
+ram-swp-unknown = Unknown
 ram-hdr = RAM Info
 ram-swp-hdr = Swaps Info
 ram-swp-not-found = No swaps files/partitions found.
+ram-zram-hdr = zram Devices
+ram-zram-alg = Compression algorithm
+ram-zram-disksize = Disk size
+ram-zram-orig = Uncompressed data
+ram-zram-compr = Compressed data
+ram-zram-mem-used = Memory used
+ram-zram-ratio = Compression ratio
 
 # SETTINGS PAGE
 settings-update-period = Update period
 settings-uperiod-tip = Specify the data update period (in secs). The higher the update period, the lower the load on the PC.
-settings-uper-main = Main data
+settings-uper-main = Hardware
+settings-uper-system = System information
+settings-uper-storage = File systems
+settings-uper-services = Services
 settings-look = Look and feel
 settings-look-tip = The design style affects the interface and font colors. Choose what you like.
 settings-look-thick = Chart line thickness, px.
+settings-temp-unit = Temperature unit
+settings-byte-unit = Size units
+settings-font-size = Text size, px. (after restart)
 settings-look-select = Style
+settings-lang = Language
+settings-lang-system = System
+settings-lang-error = Failed to change language: {$error}
 settings-save = Save
+settings-save-error = Failed to save settings: {$error}
 
 # STORAGES PAGE
 storage-dev = Device
 storage-fs = Filesystem
+storage-mnt = Mount point
 storage-total = Total
 storage-free = Free
 storage-used = Used
 storage-usage = Usage
+storage-inodes = Inodes: {$free} free of {$total}
+storage-ro = (read-only)
+storage-io-hdr = Disk I/O
+storage-health-hdr = Disk health
+storage-health-ok = SMART: passed
+storage-health-failing = SMART: the drive is failing!
+storage-health-unknown = SMART data is unavailable (is smartctl installed?)
+storage-temp = Temperature
+storage-power-on = Power-on hours
+storage-realloc = Reallocated sectors
+storage-wearout = Wearout
 
 # STYLE LABELS
 style-dark = Dark
@@ -311,16 +409,44 @@ misc-hostname = Host name
 misc-loadavg = Load average
 misc-uptime = Uptime
 misc-uptime-val = uptime: {$up}, downtime: {$down}
+misc-boot-time = Boot time
+misc-timezone = Timezone
+misc-rtc = Hardware clock
+misc-rtc-utc = UTC
+misc-rtc-local = Local time
+misc-sysctl = Kernel parameters
 misc-de = Desktop
 misc-lang = Language
+misc-boot-params = Boot parameters
 
 # SYSTEM MONITOR PAGE
 sysmon-x-axis = Number of counts on the X-axis:
 sysmon-toggle = Show legend
+sysmon-pause = ⏸ Pause
+sysmon-resume = ▶ Resume
+sysmon-save-png = Save as PNG
 sysmon-cpu-hdr = CPU Usage
 sysmon-ram-hdr = RAM Usage
+sysmon-net-hdr = Network Traffic
+sysmon-top-cpu-hdr = Top Processes by CPU Time
+sysmon-top-mem-hdr = Top Processes by Memory
+sysmon-top-pid = PID
+sysmon-top-name = Name
+sysmon-top-user = User
+sysmon-top-cpu = CPU Time
+sysmon-top-rss = Memory
+sysmon-top-loading = Loading the processes list...
+sysmon-top-error = Failed to get the processes list: {$error}
+sysmon-ram-breakdown = Breakdown of used RAM
+sysmon-ram-apps = Apps
+sysmon-ram-buffers = Buffers
+sysmon-ram-cache = Cache
 sysmon-cpu-unk = CPU usage statistics are unknown!
 sysmon-cpu-brk = CPU usage statistics are broken!
+sysmon-ctxt = Context switches: {$rate}/s
+sysmon-intr = Interrupts: {$rate}/s
+sysmon-forks = New processes: {$rate}/s
+sysmon-run-queue = Run queue: {$running} running, {$blocked} blocked
 
 # SYSTEMD PAGE
 sysd-hdr-name = Name
@@ -328,14 +454,20 @@ sysd-hdr-descr = Description
 sysd-hdr-load = Loaded
 sysd-hdr-actv = Active
 sysd-hdr-work = Work
+sysd-hdr-file = Unit file
 sysd-total = Total services: {$total}
+sysd-bus-unavailable = systemd bus not reachable: {$error}
+sysd-unit-file-error = Failed to read the unit file: {$error}
 
 # SOFTWARE PAGE
 soft-hdr-name = Name
 soft-hdr-ver = Version
 soft-hdr-arch = Arch
 soft-hdr-type = Type
+soft-hdr-size = Size
 soft-total = Total packages: {$total}
+soft-search = Search packages...
+soft-no-managers = No supported package managers found
 
 # USERS PAGE
 users-name = User name
@@ -345,11 +477,60 @@ users-gecos = GECOS
 users-home = Home directory
 users-shell = Login shell
 users-hdr = User #{$id}
+users-hdr-service = Service account #{$id}
+users-can-login = Can log in
+users-groups = Groups
+users-sessions = Active sessions
+users-sessions-empty = No active sessions
+users-last-logins = Last logins
+users-session-user = User
+users-session-tty = Terminal
+users-session-host = Host
+users-session-local = local
+users-session-time = Login time
+users-sort = Sort by:
 
 # CPU VULNERABILITY PAGE
 vuln-hdr-name = Name
 vuln-hdr-descr = Description
+vuln-affected = Unmitigated vulnerabilities: {$count}
+
+# SENSORS PAGE
+sensors-not-found = No hardware sensors found
+sensors-chip = {$name} ({$hwmon})
+sensors-unknown-name = <unknown chip>
+sensors-min = min
+sensors-max = max
+sensors-crit = crit
+
+# ENVIRONMENT PAGE
+env-filter = Filter variables...
+env-show-secrets = Show secret values
+
+# DEVELOPMENT PAGE
+dev-hdr-name = Program
+dev-hdr-version = Version
+dev-hdr-path = Path
+dev-not-installed = Not installed
+dev-unknown = Unknown
 
 # LINE THICKNESS LABELS
 lthick-one = One
 lthick-two = Two
+temp-celsius = Celsius (°C)
+temp-fahrenheit = Fahrenheit (°F)
+bytes-binary = Binary (KiB, MiB, GiB)
+bytes-decimal = Decimal (kB, MB, GB)
+
+# EXPORT MANAGER
+export-copied = Data copied to clipboard
+export-copy-error = Failed to serialize data: {$error}
+export-chart-saved = Chart saved to {$path}
+export-chart-error = Failed to save chart: {$error}
+export-save-error = Failed to save data: {$error}
+snapshot-open-error = Failed to open the snapshot: {$error}
+snapshot-missing-section = This section isn't stored in the snapshot
+
+# TOASTS
+toast-close = Close
+toast-open-link-error = Failed to open {$url}: {$error}
@@ -5,11 +5,14 @@
 sidebar-export = Экспорт
 sidebar-settings = Настройки
 sidebar-about = О программе
+sidebar-refresh = Обновить сейчас
 sidebar-basic = Основное
 sidebar-hardware = Оборудование
 sidebar-admin = Администрирование
 sidebar-system = Система
 sidebar-manage = Обслуживание
+sidebar-search = Поиск страниц...
+sidebar-not-found = Ничего не найдено
 
 # PAGES
 page-dashboard = Обзор
@@ -47,6 +50,7 @@ about-flib = Версия ferrix-lib
 about-author-hdr = Автор:
 about-feedback-hdr = Фидбек:
 about-source-hdr = Исходный код:
+about-license-hdr = Лицензия:
 about-blog = Блог:
 about-author = (C) 2025, 2026 Михаил Краснов
 about-donate = Вы можете отправить мне донат на карту: 2202 2062 5233 5406 (Сбер; Россия). Спасибо!
@@ -75,11 +79,16 @@ bat-lvl-cri = Критический заряд!
 bat-lvl-non = None
 bat-lvl-unk = Неизвестно ({$lbl})
 bat-health = Уровень здоровья, %
+bat-charge-start = Начинать зарядку при
+bat-charge-stop = Прекращать зарядку при
+bat-charge-limit = Ограничить зарядку до
+bat-charge-limit-error = Не удалось установить ограничение зарядки: {$error}
 bat-tech = Технология
 bat-cycle-cnt = Количество циклов
 bat-volt-min-des = Мин. проектное напряжение, В
 bat-volt-now = Текущее напряжение
 bat-power-now = Текущая мощность
+bat-remaining = осталось ~{$time}
 bat-energy-full-des = Полная проектная энергия, Вт/ч
 bat-energy-full = Полная энергия, Вт/ч
 bat-energy-now = Текущая энергия, Вт/ч
@@ -87,6 +96,8 @@ bat-model = Модель аккумулятора
 bat-manufact = Производитель
 bat-serial = Серийный номер
 bat-not-found = Отсутствуют подключенные аккумуляторы
+bat-on-ac = Питание от сети ({$adapter})
+bat-on-bat = Питание от аккумулятора
 
 # TABLE HEADERS
 hdr-param = Параметр
@@ -96,6 +107,15 @@ hdr-value = Значение
 bool-true = ДА
 bool-false = НЕТ
 
+# Missing values
+missing-not-supported = не поддерживается
+missing-permission-denied = требуются права root
+missing-not-present = отсутствует
+
+# Table filter
+table-filter = Фильтр...
+table-filter-empty = Ничего не найдено
+
 # LOADING PAGE
 ldr-page-tooltip = Загрузка данных...
 
@@ -106,6 +126,7 @@ err-page-tooltip = Ошибка загрузки данных!
 cpu-vendor = Производитель
 cpu-family = Семейство
 cpu-model = Модель
+cpu-model-id = ID модели
 cpu-stepping = Stepping
 cpu-microcode = Микрокод
 cpu-freq = Частота
@@ -133,7 +154,9 @@ cpu-arch = Архитектура
 cpu-var = Вариант
 cpu-part = Тип ядра
 cpu-rev = Ревизия
+cpu-isa = Расширения набора инструкций
 cpu-see-freq = См. страницу «Частоты ЦП»
+cpu-freq-chart = Текущая частота ядер ЦП
 
 # CPU FREQUENCY PAGE
 cpufreq-tboost = Поддержка CPU Turbo Boost
@@ -150,10 +173,13 @@ cpufreq-scaling_max = Мкс. текущая частота
 cpufreq-scaling_cur = Текущая частота
 cpufreq-scaling_gov = Регулятор
 cpufreq-avail_gov = Доступные регуляторы
+cpufreq-set-gov = Установить регулятор
+cpufreq-set-gov-error = Не удалось установить регулятор частоты: {$error}
 cpufreq-avail_freq = Доступные частоты
 cpufreq-scaling_drv = Драйвер
 cpufreq-trans_lat = Задержка переключения
 cpufreq-set_speed = Заданная частота
+cpufreq-affected = Управляемые ядра
 cpufreq-policy = Управление частотами ЦП №{$cpu}
 
 # DASHBOARD PAGE
@@ -166,9 +192,12 @@ dash-mem-used = Использ.: {$used}
 dash-mem-total = Всего: {$total}
 dash-proc-usage = Нагрузка на ЦП
 dash-proc-usg_label = Общая: {$usage}%
+dash-load = Средняя нагрузка
+dash-load-info = {$load} ({$ratio}% ЦП)
 dash-swap = Подкачка
 dash-bat = Аккумулятор
 dash-unk-bat = Без имени
+dash-power = Источник питания
 dash-root-part = Корневой раздел
 dash-home-part = Домашний раздел
 dash-unk-part = Неизвестный раздел
@@ -192,22 +221,39 @@ distro-privacy-policy = Политика конфиденциальности
 distro-logo = Логотип
 distro-def-host = Стандартное имя хоста
 distro-sysext-lvl = Уровень поддержки расширений
+distro-ansi-color = Цвет в консоли (ANSI)
+distro-boot-hdr = Время загрузки
+distro-boot-firmware = Прошивка
+distro-boot-loader = Загрузчик
+distro-boot-kernel = Ядро
+distro-boot-initrd = Initrd
+distro-boot-userspace = Пространство пользователя
+distro-boot-total = Всего
+distro-boot-not-finished = Загрузка ещё не завершена
+distro-boot-error = Время загрузки недоступно: {$error}
 
 # DRM PAGE
 drm-title = Экран №{$idx}
+drm-gpu-title = Видеокарта {$name}
+drm-gpu-driver = Драйвер
+drm-gpu-pci-id = PCI ID
+drm-gpu-vram = Видеопамять
 drm-summary = Общая информация
 drm-vparams = Параметры видео
 drm-edid-not-found = Данные EDID для экрана №{$idx} не существуют!
 drm-not-enabled = Экран №{$idx} выключен!
 drm-modes = Поддерживаемые разрешения
 drm-mode = Разрешение
+drm-current-mode = Текущий режим
 drm-manufacturer = Производитель
+drm-model = Модель
 drm-pcode = Код продукта
 drm-snum = Серийный номер
 drm-date = Неделя/Год
 drm-edid-ver = Версия EDID
 drm-edid-rev = Ревизия EDID
 drm-size = Размер экрана, см
+drm-size-mm = Размер изображения, мм
 drm-gamma = Гамма дисплея (стандартная)
 drm-signal = Тип сигнала
 drm-digital = Цифровой
@@ -226,14 +272,21 @@ groups-members = Члены группы
 kmod-name = Имя
 kmod-size = Размер
 kmod-instances = Экз.
-kmod-depends = Зависимости
+kmod-used-by = Используется
 kmod-state = Состояние
 kmod-addrs = Адреса
+kmod-params = Параметры модуля {$name}
+kmod-params-close = Закрыть
+kmod-params-empty = У модуля нет параметров
+kmod-filter = Фильтр модулей по имени...
 kernel-summary = Обзор
 kernel-cmdline = Командная строка
 kernel-arch = Архитектура
 kernel-version = Версия
 kernel-build = Сборка
+kernel-build-date = Дата сборки
+kernel-compiler = Компилятор
+kernel-config = Доступна конфигурация ядра
 kernel-pid-max = Макс. число процессов
 kernel-threads-max = Макс. число потоков
 kernel-user-evs = Макс. число user events
@@ -276,31 +329,76 @@ ram-nfs-unstable = Нестабильный NFS
 ram-bounce = Bounce буферы
 ram-writeback-tmp = Временные буферы (для FUSE)
 ram-commit-limit = Можно выделить (max.)
+ram-advanced = Дополнительно (большие страницы)
+ram-hp-total = Большие страницы (всего)
+ram-hp-free = Большие страницы (свободно)
+ram-hp-rsvd = Большие страницы (зарезервировано)
+ram-hp-surp = Большие страницы (сверх лимита)
+ram-hp-size = Размер большой страницы
+ram-hp-tlb = Память больших страниц (HugeTLB)
+ram-thp-mode = Режим прозрачных больших страниц
+ram-thp-anon = Анонимные прозрачные большие страницы
+ram-thp-shmem = Большие страницы shmem/tmpfs
+ram-thp-file = Файловые большие страницы
 ram-swp = Подкачка {$name}
 ram-swp-size = Объём
 ram-swp-used = Занятый объём
 ram-swp-prior = Приоритет
+ram-swp-kind = Тип
+ram-swp-partition = Раздел
+ram-swp-file = Файл
+ram-swp-zram = zram (сжатие в ОЗУ)
+ram-swp-unknown = Неизвестно
 ram-hdr = Оперативная память
 ram-swp-hdr = Подкачка
 ram-swp-not-found = Не найдены файлы/разделы подкачки.
+ram-zram-hdr = Устройства zram
+ram-zram-alg = Алгоритм сжатия
+ram-zram-disksize = Размер устройства
+ram-zram-orig = Несжатые данные
+ram-zram-compr = Сжатые данные
+ram-zram-mem-used = Использовано памяти
+ram-zram-ratio = Коэффициент сжатия
 
 # SETTINGS PAGE
 settings-update-period = Период обновления
 settings-uperiod-tip = Укажите период обновления данных (в сек.). Чем выше период обновления, тем ниже нагрузка на ПК.
-settings-uper-main = Основные данные
+settings-uper-main = Оборудование
+settings-uper-system = Системная информация
+settings-uper-storage = Файловые системы
+settings-uper-services = Службы
 settings-look = Оформление программы
 settings-look-tip = Стиль оформления влияет на цвета интерфейса и шрифта. Выберите то, что нравится вам.
 settings-look-thick = Толщина линий графика, пикс.
+settings-temp-unit = Единица температуры
+settings-byte-unit = Единицы размера
+settings-font-size = Размер текста, px. (после перезапуска)
 settings-look-select = Стиль оформления
+settings-lang = Язык
+settings-lang-system = Системный
+settings-lang-error = Не удалось сменить язык: {$error}
 settings-save = Сохранить
+settings-save-error = Не удалось сохранить настройки: {$error}
 
 # STORAGES PAGE
 storage-dev = Устройство
 storage-fs = ФС
+storage-mnt = Точка монтирования
 storage-total = Всего
 storage-free = Свободно
 storage-used = Использ.
 storage-usage = Использование
+storage-inodes = Inode: свободно {$free} из {$total}
+storage-ro = (только чтение)
+storage-io-hdr = Дисковый ввод/вывод
+storage-health-hdr = Состояние дисков
+storage-health-ok = SMART: в норме
+storage-health-failing = SMART: накопитель выходит из строя!
+storage-health-unknown = Данные SMART недоступны (установлен ли smartctl?)
+storage-temp = Температура
+storage-power-on = Отработано часов
+storage-realloc = Переназначенные сектора
+storage-wearout = Износ
 
 # STYLE LABELS
 style-dark = Тёмный
@@ -311,16 +409,44 @@ misc-hostname = Имя хоста
 misc-loadavg = Средняя нагрузка
 misc-uptime = Время работы
 misc-uptime-val = работы: {$up}, простоя: {$down}
+misc-boot-time = Время загрузки
+misc-timezone = Часовой пояс
+misc-rtc = Аппаратные часы
+misc-rtc-utc = UTC
+misc-rtc-local = Местное время
+misc-sysctl = Параметры ядра
 misc-de = Рабочее окружение
 misc-lang = Язык
+misc-boot-params = Параметры загрузки
 
 # SYSTEM MONITOR PAGE
 sysmon-x-axis = Число отсчётов по оси X:
 sysmon-toggle = Показывать легенду
+sysmon-pause = ⏸ Пауза
+sysmon-resume = ▶ Продолжить
+sysmon-save-png = Сохранить в PNG
 sysmon-cpu-hdr = Использование ЦП
 sysmon-ram-hdr = Использование ОЗУ
+sysmon-net-hdr = Сетевой трафик
+sysmon-top-cpu-hdr = Процессы с наибольшим временем ЦП
+sysmon-top-mem-hdr = Процессы с наибольшим потреблением памяти
+sysmon-top-pid = PID
+sysmon-top-name = Имя
+sysmon-top-user = Пользователь
+sysmon-top-cpu = Время ЦП
+sysmon-top-rss = Память
+sysmon-top-loading = Загрузка списка процессов...
+sysmon-top-error = Не удалось получить список процессов: {$error}
+sysmon-ram-breakdown = Структура занятой ОЗУ
+sysmon-ram-apps = Программы
+sysmon-ram-buffers = Буферы
+sysmon-ram-cache = Кэш
 sysmon-cpu-unk = Статистика использования ЦП неизвестна!
 sysmon-cpu-brk = Статистика использования ЦП повреждена!
+sysmon-ctxt = Переключения контекста: {$rate}/с
+sysmon-intr = Прерывания: {$rate}/с
+sysmon-forks = Новые процессы: {$rate}/с
+sysmon-run-queue = Очередь выполнения: {$running} выполняются, {$blocked} заблокированы
 
 # SYSTEMD PAGE
 sysd-hdr-name = Имя
@@ -328,14 +454,20 @@ sysd-hdr-descr = Описание
 sysd-hdr-load = Загружен
 sysd-hdr-actv = Активен
 sysd-hdr-work = Работает
+sysd-hdr-file = Юнит-файл
 sysd-total = Всего сервисов: {$total}
+sysd-bus-unavailable = Шина systemd недоступна: {$error}
+sysd-unit-file-error = Не удалось прочитать файл юнита: {$error}
 
 # SOFTWARE PAGE
 soft-hdr-name = Имя
 soft-hdr-ver = Версия
 soft-hdr-arch = Архитектура
 soft-hdr-type = Тип
+soft-hdr-size = Размер
 soft-total = Всего пакетов: {$total}
+soft-search = Поиск пакетов...
+soft-no-managers = Поддерживаемые пакетные менеджеры не найдены
 
 # USERS PAGE
 users-name = Имя пользователя
@@ -345,11 +477,60 @@ users-gecos = GECOS
 users-home = Домашний каталог
 users-shell = Оболочка входа
 users-hdr = Пользователь №{$id}
+users-hdr-service = Служебная учётная запись №{$id}
+users-can-login = Может входить в систему
+users-groups = Группы
+users-sessions = Активные сеансы
+users-sessions-empty = Нет активных сеансов
+users-last-logins = Последние входы
+users-session-user = Пользователь
+users-session-tty = Терминал
+users-session-host = Узел
+users-session-local = локальный
+users-session-time = Время входа
+users-sort = Сортировать по:
 
 # CPU VULNERABILITY PAGE
 vuln-hdr-name = Имя
 vuln-hdr-descr = Описание
+vuln-affected = Неустранённые уязвимости: {$count}
+
+# SENSORS PAGE
+sensors-not-found = Аппаратные датчики не найдены
+sensors-chip = {$name} ({$hwmon})
+sensors-unknown-name = <неизвестный чип>
+sensors-min = мин.
+sensors-max = макс.
+sensors-crit = крит.
+
+# ENVIRONMENT PAGE
+env-filter = Фильтр переменных...
+env-show-secrets = Показывать секретные значения
+
+# DEVELOPMENT PAGE
+dev-hdr-name = Программа
+dev-hdr-version = Версия
+dev-hdr-path = Путь
+dev-not-installed = Не установлена
+dev-unknown = Неизвестно
 
 # LINE THICKNESS LABELS
 lthick-one = Один
 lthick-two = Два
+temp-celsius = Цельсий (°C)
+temp-fahrenheit = Фаренгейт (°F)
+bytes-binary = Двоичные (КиБ, МиБ, ГиБ)
+bytes-decimal = Десятичные (кБ, МБ, ГБ)
+
+# EXPORT MANAGER
+export-copied = Данные скопированы в буфер обмена
+export-copy-error = Не удалось сериализовать данные: {$error}
+export-chart-saved = График сохранён в {$path}
+export-chart-error = Не удалось сохранить график: {$error}
+export-save-error = Не удалось сохранить данные: {$error}
+snapshot-open-error = Не удалось открыть снимок: {$error}
+snapshot-missing-section = Этот раздел не сохранён в снимке
+
+# TOASTS
+toast-close = Закрыть
+toast-open-link-error = Не удалось открыть {$url}: {$error}
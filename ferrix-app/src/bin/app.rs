@@ -18,19 +18,66 @@
  * SPDX-License-Identifier: GPL-3.0-or-later
  */
 
-use ferrix_app::ferrix::Ferrix;
+use anyhow::{Result, anyhow};
+use ferrix_app::{ferrix::Ferrix, settings::FXSettings};
+use ferrix_lib::traits::ToPlainText;
 use iced::{Size, window::Settings};
 
 const APP_LOGO: &[u8] = include_bytes!("../../data/icons/hicolor/scalable/apps/win_logo.png");
 
+#[cfg(not(feature = "dbus"))]
+const USAGE: &str = "Usage: ferrix-app [PAGE] [--open <SNAPSHOT.json>]
+       ferrix-app --export <json|xml|yaml|plain> [--output <FILE>]
+       ferrix-app --diff <OLD.json> <NEW.json> [--ignore <PATH>]...";
+#[cfg(feature = "dbus")]
+const USAGE: &str = "Usage: ferrix-app [PAGE] [--open <SNAPSHOT.json>]
+       ferrix-app --export <json|xml|yaml|plain> [--output <FILE>]
+       ferrix-app --diff <OLD.json> <NEW.json> [--ignore <PATH>]...
+       ferrix-app --dbus";
+
 pub fn main() -> iced::Result {
+    // Headless mode: the GUI isn't started at all
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args
+        .iter()
+        .any(|arg| arg == "--export" || arg == "--output")
+    {
+        match export(&args) {
+            Ok(_) => std::process::exit(0),
+            Err(why) => {
+                eprintln!("Error: {why}\n\n{USAGE}");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if args.first().is_some_and(|arg| arg == "--diff") {
+        match diff(&args[1..]) {
+            Ok(_) => std::process::exit(0),
+            Err(why) => {
+                eprintln!("Error: {why}\n\n{USAGE}");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    #[cfg(feature = "dbus")]
+    if args.iter().any(|arg| arg == "--dbus") {
+        if let Err(why) = serve_dbus() {
+            eprintln!("Error: {why}");
+            std::process::exit(1);
+        }
+    }
+
     if &(std::env::var("USER").unwrap_or("".to_string())) == "root" {
         panic!("Running this program as `root` is prohibited.");
     }
 
+    let settings = FXSettings::load();
+
     iced::application(Ferrix::default, Ferrix::update, Ferrix::view)
         .settings(iced::Settings {
-            default_text_size: iced::Pixels(12.),
+            default_text_size: iced::Pixels(settings.font_size as f32),
             ..Default::default()
         })
         .window(Settings {
@@ -48,3 +95,90 @@ pub fn main() -> iced::Result {
         .title(Ferrix::title)
         .run()
 }
+
+/// Writes all system information to the stdout or to the file
+fn export(args: &[String]) -> Result<()> {
+    let mut format = None;
+    let mut output = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--export" => format = args.next(),
+            "--output" => output = args.next(),
+            _ => return Err(anyhow!("unknown argument: {arg}")),
+        }
+    }
+    let format = format.ok_or(anyhow!("export format isn't specified"))?;
+
+    let data = tokio::runtime::Runtime::new()?.block_on(ferrix_lib::Ferrix::new())?;
+    let contents = match format.as_str() {
+        "json" => data.to_json_pretty()?,
+        "xml" => data.to_xml()?,
+        "yaml" => data.to_yaml()?,
+        "plain" => data.to_plain(),
+        _ => return Err(anyhow!("unknown export format: {format}")),
+    };
+
+    match output {
+        Some(path) => std::fs::write(path, contents)?,
+        None => println!("{contents}"),
+    }
+    Ok(())
+}
+
+/// Prints the differences between two snapshots exported with
+/// `--export json`
+fn diff(args: &[String]) -> Result<()> {
+    let mut files = Vec::new();
+    let mut ignore = ferrix_lib::diff::DEFAULT_IGNORE
+        .iter()
+        .map(|path| path.to_string())
+        .collect::<Vec<_>>();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--ignore" => ignore.push(
+                args.next()
+                    .ok_or(anyhow!("path to ignore isn't specified"))?
+                    .to_string(),
+            ),
+            _ => files.push(arg),
+        }
+    }
+    let [old, new] = files[..] else {
+        return Err(anyhow!("two snapshots must be specified"));
+    };
+
+    let read = |path: &str| -> Result<serde_json::Value> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|why| anyhow!("failed to read \"{path}\": {why}"))?;
+        Ok(serde_json::from_str(&contents)?)
+    };
+    let (old, new) = (read(old)?, read(new)?);
+
+    let schema = |snapshot: &serde_json::Value| snapshot.pointer("/_ferrix/schema").cloned();
+    if schema(&old) != schema(&new) {
+        eprintln!("Warning: snapshots have different data format versions");
+    }
+
+    let changes = ferrix_lib::diff::diff_values(&old, &new, &ignore);
+    if changes.is_empty() {
+        println!("No changes");
+    }
+    for change in changes {
+        println!("{change}");
+    }
+    Ok(())
+}
+
+/// Serves Ferrix data on the session bus until the program is killed
+#[cfg(feature = "dbus")]
+fn serve_dbus() -> Result<()> {
+    tokio::runtime::Runtime::new()?.block_on(async {
+        let _conn = ferrix_lib::dbus::serve().await?;
+        println!("Serving {} on the session bus", ferrix_lib::dbus::BUS_NAME);
+        std::future::pending::<Result<()>>().await
+    })
+}
@@ -19,6 +19,7 @@
  */
 
 use ferrix_app::{dmi::DMIData, kernel::KResult};
+use ferrix_lib::{battery, cpu_freq};
 use std::env;
 
 fn print_error_mode() {
@@ -29,6 +30,46 @@ fn print_error_mode() {
     std::process::exit(1);
 }
 
+fn set_governor(core: Option<String>, governor: Option<String>) {
+    let (core, governor) = match (core.and_then(|c| c.parse::<usize>().ok()), governor) {
+        (Some(core), Some(governor)) => (core, governor),
+        _ => {
+            eprintln!(
+                "{}",
+                serde_json::json!({
+                    "error": "Usage: ferrix-polkit governor <CORE> <GOVERNOR>",
+                })
+            );
+            std::process::exit(1);
+        }
+    };
+
+    if let Err(why) = cpu_freq::set_governor(core, &governor) {
+        eprintln!("{}", serde_json::json!({ "error": why.to_string() }));
+        std::process::exit(1);
+    }
+}
+
+fn set_charge_limit(bat: Option<String>, stop: Option<String>) {
+    let (bat, stop) = match (bat, stop.and_then(|s| s.parse::<u8>().ok())) {
+        (Some(bat), Some(stop)) => (bat, stop),
+        _ => {
+            eprintln!(
+                "{}",
+                serde_json::json!({
+                    "error": "Usage: ferrix-polkit charge-limit <BATTERY> <STOP>",
+                })
+            );
+            std::process::exit(1);
+        }
+    };
+
+    if let Err(why) = battery::set_charge_limit(&bat, stop) {
+        eprintln!("{}", serde_json::json!({ "error": why.to_string() }));
+        std::process::exit(1);
+    }
+}
+
 fn main() {
     let mut args = env::args().skip(1);
     let mode = args.next();
@@ -41,6 +82,10 @@ fn main() {
             } else if &mode == "kmods" {
                 let data = KResult::new();
                 println!("{}", data.to_json().unwrap());
+            } else if &mode == "governor" {
+                set_governor(args.next(), args.next());
+            } else if &mode == "charge-limit" {
+                set_charge_limit(args.next(), args.next());
             } else {
                 print_error_mode();
             }
@@ -21,79 +21,23 @@
 //! DMI Service Provider
 
 use anyhow::Result;
-use async_std::task;
-use ferrix_lib::dmi::{Baseboard, Bios, Chassis, Processor};
+use ferrix_lib::dmi::{
+    Baseboard, Bios, Chassis, DMIExtras, DMITable, DmiError, Processor, SMBiosData, load_table,
+};
 use serde::{Deserialize, Serialize};
-use std::{env, path::Path, process::Command, sync::LazyLock};
 
-use crate::load_state::{LoadState, ToLoadState};
-
-static PATH: LazyLock<Vec<String>> = LazyLock::new(|| path());
-
-fn path() -> Vec<String> {
-    env::var("PATH")
-        .ok()
-        .and_then(|pth| Some(pth.split(':').map(|p| p.to_string()).collect::<Vec<_>>()))
-        .unwrap_or(vec!["/usr/bin".to_string()])
-}
-
-fn auth_app() -> Option<String> {
-    let apps = ["pkexec", "gksudo"];
-    let bin_dirs = &PATH;
-
-    for a in apps {
-        for b in bin_dirs.as_slice() {
-            let s = Path::new(b).join(a);
-            if s.exists() {
-                return Some(s.display().to_string());
-            }
-        }
-    }
-
-    None
-}
-
-fn fx_polkit_app() -> Option<String> {
-    let app = "ferrix-polkit";
-    let bin_dirs = &PATH;
-    for b in bin_dirs.as_slice() {
-        let s = Path::new(b).join(app);
-        if s.exists() {
-            return Some(s.display().to_string());
-        }
-    }
-    None
-}
+use crate::{
+    load_state::{LoadState, ToLoadState},
+    polkit,
+};
 
 pub async fn get_dmi_data() -> LoadState<DMIData> {
-    let auth_app = match auth_app() {
-        Some(auth_app) => auth_app,
-        None => return LoadState::Error("No authentication software found".to_string()),
-    };
-    let fx_app = match fx_polkit_app() {
-        Some(fx_app) => fx_app,
-        None => return LoadState::Error("No `ferrix-app` program found".to_string()),
+    let output = match polkit::run(vec!["dmi".to_string()]).await {
+        Ok(output) => output,
+        Err(why) => return LoadState::Error(why),
     };
 
-    let output =
-        task::spawn_blocking(move || Command::new(auth_app).arg(fx_app).arg("dmi").output()).await;
-    dbg!(&output);
-
-    if let Err(why) = output {
-        return LoadState::Error(why.to_string());
-    }
-    let output = output.unwrap();
-    if output.status.code().unwrap_or(0) != 0 {
-        return LoadState::Error(format!(
-            "[ferrix-polkit] Non-zero return code:\n{}",
-            String::from_utf8_lossy(&output.stderr)
-        ));
-    }
-
-    let json_str = String::from_utf8_lossy(&output.stdout);
-    let json_data = DMIData::from_json(&json_str);
-
-    match json_data {
+    match DMIData::from_json(&output) {
         Ok(data) => LoadState::Loaded(data),
         Err(why) => LoadState::Error(why.to_string()),
     }
@@ -105,6 +49,7 @@ pub struct DMIData {
     pub baseboard: LoadState<Baseboard>,
     pub chassis: LoadState<Chassis>,
     pub processor: LoadState<Processor>,
+    pub extras: LoadState<DMIExtras>,
 }
 
 impl DMIData {
@@ -114,6 +59,37 @@ impl DMIData {
             baseboard: Baseboard::new().to_load_state(),
             chassis: Chassis::new().to_load_state(),
             processor: Processor::new().to_load_state(),
+            extras: DMIExtras::new().to_load_state(),
+        }
+    }
+
+    /// Tries to read the DMI table without root privileges
+    ///
+    /// Returns [`DmiError::PermissionDenied`] if it must be read using
+    /// `ferrix-polkit`
+    pub fn load() -> std::result::Result<Self, DmiError> {
+        let table = load_table()?;
+        Ok(Self::new_from_table(&table))
+    }
+
+    pub fn new_from_table(table: &SMBiosData) -> Self {
+        Self {
+            bios: Bios::new_from_table(table).to_load_state(),
+            baseboard: Baseboard::new_from_table(table).to_load_state(),
+            chassis: Chassis::new_from_table(table).to_load_state(),
+            processor: Processor::new_from_table(table).to_load_state(),
+            extras: DMIExtras::new_from_table(table).to_load_state(),
+        }
+    }
+
+    /// Takes the data from the full table (e.g. from the snapshot)
+    pub fn from_table(table: &DMITable) -> Self {
+        Self {
+            bios: LoadState::Loaded(table.bios.clone()),
+            baseboard: LoadState::Loaded(table.baseboard.clone()),
+            chassis: LoadState::Loaded(table.chassis.clone()),
+            processor: LoadState::Loaded(table.processor.clone()),
+            extras: LoadState::Loaded(table.extras.clone()),
         }
     }
 
@@ -20,20 +20,23 @@
 
 //! Export manager
 
-use std::fmt::Display;
+use std::{collections::HashSet, fmt::Display, time::SystemTime};
 
 use ferrix_lib::{
     battery::BatInfo,
     cpu::Processors,
     drm::Video,
     init::SystemdServices,
+    parts::Mounts,
     ram::RAM,
-    sys::{Groups, KModules, Kernel, OsRelease, Users},
+    sys::{Groups, KModules, Kernel, OsRelease, Uptime, Users, get_hostname},
     traits::ToJson,
+    utils::local_time_string,
 };
 use serde::Serialize;
+use serde_json::Value;
 
-use crate::DataLoadingState;
+use crate::{DataLoadingState, dmi::DMIData};
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum ExportStatus {
@@ -49,10 +52,21 @@ pub enum ExportStatus {
 pub enum ExportFormat {
     CompressedJson,
     HumanJson,
+    Csv,
+    Html,
 }
 
 impl ExportFormat {
-    pub const ALL: &[Self] = &[Self::CompressedJson, Self::HumanJson];
+    pub const ALL: &[Self] = &[Self::CompressedJson, Self::HumanJson, Self::Csv, Self::Html];
+
+    /// Extension of the exported file (without a dot)
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::CompressedJson | Self::HumanJson => "json",
+            Self::Csv => "csv",
+            Self::Html => "html",
+        }
+    }
 }
 
 impl Display for ExportFormat {
@@ -63,19 +77,51 @@ impl Display for ExportFormat {
             match self {
                 Self::CompressedJson => "Compressed JSON",
                 Self::HumanJson => "Human-readable JSON",
+                Self::Csv => "CSV table",
+                Self::Html => "HTML report",
             }
         )
     }
 }
 
+/// Usage chart which can be saved as a PNG image
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ChartKind {
+    Cpu,
+    Ram,
+    Net,
+}
+
+impl ChartKind {
+    /// Default name of the image file
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::Cpu => "cpu-chart.png",
+            Self::Ram => "ram-chart.png",
+            Self::Net => "net-chart.png",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum ExportMode {
     AllData,
-    Selected,
+    Sections(HashSet<Section>),
 }
 
 impl ExportMode {
-    pub const ALL: &[Self] = &[Self::AllData, Self::Selected];
+    /// Sections selected by default when switching to
+    /// [`ExportMode::Sections`]
+    pub fn default_sections() -> Self {
+        Self::Sections(HashSet::from([Section::Cpu, Section::Ram]))
+    }
+
+    pub fn contains(&self, section: Section) -> bool {
+        match self {
+            Self::AllData => true,
+            Self::Sections(sections) => sections.contains(&section),
+        }
+    }
 }
 
 impl Display for ExportMode {
@@ -85,7 +131,66 @@ impl Display for ExportMode {
             "{}",
             match self {
                 Self::AllData => "All collected data",
-                Self::Selected => "Selected data",
+                Self::Sections(_) => "Selected sections",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Section {
+    Cpu,
+    Ram,
+    Storage,
+    Dmi,
+    Battery,
+    Drm,
+    OsRelease,
+    Kernel,
+    KModules,
+    Users,
+    Groups,
+    Services,
+    Misc,
+}
+
+impl Section {
+    pub const ALL: &[Self] = &[
+        Self::Cpu,
+        Self::Ram,
+        Self::Storage,
+        Self::Dmi,
+        Self::Battery,
+        Self::Drm,
+        Self::OsRelease,
+        Self::Kernel,
+        Self::KModules,
+        Self::Users,
+        Self::Groups,
+        Self::Services,
+        Self::Misc,
+    ];
+}
+
+impl Display for Section {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Cpu => "Processors",
+                Self::Ram => "Memory",
+                Self::Storage => "File systems",
+                Self::Dmi => "DMI tables",
+                Self::Battery => "Battery",
+                Self::Drm => "Screen",
+                Self::OsRelease => "Distribution",
+                Self::Kernel => "Kernel",
+                Self::KModules => "Kernel modules",
+                Self::Users => "Users",
+                Self::Groups => "Groups",
+                Self::Services => "systemd services",
+                Self::Misc => "System (misc)",
             }
         )
     }
@@ -110,44 +215,193 @@ impl<'a, T> From<&'a DataLoadingState<T>> for ExportMember<'a, T> {
     }
 }
 
-fn get_data<'a, T>(data: &'a DataLoadingState<T>) -> Option<ExportMember<'a, T>> {
+fn get_data<'a, T>(
+    mode: &ExportMode,
+    section: Section,
+    data: &'a DataLoadingState<T>,
+) -> Option<ExportMember<'a, T>> {
+    if !mode.contains(section) {
+        return None;
+    }
     if let DataLoadingState::Loading = data {
         return None;
     }
     Some(ExportMember::from(data))
 }
 
+/// Collected data to export. Sections which aren't loaded yet or
+/// aren't selected by the user are omitted.
 #[derive(Debug, Clone, Serialize)]
 pub struct ExportData<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cpu: Option<ExportMember<'a, Processors>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ram: Option<ExportMember<'a, RAM>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage: Option<ExportMember<'a, Mounts>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dmi: Option<ExportMember<'a, DMIData>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub battery: Option<ExportMember<'a, BatInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub drm: Option<ExportMember<'a, Video>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub os_release: Option<ExportMember<'a, OsRelease>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub kernel: Option<ExportMember<'a, Kernel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub kmods: Option<ExportMember<'a, KModules>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub users: Option<ExportMember<'a, Users>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub groups: Option<ExportMember<'a, Groups>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub systemd: Option<ExportMember<'a, SystemdServices>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub misc: Option<ExportMember<'a, crate::System>>,
 }
 
 impl<'a> From<&'a mut crate::ferrix::Ferrix> for ExportData<'a> {
     fn from(value: &'a mut crate::ferrix::Ferrix) -> Self {
+        let data = &value.data;
+        let mode = &data.export_mode;
         Self {
-            cpu: get_data(&value.data.proc_data),
-            ram: get_data(&value.data.ram_data),
-            battery: get_data(&value.data.bat_data),
-            drm: get_data(&value.data.drm_data),
-            os_release: get_data(&value.data.osrel_data),
-            kernel: get_data(&value.data.kernel_data),
-            kmods: get_data(&value.data.kmods_data),
-            users: get_data(&value.data.users_list),
-            groups: get_data(&value.data.groups_list),
-            systemd: get_data(&value.data.sysd_services_list),
-            misc: get_data(&value.data.system),
+            cpu: get_data(mode, Section::Cpu, &data.proc_data),
+            ram: get_data(mode, Section::Ram, &data.ram_data),
+            storage: get_data(mode, Section::Storage, &data.storages),
+            dmi: get_data(mode, Section::Dmi, &data.dmi_data),
+            battery: get_data(mode, Section::Battery, &data.bat_data),
+            drm: get_data(mode, Section::Drm, &data.drm_data),
+            os_release: get_data(mode, Section::OsRelease, &data.osrel_data),
+            kernel: get_data(mode, Section::Kernel, &data.kernel_data),
+            kmods: get_data(mode, Section::KModules, &data.kmods_data),
+            users: get_data(mode, Section::Users, &data.users_list),
+            groups: get_data(mode, Section::Groups, &data.groups_list),
+            systemd: get_data(mode, Section::Services, &data.sysd_services_list),
+            misc: get_data(mode, Section::Misc, &data.system),
         }
     }
 }
 
 impl<'a> ToJson for ExportData<'a> {}
+
+impl<'a> ExportData<'a> {
+    /// Serializes the data into a CSV table with `section,param,value`
+    /// columns
+    ///
+    /// Nested structures are flattened into dotted keys (e.g.
+    /// `cpu.entries.0.model_name`), so the output is suitable for
+    /// spreadsheets and for diffing data from two machines.
+    pub fn to_csv(&self) -> anyhow::Result<String> {
+        let mut csv = String::from("section,param,value\n");
+        for (section, rows) in self.flatten_sections()? {
+            for (param, value) in rows {
+                csv += &format!(
+                    "{},{},{}\n",
+                    csv_field(&section),
+                    csv_field(&param),
+                    csv_field(&value)
+                );
+            }
+        }
+        Ok(csv)
+    }
+
+    /// Renders a self-contained HTML report (with inline CSS and
+    /// without any external resources) with one table per section
+    pub fn to_html(&self) -> anyhow::Result<String> {
+        let hostname = get_hostname().unwrap_or("unknown".to_string());
+        let generated = local_time_string(SystemTime::now()).unwrap_or_default();
+        let boot_time = Uptime::new()
+            .ok()
+            .and_then(|uptime| uptime.boot_time())
+            .and_then(local_time_string)
+            .unwrap_or_default();
+
+        let mut html = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Ferrix report: {host}</title>\n<style>{HTML_STYLE}</style>\n\
+             </head>\n<body>\n<h1>Ferrix report</h1>\n<p>Host: <b>{host}</b><br>\
+             Generated: {generated}<br>Booted: {boot_time}</p>\n",
+            host = html_escape(&hostname),
+        );
+        for (section, rows) in self.flatten_sections()? {
+            html += &format!(
+                "<h2>{}</h2>\n<table>\n<tr><th>Parameter</th><th>Value</th></tr>\n",
+                html_escape(&section)
+            );
+            let prefix = format!("{section}.");
+            for (param, value) in &rows {
+                html += &format!(
+                    "<tr><td>{}</td><td>{}</td></tr>\n",
+                    html_escape(param.strip_prefix(&prefix).unwrap_or(param)),
+                    html_escape(value)
+                );
+            }
+            html += "</table>\n";
+        }
+        html += &format!(
+            "<footer>Ferrix System Monitor v{}</footer>\n</body>\n</html>\n",
+            env!("CARGO_PKG_VERSION")
+        );
+        Ok(html)
+    }
+
+    /// Flattens every section into `(param, value)` rows
+    fn flatten_sections(&self) -> anyhow::Result<Vec<(String, Vec<Row>)>> {
+        let mut sections = Vec::new();
+        if let Value::Object(obj) = serde_json::to_value(self)? {
+            for (section, data) in &obj {
+                // Skip the `data` level added by `ExportMember::Data`
+                let data = data.get("data").unwrap_or(data);
+                let mut rows = Vec::new();
+                flatten(section, data, &mut rows);
+                sections.push((section.clone(), rows));
+            }
+        }
+        Ok(sections)
+    }
+}
+
+/// `(param, value)` row of a flattened section
+type Row = (String, String);
+
+const HTML_STYLE: &str = "body{font-family:sans-serif;margin:2em;color:#222}\
+h2{border-bottom:1px solid #ccc;padding-bottom:.2em}\
+table{border-collapse:collapse;margin-bottom:1.5em}\
+th,td{border:1px solid #ccc;padding:.3em .6em;text-align:left;vertical-align:top}\
+th{background:#eee}tr:nth-child(even) td{background:#f8f8f8}\
+footer{color:#888;font-size:.8em}";
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn flatten(key: &str, value: &Value, rows: &mut Vec<Row>) {
+    match value {
+        Value::Null => {}
+        Value::Object(obj) => {
+            for (k, v) in obj {
+                flatten(&format!("{key}.{k}"), v, rows);
+            }
+        }
+        Value::Array(arr) => {
+            for (i, v) in arr.iter().enumerate() {
+                flatten(&format!("{key}.{i}"), v, rows);
+            }
+        }
+        Value::String(s) => rows.push((key.to_string(), s.clone())),
+        _ => rows.push((key.to_string(), value.to_string())),
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
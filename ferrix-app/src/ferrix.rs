@@ -21,22 +21,39 @@
 //! Data from `ferrix-lib`
 
 use crate::{
-    SETTINGS_PATH, dmi::DMIData, load_state::LoadState, messages::Message, pages::Page,
-    settings::FXSettings, sidebar::sidebar, utils::get_home, widgets::line_charts::LineChart,
+    dmi::DMIData,
+    export::{ExportFormat, ExportMode},
+    fl,
+    load_state::LoadState,
+    messages::Message,
+    modals::{MAX_TOASTS, Toast, ToastLevel, toasts},
+    pages::{KModsSortColumn, Page, UsersSortColumn},
+    settings::FXSettings,
+    sidebar::sidebar,
+    widgets::{line_charts::LineChart, table::SortState},
 };
 use ferrix_lib::{
     battery::BatInfo,
     cpu::{Processors, Stat},
     cpu_freq::CpuFreq,
+    devtools::Toolchains,
     drm::Video,
-    init::SystemdServices,
-    parts::Mounts,
-    ram::{RAM, Swaps},
+    init::{BootTimes, SystemdServices},
+    net::Interfaces,
+    parts::{DiskStats, Mounts, Smart},
+    proc::{Processes, UserNames},
+    ram::{RAM, Swaps, Zram},
+    sensors::Sensors,
     soft::InstalledPackages,
-    sys::{Groups, KModules, Kernel, OsRelease, Users},
+    sys::{Groups, KModules, Kernel, OsRelease, Sessions, Users},
     vulnerabilities::Vulnerabilities,
 };
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
+};
 
 #[derive(Debug)]
 pub struct Ferrix {
@@ -48,18 +65,41 @@ pub struct Ferrix {
 
 impl Default for Ferrix {
     fn default() -> Self {
-        let args = std::env::args().nth(1);
-        let page = match &args {
-            Some(a) => Page::from(a as &str),
-            None => Page::default(),
+        let args = std::env::args().skip(1).collect::<Vec<_>>();
+        let page = match args.first() {
+            Some(a) if a != "--open" => Page::from(a as &str),
+            _ => Page::default(),
         };
-        let settings =
-            FXSettings::read(get_home().join(".config").join(SETTINGS_PATH)).unwrap_or_default();
+        let settings = FXSettings::load();
+        let mut data = FerrixData::new(&settings);
+
+        // `ferrix-app [PAGE] --open <FILE.json>`
+        if let Some(i) = args.iter().position(|a| a == "--open") {
+            let result = match args.get(i + 1) {
+                Some(path) => data.open_snapshot(Path::new(path)),
+                None => Err(anyhow::anyhow!("path to the snapshot isn't specified")),
+            };
+            if let Err(why) = result {
+                data.push_toast(
+                    fl!("snapshot-open-error", error = why.to_string()),
+                    ToastLevel::Error,
+                );
+            }
+        }
+        if settings.language.is_some()
+            && let Err(why) = crate::i18n::set_locale(settings.language.as_deref())
+        {
+            // The auto-detected locale is used instead
+            data.push_toast(
+                fl!("settings-lang-error", error = why.to_string()),
+                ToastLevel::Error,
+            );
+        }
 
         Self {
             current_page: page,
             settings: settings.clone(),
-            data: FerrixData::new(&settings),
+            data,
             scrolled_area_id: None,
         }
     }
@@ -71,7 +111,10 @@ impl Ferrix {
     }
 
     pub fn title(&self) -> String {
-        "Ferrix System Monitor".to_string()
+        match &self.data.snapshot {
+            Some(path) => format!("Ferrix System Monitor — {}", path.display()),
+            None => "Ferrix System Monitor".to_string(),
+        }
     }
 
     pub fn update(&mut self, message: Message) -> iced::Task<Message> {
@@ -79,37 +122,79 @@ impl Ferrix {
     }
 
     pub fn view<'a>(&'a self) -> iced::Element<'a, Message> {
-        iced::widget::row![sidebar(self.current_page), self.current_page.page(&self)]
-            .spacing(5)
-            .padding(5)
-            .into()
+        let view = iced::widget::row![
+            sidebar(
+                self.current_page,
+                &self.data.sidebar_filter,
+                &self.settings.collapsed_groups,
+                self.data.failed_units,
+            ),
+            self.current_page.page(&self)
+        ]
+        .spacing(5)
+        .padding(5);
+
+        if self.data.toasts.is_empty() {
+            view.into()
+        } else {
+            toasts(view, &self.data.toasts)
+        }
     }
 }
 
 #[derive(Debug)]
 pub struct FerrixData {
     pub is_polkit: bool,
+    /// Path to the opened snapshot. Data of this machine isn't
+    /// collected while it's set
+    pub snapshot: Option<PathBuf>,
 
     pub proc_data: LoadState<Processors>,
     pub selected_proc: usize,
+    pub selected_kmod: Option<String>,
+    pub kmods_sort: SortState<KModsSortColumn>,
     pub prev_proc_stat: LoadState<Stat>,
     pub curr_proc_stat: LoadState<Stat>,
+    pub prev_proc_stat_time: Option<Instant>,
+    pub curr_proc_stat_time: Option<Instant>,
     pub cpu_usage_chart: LineChart,
     pub show_cpus_chart: HashSet<usize>,
     pub show_chart_elements: usize,
     pub show_charts_legend: bool,
+    pub show_boot_params: bool,
+    pub show_ram_advanced: bool,
     pub cpu_freq: LoadState<CpuFreq>,
+    pub cpu_freq_chart: LineChart,
     pub cpu_vulnerabilities: LoadState<Vulnerabilities>,
 
     pub ram_data: LoadState<RAM>,
     pub swap_data: LoadState<Swaps>,
+    pub zram_data: LoadState<Zram>,
     pub show_mem_chart: HashSet<usize>,
     pub show_ram_chart: bool,
     pub ram_usage_chart: LineChart,
+    pub ram_breakdown_chart: LineChart,
+    pub show_ram_breakdown: bool,
 
     pub storages: LoadState<Mounts>,
+    pub smart: LoadState<Vec<Smart>>,
+    pub prev_disk_stats: Option<(Instant, DiskStats)>,
+    pub curr_disk_stats: Option<(Instant, DiskStats)>,
+    pub disk_io_chart: LineChart,
+    /// Names of the `disk_io_chart` series
+    pub disk_io_series: Vec<String>,
+    pub prev_net_stats: Option<(Instant, Interfaces)>,
+    pub curr_net_stats: Option<(Instant, Interfaces)>,
+    pub net_chart: LineChart,
+    /// Names of the `net_chart` series
+    pub net_series: Vec<String>,
+    pub processes: LoadState<Processes>,
+    /// Read once, since processes are updated much more often than
+    /// the users list changes
+    pub user_names: Option<Arc<UserNames>>,
     pub dmi_data: LoadState<DMIData>,
     pub bat_data: LoadState<BatInfo>,
+    pub sensors: LoadState<Sensors>,
     pub drm_data: LoadState<Video>,
     pub osrel_data: LoadState<OsRelease>,
 
@@ -117,50 +202,167 @@ pub struct FerrixData {
     pub kmods_data: LoadState<KModules>,
 
     pub users_list: LoadState<Users>,
+    pub users_sort: SortState<UsersSortColumn>,
+    pub sessions: LoadState<Sessions>,
     pub groups_list: LoadState<Groups>,
     pub sysd_services_list: LoadState<SystemdServices>,
-    pub installed_pkgs_list: LoadState<InstalledPackages>,
+    pub boot_times: LoadState<BootTimes>,
+    /// Number of failed units shown in the sidebar
+    pub failed_units: usize,
+    pub installed_pkgs_list: LoadState<Vec<InstalledPackages>>,
+    pub selected_pkg_manager: usize,
+    pub pkg_search_query: String,
+    pub env_filter: String,
+    pub kmods_filter: String,
+    pub sidebar_filter: String,
+    pub show_env_secrets: bool,
+    pub export_format: ExportFormat,
+    pub export_mode: ExportMode,
+    pub toasts: Vec<Toast>,
+    /// The last attempt to save the settings has failed. The error is
+    /// shown only once until the settings are saved successfully
+    pub settings_save_failed: bool,
     pub system: LoadState<crate::System>,
+    pub toolchains: LoadState<Toolchains>,
 }
 
 impl Default for FerrixData {
     fn default() -> Self {
         Self {
             is_polkit: false,
+            snapshot: None,
 
             cpu_usage_chart: LineChart::new(),
             selected_proc: 0,
+            selected_kmod: None,
+            kmods_sort: SortState::new(KModsSortColumn::Name),
             show_cpus_chart: HashSet::new(),
             show_chart_elements: 100,
             ram_usage_chart: LineChart::new(),
+            ram_breakdown_chart: LineChart::new(),
+            show_ram_breakdown: false,
             show_mem_chart: HashSet::new(),
             show_ram_chart: true,
             show_charts_legend: true,
+            show_boot_params: false,
+            show_ram_advanced: false,
 
             proc_data: LoadState::default(),
             prev_proc_stat: LoadState::default(),
             curr_proc_stat: LoadState::default(),
+            prev_proc_stat_time: None,
+            curr_proc_stat_time: None,
             cpu_freq: LoadState::default(),
+            cpu_freq_chart: LineChart::new(),
             cpu_vulnerabilities: LoadState::default(),
             ram_data: LoadState::default(),
             swap_data: LoadState::default(),
+            zram_data: LoadState::default(),
             storages: LoadState::default(),
+            smart: LoadState::default(),
+            prev_disk_stats: None,
+            curr_disk_stats: None,
+            disk_io_chart: LineChart::new(),
+            disk_io_series: Vec::new(),
+            prev_net_stats: None,
+            curr_net_stats: None,
+            net_chart: LineChart::new(),
+            net_series: Vec::new(),
+            processes: LoadState::default(),
+            user_names: None,
             dmi_data: LoadState::default(),
             bat_data: LoadState::default(),
+            sensors: LoadState::default(),
             drm_data: LoadState::default(),
             osrel_data: LoadState::default(),
             kernel_data: LoadState::default(),
             kmods_data: LoadState::default(),
             users_list: LoadState::default(),
+            users_sort: SortState::new(UsersSortColumn::Uid),
+            sessions: LoadState::default(),
             groups_list: LoadState::default(),
             sysd_services_list: LoadState::default(),
+            boot_times: LoadState::default(),
+            failed_units: 0,
             installed_pkgs_list: LoadState::default(),
+            selected_pkg_manager: 0,
+            pkg_search_query: String::new(),
+            env_filter: String::new(),
+            kmods_filter: String::new(),
+            sidebar_filter: String::new(),
+            show_env_secrets: false,
+            export_format: ExportFormat::CompressedJson,
+            export_mode: ExportMode::AllData,
+            toasts: Vec::new(),
+            settings_save_failed: false,
             system: LoadState::default(),
+            toolchains: LoadState::default(),
         }
     }
 }
 
 impl FerrixData {
+    /// Shows the notification which is closed after
+    /// [`crate::modals::TOAST_TIMEOUT`]
+    pub fn push_toast<S: Into<String>>(&mut self, text: S, level: ToastLevel) {
+        self.toasts.push(Toast::new(text, level));
+        if self.toasts.len() > MAX_TOASTS {
+            self.toasts.remove(0);
+        }
+    }
+
+    /// Closes expired notifications
+    pub fn expire_toasts(&mut self) {
+        let now = Instant::now();
+        self.toasts.retain(|toast| !toast.is_expired(now));
+    }
+
+    /// Shows the data captured with `ferrix-app --export json` (e.g.
+    /// on another machine) instead of the data of this machine
+    pub fn open_snapshot(&mut self, path: &Path) -> anyhow::Result<()> {
+        let contents = std::fs::read_to_string(path)?;
+        let data = ferrix_lib::Ferrix::from_json(&contents)?;
+
+        self.proc_data = LoadState::Loaded(data.cpu);
+        self.ram_data = LoadState::Loaded(data.ram);
+        self.swap_data = LoadState::Loaded(data.swaps);
+        self.dmi_data = match &data.dmi {
+            Some(dmi) => LoadState::Loaded(DMIData::from_table(dmi)),
+            None => LoadState::Error(fl!("snapshot-missing-section")),
+        };
+        self.drm_data = LoadState::Loaded(data.drm);
+        self.system = LoadState::Loaded(crate::System::from(&data.sys));
+        self.failed_units = data.init.failed().len();
+        self.sysd_services_list = LoadState::Loaded(data.init);
+
+        // Other sections aren't stored in the snapshot
+        fn missing<T>() -> LoadState<T> {
+            LoadState::Error(fl!("snapshot-missing-section"))
+        }
+        self.prev_proc_stat = missing();
+        self.curr_proc_stat = missing();
+        self.cpu_freq = missing();
+        self.cpu_vulnerabilities = missing();
+        self.zram_data = missing();
+        self.processes = missing();
+        self.storages = missing();
+        self.smart = missing();
+        self.bat_data = missing();
+        self.sensors = missing();
+        self.osrel_data = missing();
+        self.boot_times = missing();
+        self.kernel_data = missing();
+        self.kmods_data = missing();
+        self.users_list = missing();
+        self.sessions = missing();
+        self.groups_list = missing();
+        self.installed_pkgs_list = missing();
+        self.toolchains = missing();
+
+        self.snapshot = Some(path.to_path_buf());
+        Ok(())
+    }
+
     pub fn new(settings: &FXSettings) -> Self {
         let style = &settings.style;
         let thickness = settings.chart_line_thickness;
@@ -169,14 +371,53 @@ impl FerrixData {
         cpu_usage_chart.set_style(&style.to_theme());
         cpu_usage_chart.set_line_thickness(thickness);
 
+        let mut cpu_freq_chart = LineChart::new();
+        cpu_freq_chart.set_style(&style.to_theme());
+        cpu_freq_chart.set_line_thickness(thickness);
+        cpu_freq_chart.set_unit(" MHz");
+        cpu_freq_chart.set_auto_scale(true);
+
         let mut ram_usage_chart = LineChart::new();
         ram_usage_chart.set_style(&style.to_theme());
         ram_usage_chart.set_line_thickness(thickness);
 
+        let mut ram_breakdown_chart = LineChart::new();
+        ram_breakdown_chart.set_style(&style.to_theme());
+        ram_breakdown_chart.set_line_thickness(thickness);
+        ram_breakdown_chart.toggle_stacked();
+
+        let mut disk_io_chart = LineChart::new();
+        disk_io_chart.set_style(&style.to_theme());
+        disk_io_chart.set_line_thickness(thickness);
+        disk_io_chart.set_byte_rate(settings.byte_unit);
+        disk_io_chart.set_auto_scale(true);
+
+        let mut net_chart = LineChart::new();
+        net_chart.set_style(&style.to_theme());
+        net_chart.set_line_thickness(thickness);
+        net_chart.set_byte_rate(settings.byte_unit);
+        net_chart.set_auto_scale(true);
+
         Self {
             cpu_usage_chart,
+            cpu_freq_chart,
             ram_usage_chart,
+            ram_breakdown_chart,
+            disk_io_chart,
+            net_chart,
             ..Default::default()
         }
     }
+
+    /// All charts, so the chart settings are always applied to each of them
+    pub fn charts_mut(&mut self) -> [&mut LineChart; 6] {
+        [
+            &mut self.cpu_usage_chart,
+            &mut self.cpu_freq_chart,
+            &mut self.ram_usage_chart,
+            &mut self.ram_breakdown_chart,
+            &mut self.disk_io_chart,
+            &mut self.net_chart,
+        ]
+    }
 }
@@ -20,9 +20,11 @@
 
 //! Internationalization support
 
+use anyhow::Result;
 use i18n_embed::{
-    DesktopLanguageRequester,
+    DesktopLanguageRequester, LanguageLoader,
     fluent::{FluentLanguageLoader, fluent_language_loader},
+    unic_langid::LanguageIdentifier,
 };
 use lazy_static::lazy_static;
 use rust_embed::RustEmbed;
@@ -43,6 +45,29 @@ lazy_static! {
     pub static ref LANG_LDR: FluentLanguageLoader = read();
 }
 
+/// Reloads translations for the `lang` locale (e.g. `ru`). If `lang`
+/// is `None`, the locale is detected from the desktop settings
+pub fn set_locale(lang: Option<&str>) -> Result<()> {
+    let req_langs = match lang {
+        Some(lang) => vec![lang.parse::<LanguageIdentifier>()?],
+        None => DesktopLanguageRequester::requested_languages(),
+    };
+    i18n_embed::select(&*LANG_LDR, &Locales, &req_langs)?;
+    Ok(())
+}
+
+/// Returns the list of embedded locales
+pub fn available_locales() -> Vec<String> {
+    let mut langs = LANG_LDR
+        .available_languages(&Locales)
+        .unwrap_or_default()
+        .iter()
+        .map(|lang| lang.to_string())
+        .collect::<Vec<_>>();
+    langs.sort();
+    langs
+}
+
 #[macro_export]
 macro_rules! fl {
     ($message_id:literal) => {{
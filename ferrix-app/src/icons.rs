@@ -28,5 +28,25 @@ pub const ABOUT_ICON: &[u8] =
     include_bytes!("../data/icons/hicolor/symbolic/actions/ferrix-about.svg");
 pub const EXPORT_ICON: &[u8] =
     include_bytes!("../data/icons/hicolor/symbolic/actions/ferrix-export.svg");
+pub const REFRESH_ICON: &[u8] =
+    include_bytes!("../data/icons/hicolor/symbolic/actions/ferrix-refresh.svg");
 pub const FERRIX_ICON: &[u8] =
     include_bytes!("../data/icons/hicolor/scalable/apps/com.mskrasnov.Ferrix.svg");
+
+/* Distro logos */
+pub const TUX_LOGO: &[u8] =
+    include_bytes!("../data/icons/hicolor/symbolic/distros/ferrix-distro-tux.svg");
+pub const UBUNTU_LOGO: &[u8] =
+    include_bytes!("../data/icons/hicolor/symbolic/distros/ferrix-distro-ubuntu.svg");
+pub const FEDORA_LOGO: &[u8] =
+    include_bytes!("../data/icons/hicolor/symbolic/distros/ferrix-distro-fedora.svg");
+pub const ARCH_LOGO: &[u8] =
+    include_bytes!("../data/icons/hicolor/symbolic/distros/ferrix-distro-arch.svg");
+pub const DEBIAN_LOGO: &[u8] =
+    include_bytes!("../data/icons/hicolor/symbolic/distros/ferrix-distro-debian.svg");
+pub const OPENSUSE_LOGO: &[u8] =
+    include_bytes!("../data/icons/hicolor/symbolic/distros/ferrix-distro-opensuse.svg");
+pub const LINUXMINT_LOGO: &[u8] =
+    include_bytes!("../data/icons/hicolor/symbolic/distros/ferrix-distro-linuxmint.svg");
+pub const MANJARO_LOGO: &[u8] =
+    include_bytes!("../data/icons/hicolor/symbolic/distros/ferrix-distro-manjaro.svg");
@@ -22,7 +22,7 @@ pub mod export;
 pub mod i18n;
 pub mod icons;
 pub mod load_state;
-// pub mod modals;
+pub mod modals;
 pub mod pages;
 pub mod styles;
 pub mod utils;
@@ -30,6 +30,7 @@ pub mod widgets;
 
 pub mod dmi;
 pub mod kernel;
+pub mod polkit;
 
 // REFACTORED MODULES
 pub mod ferrix;
@@ -46,29 +47,82 @@ pub use pages::*;
 use serde::Serialize;
 
 use anyhow::Result;
-use ferrix_lib::sys::{LoadAVG, Uptime, get_current_desktop, get_env_vars, get_hostname, get_lang};
+use ferrix_lib::sys::{
+    LoadAVG, Sys, SysctlError, Uptime, get_cmdline, get_current_desktop, get_env_vars,
+    get_hostname, get_lang, get_rtc_utc, get_timezone, parse_cmdline, sysctl,
+};
+use std::time::SystemTime;
 
 const SETTINGS_PATH: &str = "./ferrix.conf";
 
+/// Kernel parameters shown on the "System (misc)" page
+const SYSCTL_KEYS: &[&str] = &[
+    "vm.swappiness",
+    "vm.overcommit_memory",
+    "net.ipv4.ip_forward",
+    "kernel.sysrq",
+    "kernel.perf_event_paranoid",
+    "fs.file-max",
+];
+
 #[derive(Debug, Clone, Serialize)]
 pub struct System {
     pub hostname: Option<String>,
     pub loadavg: Option<LoadAVG>,
     pub uptime: Option<Uptime>,
+    pub boot_time: Option<SystemTime>,
+    pub timezone: Option<String>,
+    pub rtc_utc: Option<bool>,
     pub desktop: Option<String>,
     pub language: Option<String>,
     pub env_vars: Vec<(String, String)>,
+    pub cmdline_params: Vec<(String, Option<String>)>,
+    /// Values of [`SYSCTL_KEYS`]. The error is kept to show why the
+    /// value is missing
+    pub sysctls: Vec<(String, Result<String, SysctlError>)>,
 }
 
 impl System {
     pub fn new() -> Result<Self> {
+        let uptime = Uptime::new()?;
+        let boot_time = uptime.boot_time();
         Ok(Self {
             hostname: get_hostname(),
             loadavg: Some(LoadAVG::new()?),
-            uptime: Some(Uptime::new()?),
+            uptime: Some(uptime),
+            boot_time,
+            timezone: get_timezone(),
+            rtc_utc: get_rtc_utc(),
             desktop: get_current_desktop(),
             language: get_lang(),
             env_vars: get_env_vars(),
+            cmdline_params: get_cmdline()
+                .map(|cmdline| parse_cmdline(&cmdline))
+                .unwrap_or_default(),
+            sysctls: SYSCTL_KEYS
+                .iter()
+                .map(|key| (key.to_string(), sysctl(key)))
+                .collect(),
         })
     }
 }
+
+impl From<&Sys> for System {
+    /// Used for snapshots, which don't contain the desktop, the
+    /// language and kernel parameters
+    fn from(value: &Sys) -> Self {
+        Self {
+            hostname: value.hostname.clone(),
+            loadavg: Some(value.loadavg.clone()),
+            uptime: Some(value.uptime.clone()),
+            boot_time: value.boot_time,
+            timezone: value.timezone.clone(),
+            rtc_utc: value.rtc_utc,
+            desktop: None,
+            language: None,
+            env_vars: value.env_vars.clone(),
+            cmdline_params: value.cmdline_params.clone(),
+            sysctls: Vec::new(),
+        }
+    }
+}
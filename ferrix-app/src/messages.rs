@@ -24,12 +24,17 @@ use ferrix_lib::{
     battery::BatInfo,
     cpu::{Processors, Stat},
     cpu_freq::CpuFreq,
+    devtools::Toolchains,
+    dmi::DmiError,
     drm::Video,
-    init::{Connection, SystemdServices},
-    parts::Mounts,
-    ram::{RAM, Swaps},
+    init::{BootTimes, Bus, ServiceInfo, SystemdServices, connect_with_timeout},
+    net::Interfaces,
+    parts::{DiskStats, Mounts, Smart, Storages},
+    proc::{Processes, user_names},
+    ram::{RAM, Swaps, Zram},
+    sensors::Sensors,
     soft::InstalledPackages,
-    sys::{Groups, KModules, Kernel, OsRelease, Users},
+    sys::{Groups, KModules, Kernel, OsRelease, Sessions, Users},
     traits::ToJson,
     vulnerabilities::Vulnerabilities,
 };
@@ -41,17 +46,28 @@ use iced::{
         operation::{self, AbsoluteOffset, RelativeOffset},
     },
 };
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use crate::{
     DataLoadingState, Page, SETTINGS_PATH, System,
     dmi::DMIData,
-    export::{ExportData, ExportFormat, ExportMode},
+    export::{ChartKind, ExportData, ExportFormat, ExportMode, Section},
     ferrix::{Ferrix, FerrixData},
-    settings::{ChartLineThickness, FXSettings, Style},
+    fl,
+    modals::ToastLevel,
+    pages::{KModsSortColumn, UsersSortColumn},
+    settings::{ByteUnit, ChartLineThickness, FXSettings, Style, TempUnit, UpdateSection},
+    sidebar::SidebarGroup,
     utils::{ToColor, get_home},
     widgets::line_charts::LineSeries,
 };
 
+/// Timeout of a single attempt to connect to the D-Bus
+const BUS_TIMEOUT: Duration = Duration::from_secs(2);
+
 #[derive(Debug, Clone)]
 pub enum Message {
     DataReceiver(DataReceiverMessage),
@@ -61,6 +77,8 @@ pub enum Message {
 
     SelectPage(Page),
     Keyboard(KeyboardMessage),
+    ShowToast(String, ToastLevel),
+    ExpireToasts,
     Dummy,
 }
 
@@ -76,6 +94,14 @@ impl Message {
 
             Self::SelectPage(page) => state.select_page(page),
             Self::Keyboard(keyboard) => keyboard.update(state),
+            Self::ShowToast(text, level) => {
+                state.data.push_toast(text, level);
+                Task::none()
+            }
+            Self::ExpireToasts => {
+                state.data.expire_toasts();
+                Task::none()
+            }
             Self::Dummy => Task::none(),
         }
     }
@@ -102,6 +128,7 @@ pub enum DataReceiverMessage {
     ProcStatReceived(DataLoadingState<Stat>),
 
     GetCPUFrequency,
+    AddCPUFreqSeries,
     CPUFrequencyReceived(DataLoadingState<CpuFreq>),
 
     GetCPUVulnerabilities,
@@ -113,17 +140,38 @@ pub enum DataReceiverMessage {
     GetSwapData,
     SwapDataReceived(DataLoadingState<Swaps>),
 
+    GetZramData,
+    ZramDataReceived(DataLoadingState<Zram>),
+
     AddTotalRAMUsage,
+    AddRAMBreakdown,
 
     GetStorageData,
     StorageDataReceived(DataLoadingState<Mounts>),
 
+    GetSmartData,
+    SmartDataReceived(DataLoadingState<Vec<Smart>>),
+
+    GetDiskStats,
+    DiskStatsReceived(DataLoadingState<DiskStats>),
+    AddDiskIOSeries,
+
+    GetNetStats,
+    NetStatsReceived(DataLoadingState<Interfaces>),
+    AddNetSeries,
+
+    GetProcesses,
+    ProcessesReceived(DataLoadingState<Processes>),
+
     GetDMIData,
     DMIDataReceived(DataLoadingState<DMIData>),
 
     GetBatInfo,
     BatInfoReceived(DataLoadingState<BatInfo>),
 
+    GetSensorsData,
+    SensorsReceived(DataLoadingState<Sensors>),
+
     GetDRMData,
     DRMDataReceived(DataLoadingState<Video>),
 
@@ -139,17 +187,32 @@ pub enum DataReceiverMessage {
     GetUsersData,
     UsersDataReceived(DataLoadingState<Users>),
 
+    GetSessionsData,
+    SessionsDataReceived(DataLoadingState<Sessions>),
+
     GetGroupsData,
     GroupsDataReceived(DataLoadingState<Groups>),
 
     GetSystemdServices,
     SystemdServicesReceived(DataLoadingState<SystemdServices>),
+    GetUnitFile(ServiceInfo),
+    UnitFileReceived(ServiceInfo),
+
+    GetBootTimes,
+    BootTimesReceived(DataLoadingState<BootTimes>),
 
     GetPackagesList,
-    PackagesListReceived(DataLoadingState<InstalledPackages>),
+    PackagesListReceived(DataLoadingState<Vec<InstalledPackages>>),
+    PackagesSearchChanged(String),
+    EnvFilterChanged(String),
+    KModsFilterChanged(String),
+    SidebarFilterChanged(String),
 
     GetSystemData,
     SystemDataReceived(DataLoadingState<System>),
+
+    GetToolchains,
+    ToolchainsReceived(DataLoadingState<Toolchains>),
 }
 
 impl DataReceiverMessage {
@@ -180,6 +243,10 @@ impl DataReceiverMessage {
                 } else if fx.curr_proc_stat.is_none() && fx.prev_proc_stat.is_none() {
                     fx.prev_proc_stat = state.clone();
                 }
+                if state.is_some() {
+                    fx.prev_proc_stat_time = fx.curr_proc_stat_time;
+                    fx.curr_proc_stat_time = Some(Instant::now());
+                }
                 fx.curr_proc_stat = state;
                 Task::none()
             }
@@ -213,17 +280,13 @@ impl DataReceiverMessage {
                 let curr_proc = curr_proc.to_option().unwrap();
                 let prev_proc = prev_proc.to_option().unwrap();
 
-                if curr_proc.cpus.len() != prev_proc.cpus.len() {
-                    return Task::none();
-                }
-                let len = curr_proc.cpus.len();
+                let usage = curr_proc.per_core_usage(prev_proc);
 
                 let colors_set = &settings.chart_colors.colors;
                 let def_colors = &settings.chart_colors.default_colors;
 
-                for id in 0..len {
-                    let percent =
-                        curr_proc.cpus[id].usage_percentage(Some(prev_proc.cpus[id])) as f64;
+                for (id, percent) in usage.into_iter().enumerate() {
+                    let percent = percent as f64;
 
                     if fx.show_cpus_chart.get(&id).is_none() {
                         let name = format!("CPU #{id}");
@@ -256,7 +319,43 @@ impl DataReceiverMessage {
                 fx.show_chart_elements = elems;
 
                 fx.cpu_usage_chart.set_max_values(elems);
+                fx.cpu_freq_chart.set_max_values(elems);
                 fx.ram_usage_chart.set_max_values(elems);
+                fx.ram_breakdown_chart.set_max_values(elems);
+                fx.disk_io_chart.set_max_values(elems);
+                fx.net_chart.set_max_values(elems);
+
+                Task::none()
+            }
+            Self::AddCPUFreqSeries => {
+                let Some(cpu_freq) = fx.cpu_freq.to_option() else {
+                    return Task::none();
+                };
+
+                let colors_set = &settings.chart_colors.colors;
+                let def_colors = &settings.chart_colors.default_colors;
+
+                for (id, policy) in cpu_freq.policy.iter().enumerate() {
+                    // kHz -> MHz
+                    let mhz = policy.scaling_cur_freq.unwrap_or(0) as f64 / 1000.;
+
+                    if id >= fx.cpu_freq_chart.series_count() {
+                        let name = format!("CPU #{id}");
+                        let color = match colors_set.get(&name) {
+                            Some(col) => col.to_color(),
+                            None => match def_colors.get(id) {
+                                Some(col) => col.to_color(),
+                                None => color!(255, 255, 255),
+                            },
+                        };
+                        let mut line = LineSeries::new(name, color, fx.show_chart_elements);
+                        line.push(mhz);
+
+                        fx.cpu_freq_chart.push_series(line);
+                    } else {
+                        fx.cpu_freq_chart.push_to(id, mhz);
+                    }
+                }
 
                 Task::none()
             }
@@ -292,9 +391,155 @@ impl DataReceiverMessage {
                 fx.storages = state;
                 Task::none()
             }
+            Self::SmartDataReceived(state) => {
+                fx.smart = state;
+                Task::none()
+            }
+            Self::DiskStatsReceived(state) => {
+                if let DataLoadingState::Loaded(stats) = state {
+                    fx.prev_disk_stats = fx.curr_disk_stats.take();
+                    fx.curr_disk_stats = Some((Instant::now(), stats));
+                }
+                Task::none()
+            }
+            Self::GetDiskStats => Task::perform(
+                async move {
+                    let stats = DiskStats::new();
+                    match stats {
+                        Ok(stats) => DataLoadingState::Loaded(stats),
+                        Err(why) => DataLoadingState::Error(why.to_string()),
+                    }
+                },
+                |val| Message::DataReceiver(Self::DiskStatsReceived(val)),
+            ),
+            Self::AddDiskIOSeries => {
+                let (Some((prev_time, prev)), Some((curr_time, curr))) =
+                    (&fx.prev_disk_stats, &fx.curr_disk_stats)
+                else {
+                    return Task::none();
+                };
+                let secs = curr_time.duration_since(*prev_time).as_secs_f64();
+                if secs <= 0. {
+                    return Task::none();
+                }
+
+                let colors_set = &settings.chart_colors.colors;
+                let def_colors = &settings.chart_colors.default_colors;
+
+                for disk in curr.delta(prev).disks.iter().filter(|d| d.is_disk()) {
+                    let values = [
+                        (format!("{} read", disk.name), disk.read_bytes()),
+                        (format!("{} write", disk.name), disk.written_bytes()),
+                    ];
+                    for (name, bytes) in values {
+                        let rate = bytes as f64 / secs;
+                        match fx.disk_io_series.iter().position(|s| s == &name) {
+                            Some(idx) => fx.disk_io_chart.push_to(idx, rate),
+                            None => {
+                                let id = fx.disk_io_series.len();
+                                let color = match colors_set.get(&name) {
+                                    Some(col) => col.to_color(),
+                                    None if id < def_colors.len() => def_colors[id].to_color(),
+                                    None => color!(255, 255, 255),
+                                };
+                                let mut line =
+                                    LineSeries::new(name.clone(), color, fx.show_chart_elements);
+                                line.push(rate);
+                                fx.disk_io_chart.push_series(line);
+                                fx.disk_io_series.push(name);
+                            }
+                        }
+                    }
+                }
+
+                Task::none()
+            }
+            Self::NetStatsReceived(state) => {
+                if let DataLoadingState::Loaded(stats) = state {
+                    fx.prev_net_stats = fx.curr_net_stats.take();
+                    fx.curr_net_stats = Some((Instant::now(), stats));
+                }
+                Task::none()
+            }
+            Self::GetNetStats => Task::perform(
+                async move {
+                    let stats = Interfaces::new();
+                    match stats {
+                        Ok(stats) => DataLoadingState::Loaded(stats),
+                        Err(why) => DataLoadingState::Error(why.to_string()),
+                    }
+                },
+                |val| Message::DataReceiver(Self::NetStatsReceived(val)),
+            ),
+            Self::AddNetSeries => {
+                let (Some((prev_time, prev)), Some((curr_time, curr))) =
+                    (&fx.prev_net_stats, &fx.curr_net_stats)
+                else {
+                    return Task::none();
+                };
+                let secs = curr_time.duration_since(*prev_time).as_secs_f64();
+                if secs <= 0. {
+                    return Task::none();
+                }
+
+                let colors_set = &settings.chart_colors.colors;
+                let def_colors = &settings.chart_colors.default_colors;
+
+                // Loopback traffic doesn't leave the machine
+                for iface in curr.delta(prev).ifaces.iter().filter(|i| !i.is_loopback()) {
+                    let Some(stats) = iface.stats else {
+                        continue;
+                    };
+                    let values = [
+                        (format!("{} RX", iface.name), stats.rx_bytes),
+                        (format!("{} TX", iface.name), stats.tx_bytes),
+                    ];
+                    for (name, bytes) in values {
+                        let rate = bytes as f64 / secs;
+                        match fx.net_series.iter().position(|s| s == &name) {
+                            Some(idx) => fx.net_chart.push_to(idx, rate),
+                            None => {
+                                let id = fx.net_series.len();
+                                let color = match colors_set.get(&name) {
+                                    Some(col) => col.to_color(),
+                                    None if id < def_colors.len() => def_colors[id].to_color(),
+                                    None => color!(255, 255, 255),
+                                };
+                                let mut line =
+                                    LineSeries::new(name.clone(), color, fx.show_chart_elements);
+                                line.push(rate);
+                                fx.net_chart.push_series(line);
+                                fx.net_series.push(name);
+                            }
+                        }
+                    }
+                }
+
+                Task::none()
+            }
+            Self::ProcessesReceived(state) => {
+                fx.processes = state;
+                Task::none()
+            }
+            Self::GetProcesses => {
+                let users = fx
+                    .user_names
+                    .get_or_insert_with(|| Arc::new(user_names()))
+                    .clone();
+                Task::perform(
+                    async move {
+                        let procs = Processes::new_with_users(&users);
+                        match procs {
+                            Ok(procs) => DataLoadingState::Loaded(procs),
+                            Err(why) => DataLoadingState::Error(why.to_string()),
+                        }
+                    },
+                    |val| Message::DataReceiver(Self::ProcessesReceived(val)),
+                )
+            }
             Self::GetStorageData => Task::perform(
                 async move {
-                    let storage = Mounts::new();
+                    let storage = Mounts::new_physical();
                     match storage {
                         Ok(storage) => DataLoadingState::Loaded(storage),
                         Err(why) => DataLoadingState::Error(why.to_string()),
@@ -302,6 +547,27 @@ impl DataReceiverMessage {
                 },
                 |val| Message::DataReceiver(DataReceiverMessage::StorageDataReceived(val)),
             ),
+            Self::GetSmartData => Task::perform(
+                async move {
+                    let storages = match Storages::new() {
+                        Ok(storages) => storages.storages,
+                        Err(why) => return DataLoadingState::Error(why.to_string()),
+                    };
+                    // Drives without SMART data are still shown
+                    DataLoadingState::Loaded(
+                        storages
+                            .into_iter()
+                            .map(|s| {
+                                Smart::for_device(&s.devname).unwrap_or(Smart {
+                                    devname: s.devname,
+                                    ..Default::default()
+                                })
+                            })
+                            .collect(),
+                    )
+                },
+                |val| Message::DataReceiver(DataReceiverMessage::SmartDataReceived(val)),
+            ),
             Self::DMIDataReceived(state) => {
                 if state.some_value() && fx.is_polkit {
                     fx.dmi_data = state;
@@ -311,11 +577,24 @@ impl DataReceiverMessage {
                 Task::none()
             }
             Self::GetDMIData => {
-                if !fx.is_polkit && fx.dmi_data.is_none() && cur_page == Page::DMI {
-                    fx.is_polkit = true;
-                    Task::perform(async move { crate::dmi::get_dmi_data().await }, |val| {
-                        Message::DataReceiver(Self::DMIDataReceived(val))
-                    })
+                if !fx.is_polkit && !fx.dmi_data.some_value() && cur_page == Page::DMI {
+                    match DMIData::load() {
+                        Ok(data) => {
+                            fx.dmi_data = DataLoadingState::Loaded(data);
+                            Task::none()
+                        }
+                        // Only ask for the password when it's really needed
+                        Err(DmiError::PermissionDenied) => {
+                            fx.is_polkit = true;
+                            Task::perform(async move { crate::dmi::get_dmi_data().await }, |val| {
+                                Message::DataReceiver(Self::DMIDataReceived(val))
+                            })
+                        }
+                        Err(why) => {
+                            fx.dmi_data = DataLoadingState::Error(why.to_string());
+                            Task::none()
+                        }
+                    }
                 } else {
                     Task::none()
                 }
@@ -334,6 +613,20 @@ impl DataReceiverMessage {
                 },
                 |val| Message::DataReceiver(Self::BatInfoReceived(val)),
             ),
+            Self::SensorsReceived(state) => {
+                fx.sensors = state;
+                Task::none()
+            }
+            Self::GetSensorsData => Task::perform(
+                async move {
+                    let sensors = Sensors::new();
+                    match sensors {
+                        Ok(sensors) => DataLoadingState::Loaded(sensors),
+                        Err(why) => DataLoadingState::Error(why.to_string()),
+                    }
+                },
+                |val| Message::DataReceiver(Self::SensorsReceived(val)),
+            ),
             Self::DRMDataReceived(state) => {
                 fx.drm_data = state;
                 Task::none()
@@ -376,6 +669,61 @@ impl DataReceiverMessage {
                 },
                 |val| Message::DataReceiver(Self::SwapDataReceived(val)),
             ),
+            Self::ZramDataReceived(state) => {
+                fx.zram_data = state;
+                Task::none()
+            }
+            Self::GetZramData => Task::perform(
+                async move {
+                    let zram = Zram::new();
+                    match zram {
+                        Ok(zram) => DataLoadingState::Loaded(zram),
+                        Err(why) => DataLoadingState::Error(why.to_string()),
+                    }
+                },
+                |val| Message::DataReceiver(Self::ZramDataReceived(val)),
+            ),
+            Self::AddRAMBreakdown => {
+                let Some(breakdown) = fx
+                    .ram_data
+                    .to_option()
+                    .and_then(|ram| ram.usage_breakdown())
+                else {
+                    return Task::none();
+                };
+                let values = [
+                    (fl!("sysmon-ram-apps"), breakdown.apps, color!(128, 64, 255)),
+                    (
+                        fl!("sysmon-ram-buffers"),
+                        breakdown.buffers,
+                        color!(255, 160, 0),
+                    ),
+                    (
+                        fl!("sysmon-ram-cache"),
+                        breakdown.cached,
+                        color!(0, 180, 120),
+                    ),
+                ];
+
+                if fx.ram_breakdown_chart.series_count() == 0 {
+                    let colors_set = &settings.chart_colors.colors;
+                    for (name, value, def_color) in values {
+                        let color = match colors_set.get(&name) {
+                            Some(col) => col.to_color(),
+                            None => def_color,
+                        };
+                        let mut line = LineSeries::new(name, color, fx.show_chart_elements);
+                        line.push(value);
+                        fx.ram_breakdown_chart.push_series(line);
+                    }
+                } else {
+                    for (idx, (_, value, _)) in values.into_iter().enumerate() {
+                        fx.ram_breakdown_chart.push_to(idx, value);
+                    }
+                }
+
+                Task::none()
+            }
             Self::AddTotalRAMUsage => {
                 let ram = &fx.ram_data;
                 let swap = &fx.swap_data;
@@ -427,7 +775,7 @@ impl DataReceiverMessage {
                                 }
                             };
                             let mut line = LineSeries::new(
-                                swap.swaps[id].filename.clone(),
+                                format!("{swap_name} ({})", swap.swaps[id].kind),
                                 color,
                                 fx.show_chart_elements,
                             );
@@ -489,7 +837,12 @@ impl DataReceiverMessage {
                 },
                 |val| Message::DataReceiver(Self::KModsDataReceived(val)),
             ),
-            Self::UsersDataReceived(state) => {
+            Self::UsersDataReceived(mut state) => {
+                if let (DataLoadingState::Loaded(users), Some(groups)) =
+                    (&mut state, fx.groups_list.to_option())
+                {
+                    users.resolve_groups(groups);
+                }
                 fx.users_list = state;
                 Task::none()
             }
@@ -506,7 +859,27 @@ impl DataReceiverMessage {
                 },
                 |val| Message::DataReceiver(Self::UsersDataReceived(val)),
             ),
+            Self::SessionsDataReceived(state) => {
+                fx.sessions = state;
+                Task::none()
+            }
+            Self::GetSessionsData => Task::perform(
+                async move {
+                    let sessions = Sessions::new();
+                    match sessions {
+                        Ok(sessions) => DataLoadingState::Loaded(sessions),
+                        Err(why) => DataLoadingState::Error(why.to_string()),
+                    }
+                },
+                |val| Message::DataReceiver(Self::SessionsDataReceived(val)),
+            ),
             Self::GroupsDataReceived(state) => {
+                // Users may be loaded before groups
+                if let (DataLoadingState::Loaded(users), Some(groups)) =
+                    (&mut fx.users_list, state.to_option())
+                {
+                    users.resolve_groups(groups);
+                }
                 fx.groups_list = state;
                 Task::none()
             }
@@ -524,16 +897,21 @@ impl DataReceiverMessage {
                 |val| Message::DataReceiver(Self::GroupsDataReceived(val)),
             ),
             Self::SystemdServicesReceived(state) => {
+                fx.failed_units = state.to_option().map_or(0, |srv| srv.failed().len());
                 fx.sysd_services_list = state;
                 Task::none()
             }
             Self::GetSystemdServices => Task::perform(
                 async move {
-                    let conn = Connection::session().await;
-                    if let Err(why) = conn {
-                        return DataLoadingState::Error(why.to_string());
-                    }
-                    let conn = conn.unwrap();
+                    let conn = match connect_with_timeout(Bus::Session, BUS_TIMEOUT).await {
+                        Ok(conn) => conn,
+                        Err(why) => {
+                            return DataLoadingState::Error(fl!(
+                                "sysd-bus-unavailable",
+                                error = why.0
+                            ));
+                        }
+                    };
 
                     let srv_list = SystemdServices::new_from_connection(&conn).await;
                     match srv_list {
@@ -543,6 +921,56 @@ impl DataReceiverMessage {
                 },
                 |val| Message::DataReceiver(Self::SystemdServicesReceived(val)),
             ),
+            Self::UnitFileReceived(unit) => {
+                if let DataLoadingState::Loaded(srv) = &mut fx.sysd_services_list
+                    && let Some(row) = srv.units.iter_mut().find(|row| row.name == unit.name)
+                {
+                    *row = unit;
+                }
+                Task::none()
+            }
+            Self::GetUnitFile(mut unit) => Task::perform(
+                async move {
+                    let conn = connect_with_timeout(Bus::Session, BUS_TIMEOUT)
+                        .await
+                        .map_err(|why| why.0)?;
+                    unit.read_unit_file(&conn)
+                        .await
+                        .map_err(|why| why.to_string())?;
+                    Ok(unit)
+                },
+                |res: Result<ServiceInfo, String>| match res {
+                    Ok(unit) => Message::DataReceiver(Self::UnitFileReceived(unit)),
+                    Err(why) => Message::ShowToast(
+                        fl!("sysd-unit-file-error", error = why),
+                        ToastLevel::Error,
+                    ),
+                },
+            ),
+            Self::BootTimesReceived(state) => {
+                fx.boot_times = state;
+                Task::none()
+            }
+            Self::GetBootTimes => Task::perform(
+                async move {
+                    // Boot is finished by the system manager, not by the
+                    // user one
+                    let conn = match connect_with_timeout(Bus::System, BUS_TIMEOUT).await {
+                        Ok(conn) => conn,
+                        Err(why) => {
+                            return DataLoadingState::Error(fl!(
+                                "sysd-bus-unavailable",
+                                error = why.0
+                            ));
+                        }
+                    };
+                    match BootTimes::new(&conn).await {
+                        Ok(times) => DataLoadingState::Loaded(times),
+                        Err(why) => DataLoadingState::Error(why.to_string()),
+                    }
+                },
+                |val| Message::DataReceiver(Self::BootTimesReceived(val)),
+            ),
             Self::SystemDataReceived(state) => {
                 fx.system = state;
                 Task::none()
@@ -561,6 +989,36 @@ impl DataReceiverMessage {
                 fx.installed_pkgs_list = state;
                 Task::none()
             }
+            Self::PackagesSearchChanged(query) => {
+                fx.pkg_search_query = query;
+                Task::none()
+            }
+            Self::EnvFilterChanged(filter) => {
+                fx.env_filter = filter;
+                Task::none()
+            }
+            Self::KModsFilterChanged(filter) => {
+                fx.kmods_filter = filter;
+                Task::none()
+            }
+            Self::SidebarFilterChanged(filter) => {
+                fx.sidebar_filter = filter;
+                Task::none()
+            }
+            Self::ToolchainsReceived(state) => {
+                fx.toolchains = state;
+                Task::none()
+            }
+            Self::GetToolchains => Task::perform(
+                async move {
+                    let toolchains = Toolchains::new();
+                    match toolchains {
+                        Ok(toolchains) => DataLoadingState::Loaded(toolchains),
+                        Err(why) => DataLoadingState::Error(why.to_string()),
+                    }
+                },
+                |val| Message::DataReceiver(Self::ToolchainsReceived(val)),
+            ),
             Self::GetSystemData => Task::perform(
                 async move {
                     let sys = System::new();
@@ -577,28 +1035,102 @@ impl DataReceiverMessage {
 
 pub type ExportToFilePath = String;
 
+/// Size of the saved chart images (in pixels)
+const CHART_IMAGE_SIZE: (u32, u32) = (1280, 480);
+
 #[derive(Debug, Clone)]
 pub enum ExportManagerMessage {
     ExportData(ExportToFilePath),
     ExportFormatSelected(ExportFormat),
     ExportModeSelected(ExportMode),
+    ExportSectionToggled(Section, bool),
+    CopyToClipboard,
+    SaveChartImage(ChartKind, ExportToFilePath),
 }
 
 impl ExportManagerMessage {
     pub fn update<'a>(self, fx: &'a mut Ferrix) -> Task<Message> {
         match self {
             Self::ExportData(path) => fx.export_data(&path),
-            _ => Task::none(),
+            Self::CopyToClipboard => fx.copy_export_data(),
+            Self::SaveChartImage(kind, path) => fx.save_chart_image(kind, &path),
+            Self::ExportFormatSelected(format) => {
+                fx.data.export_format = format;
+                Task::none()
+            }
+            Self::ExportModeSelected(mode) => {
+                fx.data.export_mode = mode;
+                Task::none()
+            }
+            Self::ExportSectionToggled(section, export) => {
+                if let ExportMode::Sections(sections) = &mut fx.data.export_mode {
+                    if export {
+                        sections.insert(section);
+                    } else {
+                        sections.remove(&section);
+                    }
+                }
+                Task::none()
+            }
         }
     }
 }
 
 impl Ferrix {
+    fn render_export_data(&mut self) -> anyhow::Result<String> {
+        let format = self.data.export_format.clone();
+        let data = ExportData::from(self);
+        match format {
+            ExportFormat::CompressedJson => data.to_json(),
+            ExportFormat::HumanJson => data.to_json_pretty(),
+            ExportFormat::Csv => data.to_csv(),
+            ExportFormat::Html => data.to_html(),
+        }
+    }
+
     fn export_data(&mut self, path: &str) -> Task<Message> {
-        let json = ExportData::from(self)
-            .to_json()
-            .unwrap_or("{error}".to_string());
-        let _ = std::fs::write(path, json);
+        let contents = self.render_export_data().unwrap_or("{error}".to_string());
+        if let Err(why) = std::fs::write(path, contents) {
+            self.data.push_toast(
+                fl!("export-save-error", error = why.to_string()),
+                ToastLevel::Error,
+            );
+        }
+        Task::none()
+    }
+
+    fn copy_export_data(&mut self) -> Task<Message> {
+        match self.render_export_data() {
+            Ok(contents) => {
+                self.data.push_toast(fl!("export-copied"), ToastLevel::Info);
+                iced::clipboard::write(contents)
+            }
+            Err(why) => {
+                self.data.push_toast(
+                    fl!("export-copy-error", error = why.to_string()),
+                    ToastLevel::Error,
+                );
+                Task::none()
+            }
+        }
+    }
+
+    fn save_chart_image(&mut self, kind: ChartKind, path: &str) -> Task<Message> {
+        let chart = match kind {
+            ChartKind::Cpu => &self.data.cpu_usage_chart,
+            ChartKind::Ram if self.data.show_ram_breakdown => &self.data.ram_breakdown_chart,
+            ChartKind::Ram => &self.data.ram_usage_chart,
+            ChartKind::Net => &self.data.net_chart,
+        };
+        match chart.save_png(path, CHART_IMAGE_SIZE) {
+            Ok(_) => self
+                .data
+                .push_toast(fl!("export-chart-saved", path = path), ToastLevel::Info),
+            Err(why) => self.data.push_toast(
+                fl!("export-chart-error", error = why.to_string()),
+                ToastLevel::Error,
+            ),
+        }
         Task::none()
     }
 }
@@ -606,34 +1138,70 @@ impl Ferrix {
 #[derive(Debug, Clone)]
 pub enum SettingsMessage {
     ChangeStyle(Style),
-    ChangeUpdatePeriod(u8),
+    ChangeUpdatePeriod(UpdateSection, u8),
     ChangeChartsUpdatePeriod(u8),
     ChangeChartLineThickness(ChartLineThickness),
     SetChartItemColor(String, (u8, u8, u8)),
+    ChangeLanguage(Option<String>),
+    ChangeFontSize(u8),
+    ChangeTempUnit(TempUnit),
+    ChangeByteUnit(ByteUnit),
+    /// A slider was released
+    Save,
 }
 
 impl SettingsMessage {
     pub fn update<'a>(self, fx: &'a mut Ferrix) -> Task<Message> {
-        match self {
+        // Sliders send a message on every step while being dragged, so
+        // their values are saved only on release (see `Self::Save`)
+        let save = !matches!(
+            self,
+            Self::ChangeUpdatePeriod(..)
+                | Self::ChangeChartsUpdatePeriod(_)
+                | Self::ChangeFontSize(_)
+        );
+        let task = match self {
             Self::ChangeStyle(style) => fx.change_style(style),
-            Self::ChangeUpdatePeriod(secs) => fx.change_update_period(secs),
+            Self::ChangeUpdatePeriod(section, secs) => fx.change_update_period(section, secs),
             Self::ChangeChartsUpdatePeriod(secs) => fx.change_charts_update_period(secs),
             Self::ChangeChartLineThickness(thick) => fx.change_line_thickness(thick),
             Self::SetChartItemColor(item, color) => fx.set_chart_item_color(item, color),
+            Self::ChangeLanguage(lang) => fx.change_language(lang),
+            Self::ChangeFontSize(size) => {
+                fx.settings.font_size = size;
+                Task::none()
+            }
+            Self::ChangeTempUnit(unit) => {
+                fx.settings.temp_unit = unit;
+                Task::none()
+            }
+            Self::ChangeByteUnit(unit) => {
+                fx.settings.byte_unit = unit;
+                fx.data.disk_io_chart.set_byte_rate(unit);
+                fx.data.net_chart.set_byte_rate(unit);
+                Task::none()
+            }
+            Self::Save => Task::none(),
+        };
+        if !save {
+            return task;
         }
+        // Every change is saved immediately so it survives restart
+        Task::batch([task, fx.save_settings()])
     }
 }
 
 impl Ferrix {
     fn change_style(&mut self, style: Style) -> Task<Message> {
         self.settings.style = style;
-        self.data.cpu_usage_chart.set_style(&style.to_theme());
-        self.data.ram_usage_chart.set_style(&style.to_theme());
+        for chart in self.data.charts_mut() {
+            chart.set_style(&style.to_theme());
+        }
         Task::none()
     }
 
-    fn change_update_period(&mut self, per: u8) -> Task<Message> {
-        self.settings.update_period = per;
+    fn change_update_period(&mut self, section: UpdateSection, per: u8) -> Task<Message> {
+        self.settings.update_periods.set(section, per);
         Task::none()
     }
 
@@ -644,15 +1212,29 @@ impl Ferrix {
 
     fn change_line_thickness(&mut self, thick: ChartLineThickness) -> Task<Message> {
         self.settings.chart_line_thickness = thick;
-        self.data.cpu_usage_chart.set_line_thickness(thick);
-        self.data.ram_usage_chart.set_line_thickness(thick);
+        for chart in self.data.charts_mut() {
+            chart.set_line_thickness(thick);
+        }
+
+        Task::none()
+    }
 
+    fn change_language(&mut self, lang: Option<String>) -> Task<Message> {
+        // All strings are taken from the loader on every `view()` call,
+        // so the interface is re-rendered in the new language at once
+        match crate::i18n::set_locale(lang.as_deref()) {
+            Ok(_) => self.settings.language = lang,
+            Err(why) => self.data.push_toast(
+                fl!("settings-lang-error", error = why.to_string()),
+                ToastLevel::Error,
+            ),
+        }
         Task::none()
     }
 
     fn set_chart_item_color(&mut self, item: String, color: (u8, u8, u8)) -> Task<Message> {
         self.settings.chart_colors.colors.insert(item, color);
-        self.save_settings()
+        Task::none()
     }
 }
 
@@ -663,7 +1245,21 @@ pub enum ButtonsMessage {
     CopyButtonPressed(String),
 
     ChangeLegendShow(bool),
+    ChangeBootParamsShow(bool),
+    ChangeRAMBreakdownShow(bool),
+    ChangeRAMAdvancedShow(bool),
+    ToggleChartsPause,
+    ToggleSidebarGroup(SidebarGroup),
+    RefreshCurrentPage,
+    ChangeEnvSecretsShow(bool),
     ProcessorSelected(usize),
+    KModuleSelected(Option<String>),
+    KModsSortChanged(KModsSortColumn),
+    UsersSortChanged(UsersSortColumn),
+    PackageManagerSelected(usize),
+    CloseToast(usize),
+    SetChargeLimit(String, u8),
+    SetGovernor(usize, String),
 }
 
 impl ButtonsMessage {
@@ -673,29 +1269,182 @@ impl ButtonsMessage {
             Self::SaveSettingsButtonPressed => fx.save_settings(),
             Self::CopyButtonPressed(s) => iced::clipboard::write(s),
             Self::ChangeLegendShow(show) => fx.set_show_charts_legend(show),
+            Self::ChangeBootParamsShow(show) => {
+                fx.data.show_boot_params = show;
+                Task::none()
+            }
+            Self::ChangeRAMBreakdownShow(show) => {
+                fx.data.show_ram_breakdown = show;
+                Task::none()
+            }
+            Self::ChangeRAMAdvancedShow(show) => {
+                fx.data.show_ram_advanced = show;
+                Task::none()
+            }
+            Self::ToggleChartsPause => fx.toggle_charts_pause(),
+            Self::ToggleSidebarGroup(group) => fx.toggle_sidebar_group(group),
+            Self::RefreshCurrentPage => fx.refresh_current_page(),
+            Self::ChangeEnvSecretsShow(show) => {
+                fx.data.show_env_secrets = show;
+                Task::none()
+            }
             Self::ProcessorSelected(id) => fx.proc_selected(id),
+            Self::KModuleSelected(name) => {
+                fx.data.selected_kmod = name;
+                Task::none()
+            }
+            Self::KModsSortChanged(column) => {
+                fx.data.kmods_sort = fx.data.kmods_sort.toggle(column);
+                Task::none()
+            }
+            Self::UsersSortChanged(column) => {
+                fx.data.users_sort = fx.data.users_sort.toggle(column);
+                Task::none()
+            }
+            Self::PackageManagerSelected(id) => fx.pkg_manager_selected(id),
+            Self::CloseToast(id) => {
+                if id < fx.data.toasts.len() {
+                    fx.data.toasts.remove(id);
+                }
+                Task::none()
+            }
+            Self::SetChargeLimit(bat, stop) => Task::perform(
+                crate::polkit::set_charge_limit(bat, stop),
+                |res| match res {
+                    Ok(_) => Message::DataReceiver(DataReceiverMessage::GetBatInfo),
+                    Err(why) => Message::ShowToast(
+                        fl!("bat-charge-limit-error", error = why),
+                        ToastLevel::Error,
+                    ),
+                },
+            ),
+            Self::SetGovernor(core, governor) => Task::perform(
+                crate::polkit::set_governor(core, governor),
+                |res| match res {
+                    Ok(_) => Message::DataReceiver(DataReceiverMessage::GetCPUFrequency),
+                    Err(why) => Message::ShowToast(
+                        fl!("cpufreq-set-gov-error", error = why),
+                        ToastLevel::Error,
+                    ),
+                },
+            ),
         }
     }
 }
 
 impl Ferrix {
     fn go_to_url(&self, url: &str) -> Task<Message> {
-        // TODO: add error handling
-        let _ = crate::utils::xdg_open(url);
-        Task::none()
+        let url = url.to_string();
+        Task::perform(
+            async move { crate::utils::xdg_open(&url).map_err(|why| (url, why.to_string())) },
+            |res| match res {
+                Ok(_) => Message::Dummy,
+                Err((url, why)) => Message::ShowToast(
+                    fl!("toast-open-link-error", url = url, error = why),
+                    ToastLevel::Error,
+                ),
+            },
+        )
     }
 
     fn save_settings(&mut self) -> Task<Message> {
-        // TODO: add error handling
-        let _ = self
+        match self
             .settings
-            .write(get_home().join(".config").join(SETTINGS_PATH));
+            .write(get_home().join(".config").join(SETTINGS_PATH))
+        {
+            Ok(()) => self.data.settings_save_failed = false,
+            // The same error would be reported on every change otherwise
+            Err(_) if self.data.settings_save_failed => {}
+            Err(why) => {
+                self.data.settings_save_failed = true;
+                self.data.push_toast(
+                    fl!("settings-save-error", error = why.to_string()),
+                    ToastLevel::Error,
+                );
+            }
+        }
+        Task::none()
+    }
+
+    fn refresh_current_page(&mut self) -> Task<Message> {
+        use DataReceiverMessage as DRM;
+
+        if self.data.snapshot.is_some() {
+            return Task::none();
+        }
+
+        // DMI data is static and requires root privileges, so it isn't
+        // re-read here. SMART data is read once too because `smartctl`
+        // is slow
+        let messages = match self.current_page {
+            Page::Dashboard => vec![
+                DRM::GetCPUData,
+                DRM::GetProcStat,
+                DRM::GetRAMData,
+                DRM::GetSwapData,
+                DRM::GetStorageData,
+                DRM::GetBatInfo,
+                DRM::GetOsReleaseData,
+                DRM::GetSystemData,
+            ],
+            Page::Processors => vec![DRM::GetCPUData, DRM::GetCPUFrequency],
+            Page::CPUFrequency => vec![DRM::GetCPUFrequency],
+            Page::CPUVulnerabilities => vec![DRM::GetCPUVulnerabilities],
+            Page::SystemMonitor => vec![
+                DRM::GetProcStat,
+                DRM::GetRAMData,
+                DRM::GetNetStats,
+                DRM::GetProcesses,
+            ],
+            Page::Memory => vec![DRM::GetRAMData, DRM::GetSwapData, DRM::GetZramData],
+            Page::FileSystems => vec![DRM::GetStorageData, DRM::GetDiskStats],
+            Page::Battery => vec![DRM::GetBatInfo],
+            Page::Screen => vec![DRM::GetDRMData],
+            Page::Sensors => vec![DRM::GetSensorsData],
+            Page::Distro => vec![DRM::GetOsReleaseData, DRM::GetBootTimes],
+            Page::Users => vec![DRM::GetGroupsData, DRM::GetUsersData, DRM::GetSessionsData],
+            Page::Groups => vec![DRM::GetGroupsData],
+            Page::SystemManager => vec![DRM::GetSystemdServices],
+            Page::Software => vec![DRM::GetPackagesList],
+            Page::Environment | Page::SystemMisc => vec![DRM::GetSystemData],
+            Page::Kernel => vec![DRM::GetKernelData],
+            Page::KModules => vec![DRM::GetKModsData],
+            Page::Development => vec![DRM::GetToolchains],
+            Page::DMI | Page::Settings | Page::About | Page::Export | Page::Todo => vec![],
+        };
+        Task::batch(
+            messages
+                .into_iter()
+                .map(|msg| Task::done(Message::DataReceiver(msg))),
+        )
+    }
+
+    fn toggle_sidebar_group(&mut self, group: SidebarGroup) -> Task<Message> {
+        let collapsed = &mut self.settings.collapsed_groups;
+        if !collapsed.remove(&group) {
+            collapsed.insert(group);
+        }
+        self.save_settings()
+    }
+
+    fn toggle_charts_pause(&mut self) -> Task<Message> {
+        let charts = self.data.charts_mut();
+        // Charts are always paused and resumed together
+        let paused = charts[0].is_paused();
+        for chart in charts {
+            if paused {
+                chart.resume();
+            } else {
+                chart.pause();
+            }
+        }
         Task::none()
     }
 
     fn set_show_charts_legend(&mut self, show: bool) -> Task<Message> {
-        self.data.cpu_usage_chart.set_show_legend(show);
-        self.data.ram_usage_chart.set_show_legend(show);
+        for chart in self.data.charts_mut() {
+            chart.set_show_legend(show);
+        }
         self.data.show_charts_legend = show;
         Task::none()
     }
@@ -704,6 +1453,11 @@ impl Ferrix {
         self.data.selected_proc = id;
         Task::none()
     }
+
+    fn pkg_manager_selected(&mut self, id: usize) -> Task<Message> {
+        self.data.selected_pkg_manager = id;
+        Task::none()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -19,28 +19,82 @@
  */
 
 use iced::Alignment::Center;
-use iced::widget::text::IntoFragment;
 use iced::widget::{button, column, container, row, space, stack, text};
 use iced::{Color, Element, Padding};
+use std::time::{Duration, Instant};
 
 use crate::fl;
 use crate::messages::{ButtonsMessage, Message};
 
-pub fn toast<'a>(
+/// Time after which the toast is closed automatically
+pub const TOAST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Maximum number of simultaneously shown toasts. The oldest ones
+/// are closed first
+pub const MAX_TOASTS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    Error,
+}
+
+/// Transient notification shown at the bottom of the window
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub text: String,
+    pub level: ToastLevel,
+    pub created: Instant,
+}
+
+impl Toast {
+    pub fn new<S: Into<String>>(text: S, level: ToastLevel) -> Self {
+        Self {
+            text: text.into(),
+            level,
+            created: Instant::now(),
+        }
+    }
+
+    pub fn is_expired(&self, now: Instant) -> bool {
+        now.duration_since(self.created) >= TOAST_TIMEOUT
+    }
+}
+
+/// Shows the toasts over the `base` widget
+pub fn toasts<'a>(
     base: impl Into<Element<'a, Message>>,
-    content: impl IntoFragment<'a>,
+    toasts: &'a [Toast],
 ) -> Element<'a, Message> {
-    let toast = container(
+    let mut list = column![].spacing(5).align_x(Center);
+    for (id, toast) in toasts.iter().enumerate() {
+        list = list.push(toast_view(id, toast));
+    }
+
+    stack![
+        base.into(),
+        column![
+            space::vertical(),
+            row![space::horizontal(), list, space::horizontal()]
+                .padding(Padding::new(0.).bottom(8.)),
+        ],
+    ]
+    .into()
+}
+
+fn toast_view<'a>(id: usize, toast: &'a Toast) -> Element<'a, Message> {
+    let level = toast.level;
+    container(
         row![
-            text(content).style(|t: &iced::Theme| text::Style {
-                color: Some(if t.extended_palette().is_dark {
-                    t.palette().text
-                } else {
-                    Color::WHITE
+            text(&toast.text).style(move |t: &iced::Theme| text::Style {
+                color: Some(match level {
+                    ToastLevel::Error => t.palette().danger,
+                    ToastLevel::Info if t.extended_palette().is_dark => t.palette().text,
+                    ToastLevel::Info => Color::WHITE,
                 })
             }),
             button(text(fl!("toast-close")))
-                .on_press(Message::Buttons(ButtonsMessage::ShowToastToggle))
+                .on_press(Message::Buttons(ButtonsMessage::CloseToast(id)))
                 .style(button::subtle)
                 .padding(2),
         ]
@@ -57,15 +111,6 @@ pub fn toast<'a>(
         ),
         ..container::rounded_box(t)
     })
-    .padding(5);
-
-    stack![
-        base.into(),
-        column![
-            space::vertical(),
-            row![space::horizontal(), toast, space::horizontal()]
-                .padding(Padding::new(0.).bottom(8.)),
-        ],
-    ]
+    .padding(5)
     .into()
 }
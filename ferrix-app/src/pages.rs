@@ -38,6 +38,7 @@ mod battery;
 mod cpu;
 mod cpu_freq;
 mod dashboard;
+mod dev;
 mod distro;
 mod dmi;
 mod drm;
@@ -46,6 +47,7 @@ mod export;
 mod groups;
 mod kernel;
 mod ram;
+mod sensors;
 mod settings;
 mod soft;
 mod storage;
@@ -55,7 +57,9 @@ mod systemd;
 mod users;
 mod vulnerabilities;
 
+pub use kernel::KModsSortColumn;
 pub use sysmon::*;
+pub use users::UsersSortColumn;
 
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
 pub enum Page {
@@ -297,36 +301,84 @@ impl<'a> Page {
 
     pub fn page(&'a self, state: &'a Ferrix) -> Element<'a, Message> {
         let page = match self {
-            Self::Dashboard => dashboard::dashboard(&state.data).into(),
+            Self::Dashboard => dashboard::dashboard(&state.data, state.settings.byte_unit).into(),
             Self::SystemMonitor => sysmon::usage_charts_page(
                 &state.data,
                 &state.data.curr_proc_stat,
                 &state.data.prev_proc_stat,
             )
             .into(), // TODO: cur_stat and proc_stat - ???
-            Self::Processors => {
-                cpu::proc_page(&state.data.proc_data, state.data.selected_proc).into()
-            }
+            Self::Processors => cpu::proc_page(
+                &state.data.proc_data,
+                state.data.selected_proc,
+                &state.data.cpu_freq_chart,
+            )
+            .into(),
             Self::CPUFrequency => cpu_freq::cpu_freq_page(&state.data.cpu_freq).into(),
             Self::CPUVulnerabilities => {
                 vulnerabilities::vulnerabilities_page(&state.data.cpu_vulnerabilities).into()
             }
-            Self::Memory => ram::ram_page(&state.data.ram_data, &state.data.swap_data).into(),
-            Self::FileSystems => storage::storage_page(&state.data.storages).into(),
+            Self::Memory => ram::ram_page(
+                &state.data.ram_data,
+                &state.data.swap_data,
+                &state.data.zram_data,
+                state.data.show_ram_advanced,
+                state.settings.byte_unit,
+            )
+            .into(),
+            Self::FileSystems => storage::storage_page(
+                &state.data.storages,
+                &state.data.smart,
+                &state.data.disk_io_chart,
+                state.settings.temp_unit,
+                state.settings.byte_unit,
+            )
+            .into(),
             Self::DMI => dmi::dmi_page(&state.data.dmi_data).into(),
             Self::Battery => battery::bat_page(&state.data.bat_data).into(),
             Self::Screen => drm::drm_page(&state.data.drm_data).into(),
-            Self::Distro => distro::distro_page(&state.data.osrel_data).into(),
+            Self::Distro => {
+                distro::distro_page(&state.data.osrel_data, &state.data.boot_times).into()
+            }
             Self::Kernel => kernel::kernel_page(&state.data.kernel_data).into(),
-            Self::KModules => kernel::kmods_page(&state.data.kmods_data).into(),
-            Self::SystemMisc => system::system_page(&state.data.system).into(),
-            Self::Users => users::users_page(&state.data.users_list).into(),
+            Self::KModules => kernel::kmods_page(
+                &state.data.kmods_data,
+                &state.data.selected_kmod,
+                &state.data.kmods_filter,
+                state.data.kmods_sort,
+            )
+            .into(),
+            Self::Development => dev::dev_page(&state.data.toolchains).into(),
+            Self::SystemMisc => {
+                system::system_page(&state.data.system, state.data.show_boot_params).into()
+            }
+            Self::Users => users::users_page(
+                &state.data.users_list,
+                &state.data.sessions,
+                state.data.users_sort,
+            )
+            .into(),
             Self::Groups => groups::groups_page(&state.data.groups_list).into(),
             Self::SystemManager => systemd::services_page(&state.data.sysd_services_list).into(),
-            Self::Software => soft::soft_page(&state.data.installed_pkgs_list).into(),
-            Self::Environment => env::env_page(&state.data.system).into(),
+            Self::Software => soft::soft_page(
+                &state.data.installed_pkgs_list,
+                state.data.selected_pkg_manager,
+                &state.data.pkg_search_query,
+            )
+            .into(),
+            Self::Environment => env::env_page(
+                &state.data.system,
+                &state.data.env_filter,
+                state.data.show_env_secrets,
+            )
+            .into(),
+            Self::Sensors => {
+                sensors::sensors_page(&state.data.sensors, state.settings.temp_unit).into()
+            }
             Self::Settings => settings::settings_page(&state).into(),
-            Self::Export => export::export_page().into(),
+            Self::Export => {
+                export::export_page(&state.data.export_format, &state.data.export_mode).into()
+            }
             Self::About => self.about_page().into(),
             _ => self.todo_page(),
         };
@@ -368,6 +420,7 @@ impl<'a> Page {
                 text(fl!("about-author-hdr")).style(text::secondary),
                 text(fl!("about-feedback-hdr")).style(text::secondary),
                 text(fl!("about-source-hdr")).style(text::secondary),
+                text(fl!("about-license-hdr")).style(text::secondary),
                 text("crates.io:").style(text::secondary),
                 text(fl!("about-blog")).style(text::secondary),
             ]
@@ -381,6 +434,10 @@ impl<'a> Page {
                 .spacing(5),
                 link_button("mskrasnov07 at ya dot ru", "mailto:mskrasnov07@ya.ru"),
                 link_button("GitHub", "https://github.com/mskrasnov/Ferrix"),
+                link_button(
+                    "GPL-3.0-or-later",
+                    "https://www.gnu.org/licenses/gpl-3.0.html"
+                ),
                 row![
                     link_button("ferrix-app", "https://crates.io/crates/ferrix-app"),
                     text(", "),
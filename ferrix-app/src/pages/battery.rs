@@ -22,15 +22,18 @@
 
 use crate::{
     DataLoadingState, Message, fl,
-    widgets::table::{InfoRow, fmt_val, kv_info_table},
+    messages::ButtonsMessage,
+    widgets::table::{InfoRow, MissingReason, fmt_val, kv_info_table},
 };
-use ferrix_lib::battery::{BatInfo, Battery, Level, Status};
+use ferrix_lib::battery::{BatInfo, Battery, Level, PowerSupply, Status};
+use std::time::Duration;
 
 use iced::{
     Alignment::Center,
     Length,
     widget::{
-        Id, center, column, container, progress_bar, row, scrollable, space::horizontal, text,
+        Id, button, center, column, container, progress_bar, row, scrollable, space::horizontal,
+        text,
     },
 };
 
@@ -38,12 +41,21 @@ pub fn bat_page<'a>(bat_info: &'a DataLoadingState<BatInfo>) -> container::Conta
     match bat_info {
         DataLoadingState::Loaded(bat_info) => {
             let mut bat_list = column![].spacing(5);
+            let power_source = power_source(&bat_info.power_supply);
             if bat_info.bats.is_empty() {
-                bat_list = bat_list.push(center(
-                    column![text(fl!("bat-not-found")).style(text::secondary).size(16)].spacing(5),
-                ));
+                // Desktops have no batteries, but still may report the
+                // mains supply
+                let mut msg =
+                    column![text(fl!("bat-not-found")).style(text::secondary).size(16)].spacing(5);
+                if let Some(power_source) = power_source {
+                    msg = msg.push(text(power_source).style(text::secondary));
+                }
+                bat_list = bat_list.push(center(msg));
                 return container(bat_list);
             }
+            if let Some(power_source) = power_source {
+                bat_list = bat_list.push(text(power_source));
+            }
 
             for bat in &bat_info.bats {
                 bat_list = bat_list.push(
@@ -79,6 +91,9 @@ pub fn bat_page<'a>(bat_info: &'a DataLoadingState<BatInfo>) -> container::Conta
                     .align_y(Center),
                 );
                 bat_list = bat_list.push(bat_table(bat));
+                if let Some(limits) = charge_limit_buttons(bat) {
+                    bat_list = bat_list.push(limits);
+                }
             }
             container(
                 scrollable(bat_list)
@@ -131,7 +146,15 @@ fn bat_table<'a>(bat: &'a Battery) -> container::Container<'a, Message> {
         ),
         InfoRow::new(
             fl!("bat-health"),
-            bat.health.and_then(|h| Some(format!("{h:.2}%"))),
+            bat.health_percentage.and_then(|h| Some(format!("{h:.2}%"))),
+        ),
+        InfoRow::new(
+            fl!("bat-charge-start"),
+            bat.charge_start_threshold.map(|t| format!("{t}%")),
+        ),
+        InfoRow::new(
+            fl!("bat-charge-stop"),
+            bat.charge_stop_threshold.map(|t| format!("{t}%")),
         ),
         InfoRow::new(fl!("bat-tech"), bat.technology.clone()),
         InfoRow::new(fl!("bat-cycle-cnt"), fmt_val(bat.cycle_count)),
@@ -145,7 +168,13 @@ fn bat_table<'a>(bat: &'a Battery) -> container::Container<'a, Message> {
         ),
         InfoRow::new(
             fl!("bat-power-now"),
-            bat.power_now.and_then(|p| Some(format!("{p} W"))),
+            bat.power_now.map(|p| match bat.time_to_empty() {
+                Some(time) => format!(
+                    "{p:.1} W, {}",
+                    fl!("bat-remaining", time = fmt_duration(time))
+                ),
+                None => format!("{p:.1} W"),
+            }),
         ),
         InfoRow::new(
             fl!("bat-energy-full-des"),
@@ -163,5 +192,61 @@ fn bat_table<'a>(bat: &'a Battery) -> container::Container<'a, Message> {
         InfoRow::new(fl!("bat-manufact"), bat.manufacturer.clone()),
         InfoRow::new(fl!("bat-serial"), bat.serial_number.clone()),
     ];
+    // The `uevent` file contains only values reported by the driver
+    let rows = rows
+        .into_iter()
+        .map(|row| row.or_missing(MissingReason::NotSupported))
+        .collect();
     container(kv_info_table(rows)).style(container::rounded_box)
 }
+
+/// Charge limits offered on the battery page
+const CHARGE_LIMITS: [u8; 3] = [60, 80, 100];
+
+/// Buttons changing the charge stop threshold. Available only if the
+/// driver supports charge thresholds
+fn charge_limit_buttons<'a>(bat: &'a Battery) -> Option<row::Row<'a, Message>> {
+    let name = bat.name.as_ref()?;
+    let current = bat.charge_stop_threshold?;
+
+    let mut buttons = row![text(fl!("bat-charge-limit")), horizontal()]
+        .spacing(5)
+        .align_y(Center);
+    for limit in CHARGE_LIMITS {
+        let is_current = limit == current;
+        buttons = buttons.push(
+            button(text(format!("{limit}%")))
+                .style(if is_current {
+                    button::primary
+                } else {
+                    button::subtle
+                })
+                .on_press_maybe((!is_current).then(|| {
+                    Message::Buttons(ButtonsMessage::SetChargeLimit(name.clone(), limit))
+                })),
+        );
+    }
+    Some(buttons)
+}
+
+/// Returns the plug or battery label for the current power source
+pub fn power_source(power_supply: &PowerSupply) -> Option<String> {
+    power_supply.on_ac.map(|on_ac| {
+        if on_ac {
+            format!(
+                "🔌️ {}",
+                fl!(
+                    "bat-on-ac",
+                    adapter = power_supply.adapter.clone().unwrap_or_default()
+                )
+            )
+        } else {
+            format!("🔋️ {}", fl!("bat-on-bat"))
+        }
+    })
+}
+
+fn fmt_duration(time: Duration) -> String {
+    let mins = time.as_secs() / 60;
+    format!("{}h{:02}m", mins / 60, mins % 60)
+}
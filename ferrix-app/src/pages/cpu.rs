@@ -25,6 +25,8 @@ use crate::{
     load_state::LoadState,
     messages::{ButtonsMessage, Message},
     widgets::{
+        glassy_container,
+        line_charts::LineChart,
         separated_view::SeparatedView,
         table::{InfoRow, fmt_bool, fmt_val, fmt_vec, kv_info_table},
     },
@@ -39,6 +41,7 @@ use iced::{
 pub fn proc_page<'a>(
     processors: &'a LoadState<Processors>,
     id: usize,
+    freq_chart: &'a LineChart,
 ) -> container::Container<'a, Message> {
     match processors {
         LoadState::Loaded(proc) => {
@@ -70,12 +73,29 @@ pub fn proc_page<'a>(
             .style(container::rounded_box)
             .width(Length::Fill)
             .padding(2);
-            let second_panel = proc_info(proc, id).style(container::rounded_box);
+            let second_panel = container(
+                column![
+                    proc_info(proc, id),
+                    text(fl!("cpu-isa")).style(text::warning),
+                    isa_info(proc, id),
+                ]
+                .spacing(5),
+            )
+            .style(container::rounded_box);
 
             let view = SeparatedView::new(first_panel, second_panel)
                 .set_fpane_id(super::Page::Processors.scrolled_list_id().unwrap_or(""))
                 .set_spane_id(super::Page::Processors.page_id());
-            container(view.view())
+            let mut page =
+                column![container(view.view()).height(Length::FillPortion(3))].spacing(5);
+            // The chart is empty on systems without `cpufreq` support
+            if freq_chart.series_count() > 0 {
+                page = page.push(
+                    glassy_container(fl!("cpu-freq-chart"), freq_chart.view())
+                        .height(Length::FillPortion(2)),
+                );
+            }
+            container(page)
         }
         LoadState::Error(why) => super::error_page(why),
         LoadState::Loading => super::loading_page(),
@@ -105,13 +125,16 @@ fn proc_info<'a>(proc: &'a Processors, id: usize) -> container::Container<'a, Me
     let rows = vec![
         InfoRow::new(fl!("cpu-model"), proc.model_name.clone()),
         InfoRow::new(fl!("cpu-vendor"), proc.vendor_id.clone()),
+        // Family, model ID, stepping and microcode identify the exact
+        // CPU revision (e.g. to check for microcode updates)
+        InfoRow::new(fl!("cpu-family"), fmt_val(proc.cpu_family)),
+        InfoRow::new(fl!("cpu-model-id"), fmt_val(proc.model)),
+        InfoRow::new(fl!("cpu-stepping"), fmt_val(proc.stepping)),
+        InfoRow::new(fl!("cpu-microcode"), proc.microcode.clone()),
         InfoRow::new(fl!("cpu-physical-id"), fmt_val(proc.physical_id)),
         InfoRow::new(fl!("cpu-core-id"), fmt_val(proc.core_id)),
         InfoRow::new(fl!("cpu-apicid"), fmt_val(proc.apicid)),
         InfoRow::new(fl!("cpu-iapicid"), fmt_val(proc.initial_apicid)),
-        InfoRow::new(fl!("cpu-family"), fmt_val(proc.cpu_family)),
-        InfoRow::new(fl!("cpu-stepping"), fmt_val(proc.stepping)),
-        InfoRow::new(fl!("cpu-microcode"), proc.microcode.clone()),
         InfoRow::new(fl!("cpu-freq"), Some(fl!("cpu-see-freq"))),
         InfoRow::new(fl!("cpu-cache"), fmt_val(proc.cache_size)),
         InfoRow::new(fl!("cpu-siblings"), fmt_val(proc.siblings)),
@@ -143,3 +166,47 @@ fn proc_info<'a>(proc: &'a Processors, id: usize) -> container::Container<'a, Me
     ];
     container(kv_info_table(rows))
 }
+
+/// Instruction set extensions shown on the page and `/proc/cpuinfo`
+/// flags which indicate their support
+#[cfg(not(target_arch = "aarch64"))]
+const ISA_FLAGS: &[(&str, &[&str])] = &[
+    ("SSE4.2", &["sse4_2"]),
+    ("AVX", &["avx"]),
+    ("AVX2", &["avx2"]),
+    ("AVX-512", &["avx512f"]),
+    ("FMA3", &["fma"]),
+    ("AES-NI", &["aes"]),
+    ("SHA", &["sha_ni"]),
+    ("VT-x/AMD-V", &["vmx", "svm"]),
+];
+
+#[cfg(target_arch = "aarch64")]
+const ISA_FLAGS: &[(&str, &[&str])] = &[
+    ("NEON", &["asimd"]),
+    ("AES", &["aes"]),
+    ("SHA2", &["sha2"]),
+    ("CRC32", &["crc32"]),
+    ("Atomics (LSE)", &["atomics"]),
+    ("SVE", &["sve"]),
+];
+
+fn isa_info<'a>(proc: &'a Processors, id: usize) -> container::Container<'a, Message> {
+    let physical_id = proc.entries[id].physical_id;
+    let package = proc
+        .packages
+        .iter()
+        .find(|p| p.physical_id == physical_id)
+        .or(proc.packages.first());
+
+    let rows = ISA_FLAGS
+        .iter()
+        .map(|(name, flags)| {
+            InfoRow::new(
+                *name,
+                fmt_bool(package.map(|p| flags.iter().any(|f| p.has_flag(f)))),
+            )
+        })
+        .collect::<Vec<_>>();
+    container(kv_info_table(rows))
+}
@@ -23,13 +23,17 @@
 use crate::{
     Message, fl,
     load_state::LoadState,
+    messages::ButtonsMessage,
     widgets::{
         header,
         table::{InfoRow, fmt_bool, fmt_val, fmt_vec, kv_info_table},
     },
 };
-use ferrix_lib::cpu_freq::CpuFreq;
-use iced::widget::{Id, column, container, scrollable, text};
+use ferrix_lib::cpu_freq::{CpuFreq, Policy};
+use iced::{
+    Alignment::Center,
+    widget::{Id, column, container, pick_list, row, scrollable, space::horizontal, text},
+};
 
 pub fn cpu_freq_page<'a>(cpu_freq: &'a LoadState<CpuFreq>) -> container::Container<'a, Message> {
     match cpu_freq {
@@ -102,12 +106,19 @@ pub fn cpu_freq_page<'a>(cpu_freq: &'a LoadState<CpuFreq>) -> container::Contain
                         fmt_val(policy.cpuinfo_transition_latency),
                     ),
                     InfoRow::new(fl!("cpufreq-set_speed"), policy.scaling_setspeed.clone()),
+                    InfoRow::new(
+                        fl!("cpufreq-affected"),
+                        fmt_vec(&Some(policy.affected_cpus.clone())),
+                    ),
                 ];
-                let policy_view = column![
+                let mut policy_view = column![
                     text(fl!("cpufreq-policy", cpu = idx)).style(text::warning),
                     container(kv_info_table(rows)).style(container::rounded_box),
                 ]
                 .spacing(5);
+                if let Some(selector) = governor_selector(policy) {
+                    policy_view = policy_view.push(selector);
+                }
                 policy_list = policy_list.push(policy_view);
                 idx += 1;
             }
@@ -123,6 +134,29 @@ pub fn cpu_freq_page<'a>(cpu_freq: &'a LoadState<CpuFreq>) -> container::Contain
     }
 }
 
+/// Governor is set for the first CPU core of the policy; the kernel
+/// applies it to all affected cores
+fn governor_selector<'a>(policy: &'a Policy) -> Option<row::Row<'a, Message>> {
+    let core = *policy.affected_cpus.first()?;
+    let governors = policy.available_governors();
+    if governors.is_empty() {
+        return None;
+    }
+
+    Some(
+        row![
+            text(fl!("cpufreq-set-gov")),
+            horizontal(),
+            pick_list(governors, policy.scaling_governor.clone(), move |gov| {
+                Message::Buttons(ButtonsMessage::SetGovernor(core, gov))
+            })
+            .padding(3),
+        ]
+        .spacing(5)
+        .align_y(Center),
+    )
+}
+
 fn fmt_freq(f: Option<u32>) -> Option<String> {
     f.and_then(|f| {
         let (freq, suf) = if f >= 1_000_000 {
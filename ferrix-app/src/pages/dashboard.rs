@@ -20,20 +20,21 @@
 
 //! Dashboard page
 
-use crate::{Message, Page, ferrix::FerrixData, fl, load_state::LoadState, widgets::card::Card};
-use ferrix_lib::{battery::Status, utils::Size};
+use crate::{
+    Message, Page, ferrix::FerrixData, fl, load_state::LoadState, settings::ByteUnit,
+    widgets::card::Card,
+};
+use ferrix_lib::battery::Status;
 use iced::widget::{Id, column, container, grid, progress_bar, scrollable, text};
 
 #[derive(Debug, Clone, Copy)]
 struct SwapUsage<'a> {
     name: &'a str,
-    size: Size,
-    used: Size,
-    size_b: f32,
-    used_b: f32,
+    size_b: u64,
+    used_b: u64,
 }
 
-pub fn dashboard<'a>(fx: &'a FerrixData) -> container::Container<'a, Message> {
+pub fn dashboard<'a>(fx: &'a FerrixData, byte_unit: ByteUnit) -> container::Container<'a, Message> {
     let (proc_name, proc_threads) = {
         match fx.proc_data.to_option() {
             Some(proc) => {
@@ -49,35 +50,26 @@ pub fn dashboard<'a>(fx: &'a FerrixData) -> container::Container<'a, Message> {
             None => ("N/A", 0),
         }
     };
-    let (total_ram, avail_ram) = {
-        match fx.ram_data.to_option() {
-            Some(ram) => (
-                ram.total.round(2).unwrap_or(Size::None),
-                ram.available
-                    .round(2)
-                    .unwrap_or(ferrix_lib::utils::Size::None),
-            ),
-            None => (Size::None, Size::None),
-        }
+    let (total_ram_bytes, avail_ram_bytes) = match fx.ram_data.to_option() {
+        Some(ram) => (
+            ram.total.get_bytes2().unwrap_or(0),
+            ram.available.get_bytes2().unwrap_or(0),
+        ),
+        None => (0, 0),
     };
-    let total_ram_bytes = total_ram.get_bytes2().unwrap_or(0) as f32;
-    let avail_ram_bytes = avail_ram.get_bytes2().unwrap_or(0) as f32;
-
-    let used_ram_bytes = total_ram_bytes - avail_ram_bytes;
-    let used_ram = Size::B(used_ram_bytes as u64)
-        .round(2)
-        .unwrap_or(Size::B(used_ram_bytes as u64));
+    let used_ram_bytes = total_ram_bytes.saturating_sub(avail_ram_bytes);
+    let total_ram = byte_unit.format(total_ram_bytes);
+    let used_ram = byte_unit.format(used_ram_bytes);
+    let (total_ram_bytes, used_ram_bytes) = (total_ram_bytes as f32, used_ram_bytes as f32);
 
     let swaps_usage = match fx.swap_data.to_option() {
         Some(swaps) => {
             let mut usage = Vec::with_capacity(swaps.swaps.len());
             for swap in &swaps.swaps {
-                let size_b = swap.size.get_bytes2().unwrap_or(0) as f32;
-                let used_b = swap.used.get_bytes2().unwrap_or(0) as f32;
+                let size_b = swap.size.get_bytes2().unwrap_or(0);
+                let used_b = swap.used.get_bytes2().unwrap_or(0);
                 usage.push(SwapUsage {
                     name: &swap.filename,
-                    size: swap.size,
-                    used: swap.used,
                     size_b,
                     used_b,
                 });
@@ -89,10 +81,7 @@ pub fn dashboard<'a>(fx: &'a FerrixData) -> container::Container<'a, Message> {
 
     let os_name = {
         match fx.osrel_data.to_option() {
-            Some(osr) => match &osr.pretty_name {
-                Some(pname) => pname,
-                None => &osr.name,
-            },
+            Some(osr) => osr.pretty_name().unwrap_or(osr.name()),
             None => "Generic Linux",
         }
     };
@@ -114,13 +103,29 @@ pub fn dashboard<'a>(fx: &'a FerrixData) -> container::Container<'a, Message> {
     let cpu_usage = if prev_stat.is_none() || cur_stat.is_none() {
         0.0
     } else {
-        let prev_stat = prev_stat.unwrap();
-        let cur_stat = cur_stat.unwrap();
+        cur_stat.unwrap().total_usage(prev_stat.unwrap())
+    };
 
-        match &cur_stat.cpu {
-            Some(cpu) => cpu.usage_percentage(prev_stat.cpu),
-            None => 0.0,
+    let loadavg = fx
+        .system
+        .to_option()
+        .and_then(|system| system.loadavg.as_ref());
+    let load = match loadavg {
+        Some(lavg) => {
+            let (ratio, _, _) = lavg.saturation(proc_threads);
+            let label = text(fl!(
+                "dash-load-info",
+                load = format!("{:.2} / {:.2} / {:.2}", lavg.0, lavg.1, lavg.2),
+                ratio = format!("{:.0}", ratio * 100.)
+            ));
+            // The number of CPUs is unknown until processors are loaded
+            if proc_threads > 0 && lavg.is_overloaded(proc_threads) {
+                label.style(text::danger)
+            } else {
+                label
+            }
         }
+        None => text("N/A"),
     };
 
     let battery = match fx.bat_data.to_option() {
@@ -169,11 +174,12 @@ pub fn dashboard<'a>(fx: &'a FerrixData) -> container::Container<'a, Message> {
             ]
             .spacing(5),
         ),
+        Card::new(fl!("dash-load"), Message::SelectPage(Page::SystemMisc)).widget(load),
         Card::new(fl!("dash-mem"), Message::SelectPage(Page::Memory)).widget(
             column![
                 column![
-                    text(fl!("dash-mem-used", used = used_ram.to_string())),
-                    text(fl!("dash-mem-total", total = total_ram.to_string())),
+                    text(fl!("dash-mem-used", used = used_ram)),
+                    text(fl!("dash-mem-total", total = total_ram)),
                 ],
                 progress_bar(0.0..=total_ram_bytes, used_ram_bytes),
             ]
@@ -186,10 +192,11 @@ pub fn dashboard<'a>(fx: &'a FerrixData) -> container::Container<'a, Message> {
 
     /* 0 - CPU,
      * 1 - CPU Usage,
-     * 2 - RAM Usage,
-     * 3...n - Swap usage
+     * 2 - Load average,
+     * 3 - RAM Usage,
+     * 4...n - Swap usage
      */
-    let mut offset = 3;
+    let mut offset = 4;
     for swap in swaps_usage {
         items.insert(
             offset,
@@ -197,16 +204,10 @@ pub fn dashboard<'a>(fx: &'a FerrixData) -> container::Container<'a, Message> {
                 column![
                     column![
                         text(swap.name),
-                        text(fl!(
-                            "dash-mem-used",
-                            used = swap.used.round(2).unwrap_or_default().to_string()
-                        )),
-                        text(fl!(
-                            "dash-mem-total",
-                            total = swap.size.round(2).unwrap_or_default().to_string()
-                        )),
+                        text(fl!("dash-mem-used", used = byte_unit.format(swap.used_b))),
+                        text(fl!("dash-mem-total", total = byte_unit.format(swap.size_b))),
                     ],
-                    progress_bar(0.0..=swap.size_b, swap.used_b),
+                    progress_bar(0.0..=swap.size_b as f32, swap.used_b as f32),
                 ]
                 .spacing(5),
             ),
@@ -225,6 +226,16 @@ pub fn dashboard<'a>(fx: &'a FerrixData) -> container::Container<'a, Message> {
             ),
         );
     }
+    if let Some(power_source) = fx
+        .bat_data
+        .to_option()
+        .and_then(|bat| super::battery::power_source(&bat.power_supply))
+    {
+        items.push(
+            Card::new(fl!("dash-power"), Message::SelectPage(Page::Battery))
+                .widget(text(power_source)),
+        );
+    }
 
     if let LoadState::Loaded(storages) = &fx.storages {
         let storages = &storages.mounts;
@@ -233,10 +244,10 @@ pub fn dashboard<'a>(fx: &'a FerrixData) -> container::Container<'a, Message> {
                 let (usage_percent, used, total) = match &storage.fstats {
                     Some(fstats) => (
                         fstats.usage_percent() as f32,
-                        fstats.used_size().round(2).unwrap_or_default(),
-                        fstats.total_size().round(2).unwrap_or_default(),
+                        byte_unit.format(fstats.used_bytes()),
+                        byte_unit.format(fstats.total_bytes()),
                     ),
-                    None => (0., Size::None, Size::None),
+                    None => (0., byte_unit.format(0), byte_unit.format(0)),
                 };
 
                 items.push(
@@ -251,8 +262,8 @@ pub fn dashboard<'a>(fx: &'a FerrixData) -> container::Container<'a, Message> {
                     .widget(
                         column![
                             column![
-                                text(fl!("dash-mem-used", used = used.to_string())),
-                                text(fl!("dash-mem-total", total = total.to_string()))
+                                text(fl!("dash-mem-used", used = used)),
+                                text(fl!("dash-mem-total", total = total))
                             ],
                             progress_bar(0.0..=100., usage_percent),
                         ]
@@ -0,0 +1,71 @@
+/* dev.rs
+ *
+ * Copyright 2026 Michail Krasnov <mskrasnov07@ya.ru>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Development tools page
+
+use crate::{DataLoadingState, Message, fl, widgets::table::hdr_name};
+use ferrix_lib::devtools::{Toolchain, Toolchains};
+
+use iced::{
+    Element, Font, Length,
+    widget::{Id, container, scrollable, table, text},
+};
+
+pub fn dev_page<'a>(
+    toolchains: &'a DataLoadingState<Toolchains>,
+) -> container::Container<'a, Message> {
+    match toolchains {
+        DataLoadingState::Loaded(toolchains) => {
+            let table = container(dev_table(&toolchains.list)).style(container::rounded_box);
+            container(
+                scrollable(table)
+                    .spacing(5)
+                    .id(Id::new(super::Page::Development.page_id())),
+            )
+        }
+        DataLoadingState::Error(why) => super::error_page(why),
+        DataLoadingState::Loading => super::loading_page(),
+    }
+}
+
+fn dev_table<'a>(rows: &'a [Toolchain]) -> Element<'a, Message> {
+    let columns = [
+        table::column(hdr_name(fl!("dev-hdr-name")), |row: &'a Toolchain| {
+            text(&row.name).font(Font::MONOSPACE)
+        }),
+        table::column(
+            hdr_name(fl!("dev-hdr-version")),
+            |row: &'a Toolchain| match &row.version {
+                Some(version) => text(version),
+                None if row.path.is_some() => text(fl!("dev-unknown")).style(text::secondary),
+                None => text(fl!("dev-not-installed")).style(text::secondary),
+            },
+        ),
+        table::column(hdr_name(fl!("dev-hdr-path")), |row: &'a Toolchain| {
+            text(match &row.path {
+                Some(path) => path.display().to_string(),
+                None => String::new(),
+            })
+            .font(Font::MONOSPACE)
+        })
+        .width(Length::Fill),
+    ];
+    table(columns, rows).padding(2).width(Length::Fill).into()
+}
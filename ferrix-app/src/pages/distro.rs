@@ -21,22 +21,28 @@
 //! Page with information about installed Linux distro
 
 use crate::{
-    Message, fl,
+    Message, fl, icons,
     load_state::DataLoadingState,
     widgets::table::{InfoRow, kv_info_table},
 };
-use ferrix_lib::sys::OsRelease;
+use ferrix_lib::{init::BootTimes, sys::OsRelease};
 
-use iced::widget::{Id, column, container, scrollable};
+use iced::{
+    Element,
+    widget::{Id, column, container, image, scrollable, svg, text},
+};
+use std::time::Duration;
 
 pub fn distro_page<'a>(
     osrel: &'a DataLoadingState<OsRelease>,
+    boot_times: &'a DataLoadingState<BootTimes>,
 ) -> container::Container<'a, Message> {
     match osrel {
         DataLoadingState::Loaded(osrel) => {
             let mut os_data = column![].spacing(5);
+            os_data = os_data.push(logo(osrel));
             let rows = vec![
-                InfoRow::new(fl!("distro-name"), Some(osrel.name.clone())),
+                InfoRow::new(fl!("distro-name"), Some(osrel.name().to_string())),
                 InfoRow::new(fl!("distro-id"), osrel.id.clone()),
                 InfoRow::new(fl!("distro-like"), osrel.id_like.clone()),
                 InfoRow::new(fl!("distro-cpe"), osrel.cpe_name.clone()),
@@ -57,9 +63,21 @@ pub fn distro_page<'a>(
                 InfoRow::new(fl!("distro-logo"), osrel.logo.clone()),
                 InfoRow::new(fl!("distro-def-host"), osrel.default_hostname.clone()),
                 InfoRow::new(fl!("distro-sysext-lvl"), osrel.sysext_level.clone()),
+                InfoRow::new(fl!("distro-ansi-color"), osrel.ansi_color.clone()),
             ];
 
             os_data = os_data.push(container(kv_info_table(rows)).style(container::rounded_box));
+            match boot_times {
+                DataLoadingState::Loaded(times) => {
+                    os_data = os_data.push(boot_times_table(times));
+                }
+                DataLoadingState::Error(why) => {
+                    os_data = os_data.push(
+                        text(fl!("distro-boot-error", error = why.as_str())).style(text::secondary),
+                    );
+                }
+                DataLoadingState::Loading => {}
+            }
             container(
                 scrollable(os_data)
                     .spacing(5)
@@ -70,3 +88,68 @@ pub fn distro_page<'a>(
         DataLoadingState::Loading => super::loading_page(),
     }
 }
+
+fn boot_times_table<'a>(times: &BootTimes) -> Element<'a, Message> {
+    let fmt = |time: Option<Duration>| time.map(|time| format!("{:.3}s", time.as_secs_f64()));
+    let rows = vec![
+        InfoRow::new(fl!("distro-boot-firmware"), fmt(times.firmware)),
+        InfoRow::new(fl!("distro-boot-loader"), fmt(times.loader)),
+        InfoRow::new(fl!("distro-boot-kernel"), fmt(Some(times.kernel))),
+        InfoRow::new(fl!("distro-boot-initrd"), fmt(times.initrd)),
+        InfoRow::new(
+            fl!("distro-boot-userspace"),
+            fmt(times.userspace).or(Some(fl!("distro-boot-not-finished"))),
+        ),
+        InfoRow::new(fl!("distro-boot-total"), fmt(times.total())),
+    ];
+    column![
+        text(fl!("distro-boot-hdr")).style(text::warning),
+        container(kv_info_table(rows)).style(container::rounded_box),
+    ]
+    .spacing(5)
+    .into()
+}
+
+/// Size of the distro logo (in pixels)
+const LOGO_SIZE: f32 = 64.;
+
+/// Returns the bundled logo of the distro or of the distro it is
+/// based on (`ID_LIKE`)
+fn bundled_logo(osrel: &OsRelease) -> Option<&'static [u8]> {
+    let mut ids = osrel.id().into_iter().chain(osrel.id_like());
+    ids.find_map(|id| match id {
+        "ubuntu" => Some(icons::UBUNTU_LOGO),
+        "fedora" => Some(icons::FEDORA_LOGO),
+        "arch" => Some(icons::ARCH_LOGO),
+        "debian" => Some(icons::DEBIAN_LOGO),
+        "linuxmint" => Some(icons::LINUXMINT_LOGO),
+        "manjaro" => Some(icons::MANJARO_LOGO),
+        id if id.starts_with("opensuse") || id == "suse" => Some(icons::OPENSUSE_LOGO),
+        _ => None,
+    })
+}
+
+/// Bundled logo is preferred. Then the logo from the icon theme
+/// (`LOGO` key) and Tux for unknown distros
+fn logo<'a>(osrel: &OsRelease) -> Element<'a, Message> {
+    let symbolic = |bytes: &'static [u8]| -> Element<'a, Message> {
+        svg(svg::Handle::from_memory(bytes))
+            .style(|theme: &iced::Theme, _| svg::Style {
+                color: Some(theme.palette().text),
+            })
+            .width(LOGO_SIZE)
+            .height(LOGO_SIZE)
+            .into()
+    };
+
+    if let Some(bytes) = bundled_logo(osrel) {
+        return symbolic(bytes);
+    }
+    match osrel.logo_path() {
+        Some(path) if path.extension().is_some_and(|ext| ext == "svg") => {
+            svg(path).width(LOGO_SIZE).height(LOGO_SIZE).into()
+        }
+        Some(path) => image(path).width(LOGO_SIZE).height(LOGO_SIZE).into(),
+        None => symbolic(icons::TUX_LOGO),
+    }
+}
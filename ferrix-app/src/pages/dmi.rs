@@ -26,11 +26,11 @@ use crate::{
     fl,
     widgets::{
         header,
-        table::{InfoRow, fmt_bool, fmt_val, fmt_vec, hdr_name, text_fmt_val},
+        table::{InfoRow, MissingReason, fmt_bool, fmt_val, fmt_vec, hdr_name, row_value},
     },
 };
 use ferrix_lib::dmi::{
-    Baseboard, Bios, Chassis, ChassisSecurityStatusData, ChassisStateData, Processor,
+    Baseboard, Bios, Chassis, ChassisSecurityStatusData, ChassisStateData, DMIExtras, Processor,
 };
 use iced::{
     Element, Length,
@@ -44,9 +44,10 @@ pub fn dmi_page<'a>(dmi: &'a DataLoadingState<DMIData>) -> container::Container<
             let baseboard = baseboard_table(&data.baseboard);
             let chassis = chassis_table(&data.chassis);
             let proc = processor_table(&data.processor);
+            let extras = extras_table(&data.extras);
 
             container(
-                scrollable(column![bios, baseboard, chassis, proc,].spacing(5))
+                scrollable(column![bios, baseboard, chassis, proc, extras,].spacing(5))
                     .spacing(5)
                     .id(Id::new(super::Page::DMI.page_id())),
             )
@@ -424,11 +425,34 @@ fn chassis_table<'a>(c: &'a DataLoadingState<Chassis>) -> container::Container<'
                 None => container(text("Unknown chassis type").style(text::danger)),
             };
 
+            let contained_elements = if c.contained_elements.is_empty() {
+                container(text("No contained elements").style(text::secondary))
+            } else {
+                let count = |cnt: Option<u8>| cnt.map_or("reserved".to_string(), |n| n.to_string());
+                let rows = c
+                    .contained_elements
+                    .iter()
+                    .map(|e| {
+                        InfoRow::new(
+                            format!("{} (raw: 0x{:02X})", e.element_type, e.raw),
+                            Some(format!(
+                                "min: {}, max: {}",
+                                count(e.minimum),
+                                count(e.maximum)
+                            )),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                container(kv_info_table(rows)).style(container::rounded_box)
+            };
+
             container(
                 column![
                     text("Summary").style(text::warning),
                     container(kv_info_table(rows)).style(container::rounded_box),
                     chassis_type,
+                    text("Contained elements").style(text::warning),
+                    contained_elements,
                 ]
                 .spacing(5),
             )
@@ -666,6 +690,50 @@ fn processor_characteristics_table<'a>(p: &'a Processor) -> container::Container
     }
 }
 
+fn extras_table<'a>(e: &'a DataLoadingState<DMIExtras>) -> container::Container<'a, Message> {
+    let e_data = match e {
+        DataLoadingState::Loading => container(text(fl!("ldr-page-tooltip"))),
+        DataLoadingState::Error(why) => container(text(why).style(text::danger)),
+        DataLoadingState::Loaded(e) => {
+            let oem_rows = e
+                .oem_strings
+                .iter()
+                .enumerate()
+                .map(|(i, s)| InfoRow::new(format!("String #{}", i + 1), Some(s.clone())))
+                .collect::<Vec<_>>();
+            let opts_rows = e
+                .configuration_options
+                .iter()
+                .enumerate()
+                .map(|(i, s)| InfoRow::new(format!("Option #{}", i + 1), Some(s.clone())))
+                .collect::<Vec<_>>();
+
+            let oem_strings = if oem_rows.is_empty() {
+                container(text("OEM strings are not provided").style(text::secondary))
+            } else {
+                container(kv_info_table(oem_rows)).style(container::rounded_box)
+            };
+            let config_options = if opts_rows.is_empty() {
+                container(text("Configuration options are not provided").style(text::secondary))
+            } else {
+                container(kv_info_table(opts_rows)).style(container::rounded_box)
+            };
+
+            container(
+                column![
+                    text("OEM strings (Type 11)").style(text::warning),
+                    oem_strings,
+                    text("System configuration options (Type 12)").style(text::warning),
+                    config_options,
+                ]
+                .spacing(5),
+            )
+        }
+    };
+
+    container(column![header("OEM data"), e_data,].spacing(5))
+}
+
 /*******************************************************
  *******************************************************/
 
@@ -679,7 +747,9 @@ where
         })
         .width(Length::FillPortion(2)),
         table::column(hdr_name(fl!("hdr-value")), |row: InfoRow<V>| {
-            text_fmt_val(row.value)
+            // The whole table is loaded, so missing values are just
+            // absent in the DMI structure
+            row_value(row.or_missing(MissingReason::NotPresent))
         })
         .width(Length::FillPortion(5)),
     ];
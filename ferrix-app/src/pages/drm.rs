@@ -27,18 +27,24 @@ use crate::{
         table::{InfoRow, fmt_val, kv_info_table},
     },
 };
-use ferrix_lib::drm::{DRM, EDID, Video, VideoInputParams};
+use ferrix_lib::{
+    drm::{DRM, EDID, GPU, Video, VideoInputParams},
+    utils::Size,
+};
 use iced::widget::{Id, center, column, container, scrollable, text};
 
 pub fn drm_page<'a>(video: &'a DataLoadingState<Video>) -> container::Container<'a, Message> {
     match video {
         DataLoadingState::Loaded(video) => {
-            if video.devices.is_empty() {
+            if video.devices.is_empty() && video.cards.is_empty() {
                 container(center(
                     text(fl!("drm-is-empty")).size(16).style(text::secondary),
                 ))
             } else {
                 let mut layout = column![].spacing(5);
+                for card in &video.cards {
+                    layout = layout.push(gpu_subpage(card));
+                }
                 let mut i = 1;
                 for device in &video.devices {
                     layout = layout.push(screen_subpage(device, i));
@@ -56,6 +62,27 @@ pub fn drm_page<'a>(video: &'a DataLoadingState<Video>) -> container::Container<
     }
 }
 
+fn gpu_subpage<'a>(gpu: &'a GPU) -> container::Container<'a, Message> {
+    let rows = vec![
+        InfoRow::new(fl!("drm-gpu-driver"), gpu.driver.clone()),
+        InfoRow::new(fl!("drm-gpu-pci-id"), gpu.pci_id.clone()),
+        InfoRow::new(
+            fl!("drm-gpu-vram"),
+            gpu.vram_total
+                .and_then(|vram| Size::B(vram).round(2))
+                .map(|vram| vram.to_string()),
+        ),
+    ];
+
+    container(
+        column![
+            header(fl!("drm-gpu-title", name = gpu.name.as_str())),
+            container(kv_info_table(rows)).style(container::rounded_box),
+        ]
+        .spacing(5),
+    )
+}
+
 fn screen_subpage<'a>(drm: &'a DRM, idx: usize) -> container::Container<'a, Message> {
     let mut layout = column![header(fl!("drm-title", idx = idx)),].spacing(5);
 
@@ -77,6 +104,11 @@ fn screen_subpage<'a>(drm: &'a DRM, idx: usize) -> container::Container<'a, Mess
     };
 
     if drm.enabled {
+        let rows = vec![InfoRow::new(
+            fl!("drm-current-mode"),
+            drm.current_mode.map(|mode| mode.to_string()),
+        )];
+        layout = layout.push(container(kv_info_table(rows)).style(container::rounded_box));
         layout = layout.push(text(fl!("drm-modes")).style(text::warning));
         layout = layout.push(support_modes_table(&drm.modes));
     }
@@ -94,6 +126,15 @@ fn support_modes_table<'a>(modes: &'a [String]) -> container::Container<'a, Mess
 
 fn edid_summary_table<'a>(edid: &'a EDID) -> container::Container<'a, Message> {
     let rows = vec![
+        InfoRow::new(
+            fl!("drm-model"),
+            edid.product_name
+                .as_ref()
+                .map(|name| match edid.diagonal_inches() {
+                    Some(diag) => format!("{name}, {diag:.0}\""),
+                    None => name.clone(),
+                }),
+        ),
         InfoRow::new(fl!("drm-manufacturer"), Some(edid.manufacturer.clone())),
         InfoRow::new(fl!("drm-pcode"), fmt_val(Some(edid.product_code))),
         InfoRow::new(fl!("drm-snum"), Some(format!("{:X}", edid.serial_number))),
@@ -107,6 +148,13 @@ fn edid_summary_table<'a>(edid: &'a EDID) -> container::Container<'a, Message> {
             fl!("drm-size"),
             Some(format!("{}x{}", edid.hscreen_size, edid.vscreen_size)),
         ),
+        InfoRow::new(
+            fl!("drm-size-mm"),
+            match (edid.width_mm, edid.height_mm) {
+                (Some(w), Some(h)) => Some(format!("{w}x{h}")),
+                _ => None,
+            },
+        ),
         InfoRow::new(fl!("drm-gamma"), fmt_val(Some(edid.display_gamma))),
     ];
     container(kv_info_table(rows)).style(container::rounded_box)
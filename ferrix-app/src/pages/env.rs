@@ -21,30 +21,62 @@
 //! Environment variables list
 
 use crate::{
-    Message,
+    Message, fl,
     load_state::DataLoadingState,
-    widgets::table::{InfoRow, kv_info_table},
+    messages::{ButtonsMessage, DataReceiverMessage},
+    widgets::{
+        filterable_table::FilterableTable,
+        table::{InfoRow, kv_info_table},
+    },
 };
 
-use iced::widget::{Id, container, scrollable};
+use iced::widget::{container, toggler};
+
+/// Parts of variable names which values shouldn't be shown by default
+const SECRET_KEYS: &[&str] = &["TOKEN", "KEY", "SECRET", "PASSWORD"];
 
 pub fn env_page<'a>(
     system: &'a DataLoadingState<crate::System>,
+    filter: &'a str,
+    show_secrets: bool,
 ) -> container::Container<'a, Message> {
     match system {
         DataLoadingState::Loaded(sys) => {
-            let mut rows = Vec::with_capacity(sys.env_vars.len());
-            for var in &sys.env_vars {
-                rows.push(InfoRow::new(&var.0, Some(var.1.to_string())));
-            }
-            let table = container(kv_info_table(rows)).style(container::rounded_box);
-            container(
-                scrollable(table)
-                    .spacing(5)
-                    .id(Id::new(super::Page::Environment.page_id())),
-            )
+            let secrets = toggler(show_secrets)
+                .label(fl!("env-show-secrets"))
+                .on_toggle(|show| Message::Buttons(ButtonsMessage::ChangeEnvSecretsShow(show)));
+
+            let table = FilterableTable::new(&sys.env_vars, filter, |q| {
+                Message::DataReceiver(DataReceiverMessage::EnvFilterChanged(q))
+            })
+            .set_placeholder(fl!("env-filter"))
+            .push_control(secrets)
+            .set_id(super::Page::Environment.page_id())
+            .view(
+                |(key, _)| key.as_str(),
+                |vars| {
+                    let rows = vars
+                        .into_iter()
+                        .map(|(key, val)| {
+                            let val = if !show_secrets && is_secret(key) {
+                                "••••••••".to_string()
+                            } else {
+                                val.to_string()
+                            };
+                            InfoRow::new(key, Some(val))
+                        })
+                        .collect();
+                    container(kv_info_table(rows)).style(container::rounded_box)
+                },
+            );
+            container(table)
         }
         DataLoadingState::Error(why) => super::error_page(why),
         DataLoadingState::Loading => super::loading_page(),
     }
 }
+
+fn is_secret(key: &str) -> bool {
+    let key = key.to_uppercase();
+    SECRET_KEYS.iter().any(|secret| key.contains(secret))
+}
@@ -21,27 +21,50 @@
 //! Export Manager page
 
 use crate::{
-    export::{ExportFormat, ExportMode},
+    export::{ExportFormat, ExportMode, Section},
     messages::{ExportManagerMessage, Message},
 };
-use iced::widget::{button, column, container, pick_list, row, text};
+use iced::widget::{Column, button, column, container, pick_list, row, text, toggler};
+
+pub fn export_page<'a>(
+    format: &ExportFormat,
+    mode: &ExportMode,
+) -> container::Container<'a, Message> {
+    let modes = vec![
+        ExportMode::AllData,
+        match mode {
+            ExportMode::Sections(_) => mode.clone(),
+            ExportMode::AllData => ExportMode::default_sections(),
+        },
+    ];
+    let sections = match mode {
+        ExportMode::Sections(selected) => {
+            Column::with_children(Section::ALL.iter().map(|&section| {
+                toggler(selected.contains(&section))
+                    .label(section.to_string())
+                    .on_toggle(move |export| {
+                        Message::ExportManager(ExportManagerMessage::ExportSectionToggled(
+                            section, export,
+                        ))
+                    })
+                    .into()
+            }))
+            .spacing(5)
+        }
+        ExportMode::AllData => Column::new(),
+    };
 
-pub fn export_page<'a>() -> container::Container<'a, Message> {
     container(
         column![
             text("На данный момент будут экспортированы только собранные данные!"),
             row![
                 column![text("Формат экспорта:"), text("Экспортируемые данные:"),].spacing(7),
                 column![
-                    pick_list(
-                        ExportFormat::ALL,
-                        Some(ExportFormat::CompressedJson),
-                        |fmt| Message::ExportManager(ExportManagerMessage::ExportFormatSelected(
-                            fmt
-                        )),
-                    )
+                    pick_list(ExportFormat::ALL, Some(format.clone()), |fmt| {
+                        Message::ExportManager(ExportManagerMessage::ExportFormatSelected(fmt))
+                    },)
                     .padding(2),
-                    pick_list(ExportMode::ALL, Some(ExportMode::AllData), |mode| {
+                    pick_list(modes, Some(mode.clone()), |mode| {
                         Message::ExportManager(ExportManagerMessage::ExportModeSelected(mode))
                     },)
                     .padding(2),
@@ -49,9 +72,16 @@ pub fn export_page<'a>() -> container::Container<'a, Message> {
                 .spacing(5),
             ]
             .spacing(5),
-            button("Экспорт").on_press(Message::ExportManager(ExportManagerMessage::ExportData(
-                "export.json".to_string()
-            ))),
+            sections,
+            row![
+                button("Экспорт").on_press(Message::ExportManager(
+                    ExportManagerMessage::ExportData(format!("export.{}", format.extension()))
+                )),
+                button("Копировать в буфер обмена").on_press(Message::ExportManager(
+                    ExportManagerMessage::CopyToClipboard
+                )),
+            ]
+            .spacing(5),
         ]
         .spacing(5),
     )
@@ -23,13 +23,20 @@
 use crate::{
     Message, fl,
     load_state::DataLoadingState,
-    widgets::table::{InfoRow, fmt_val, hdr_name, kv_info_table, text_fmt_val},
+    messages::{ButtonsMessage, DataReceiverMessage},
+    widgets::{
+        filterable_table::FilterableTable,
+        table::{
+            InfoRow, SortState, fmt_bool, fmt_val, hdr_name, kv_info_table, sortable_hdr,
+            text_fmt_val,
+        },
+    },
 };
 use ferrix_lib::sys::{KModules, Kernel, Module};
 
 use iced::{
     Length,
-    widget::{Id, center, container, scrollable, table, text},
+    widget::{Id, button, center, column, container, row, scrollable, space, table, text},
 };
 
 pub fn kernel_page<'a>(
@@ -43,6 +50,9 @@ pub fn kernel_page<'a>(
                 InfoRow::new(fl!("kernel-arch"), kern.arch.clone()),
                 InfoRow::new(fl!("kernel-version"), kern.version.clone()),
                 InfoRow::new(fl!("kernel-build"), kern.build_info.clone()),
+                InfoRow::new(fl!("kernel-build-date"), kern.build_date.clone()),
+                InfoRow::new(fl!("kernel-compiler"), kern.compiler.clone()),
+                InfoRow::new(fl!("kernel-config"), fmt_bool(Some(kern.has_config))),
                 InfoRow::new(fl!("kernel-pid-max"), fmt_val(Some(kern.pid_max))),
                 InfoRow::new(fl!("kernel-threads-max"), fmt_val(Some(kern.threads_max))),
                 InfoRow::new(fl!("kernel-user-evs"), fmt_val(kern.user_events_max)),
@@ -60,7 +70,19 @@ pub fn kernel_page<'a>(
     }
 }
 
-pub fn kmods_page<'a>(kmods: &'a DataLoadingState<KModules>) -> container::Container<'a, Message> {
+/// Columns by which the modules list can be sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KModsSortColumn {
+    Name,
+    Size,
+}
+
+pub fn kmods_page<'a>(
+    kmods: &'a DataLoadingState<KModules>,
+    selected: &'a Option<String>,
+    filter: &'a str,
+    sort: SortState<KModsSortColumn>,
+) -> container::Container<'a, Message> {
     match kmods {
         DataLoadingState::Loaded(kmods) => {
             if kmods.modules.is_empty() {
@@ -70,12 +92,28 @@ pub fn kmods_page<'a>(kmods: &'a DataLoadingState<KModules>) -> container::Conta
                         .style(text::secondary),
                 ))
             } else {
-                let table = container(modules_table(&kmods.modules)).style(container::rounded_box);
-                container(
-                    scrollable(table)
-                        .spacing(5)
-                        .id(Id::new(super::Page::KModules.page_id())),
-                )
+                let table = FilterableTable::new(&kmods.modules, filter, |q| {
+                    Message::DataReceiver(DataReceiverMessage::KModsFilterChanged(q))
+                })
+                .set_placeholder(fl!("kmod-filter"))
+                .set_id(super::Page::KModules.page_id())
+                .view(
+                    |module| module.name.as_str(),
+                    |modules| {
+                        let mut content = column![].spacing(5);
+                        if let Some(module) = selected
+                            .as_ref()
+                            .and_then(|name| kmods.modules.iter().find(|m| &m.name == name))
+                        {
+                            content = content.push(module_params(module));
+                        }
+                        content.push(
+                            container(modules_table(modules, selected, sort))
+                                .style(container::rounded_box),
+                        )
+                    },
+                );
+                container(table)
             }
         }
         DataLoadingState::Error(why) => super::error_page(why),
@@ -83,25 +121,69 @@ pub fn kmods_page<'a>(kmods: &'a DataLoadingState<KModules>) -> container::Conta
     }
 }
 
-fn modules_table<'a>(rows: &'a [Module]) -> table::Table<'a, Message> {
-    let columns = [
-        table::column(hdr_name(fl!("kmod-name")), |row: &'a Module| {
-            text(&row.name).wrapping(text::Wrapping::WordOrGlyph)
+/// Parameters of the selected module
+fn module_params<'a>(module: &'a Module) -> container::Container<'a, Message> {
+    let header = row![
+        text(fl!("kmod-params", name = module.name.as_str())).style(text::warning),
+        space::horizontal(),
+        button(text(fl!("kmod-params-close")))
+            .on_press(Message::Buttons(ButtonsMessage::KModuleSelected(None)))
+            .style(button::secondary)
+            .padding(2),
+    ];
+    let params = if module.parameters.is_empty() {
+        container(text(fl!("kmod-params-empty")).style(text::secondary))
+    } else {
+        let rows = module
+            .parameters
+            .iter()
+            .map(|(key, val)| InfoRow::new(key, val.clone()))
+            .collect::<Vec<_>>();
+        container(kv_info_table(rows)).style(container::rounded_box)
+    };
+    container(column![header, params].spacing(5))
+}
+
+fn modules_table<'a>(
+    mut rows: Vec<&'a Module>,
+    selected: &'a Option<String>,
+    sort: SortState<KModsSortColumn>,
+) -> table::Table<'a, Message> {
+    rows.sort_by(|a, b| {
+        sort.apply(match sort.column {
+            KModsSortColumn::Name => a.name.cmp(&b.name),
+            KModsSortColumn::Size => a.size.get_bytes2().cmp(&b.size.get_bytes2()),
         })
+    });
+    let on_sort = |column| Message::Buttons(ButtonsMessage::KModsSortChanged(column));
+
+    let columns = [
+        table::column(
+            sortable_hdr(fl!("kmod-name"), KModsSortColumn::Name, &sort, on_sort),
+            move |row: &'a Module| {
+                let is_selected = selected.as_ref() == Some(&row.name);
+                button(text(&row.name).wrapping(text::Wrapping::WordOrGlyph))
+                    .on_press(Message::Buttons(ButtonsMessage::KModuleSelected(
+                        (!is_selected).then(|| row.name.clone()),
+                    )))
+                    .style(if is_selected {
+                        button::subtle
+                    } else {
+                        button::text
+                    })
+                    .padding(0)
+            },
+        )
         .width(Length::FillPortion(1)),
-        table::column(hdr_name(fl!("kmod-size")), |row: &'a Module| {
-            text_fmt_val(row.size.round(2))
-        }),
+        table::column(
+            sortable_hdr(fl!("kmod-size"), KModsSortColumn::Size, &sort, on_sort),
+            |row: &'a Module| text_fmt_val(row.size.round(2)),
+        ),
         table::column(hdr_name(fl!("kmod-instances")), |row: &'a Module| {
             text(row.instances)
         }),
-        table::column(hdr_name(fl!("kmod-depends")), |row: &'a Module| {
-            text(if &row.dependencies == "-" {
-                ""
-            } else {
-                &row.dependencies
-            })
-            .wrapping(text::Wrapping::WordOrGlyph)
+        table::column(hdr_name(fl!("kmod-used-by")), |row: &'a Module| {
+            text(row.used_by.join(", ")).wrapping(text::Wrapping::WordOrGlyph)
         })
         .width(Length::FillPortion(3)),
         table::column(hdr_name(fl!("kmod-state")), |row: &'a Module| {
@@ -23,76 +23,134 @@
 use crate::{
     Message, fl,
     load_state::DataLoadingState,
+    messages::ButtonsMessage,
+    settings::ByteUnit,
     widgets::{
         header,
         table::{InfoRow, fmt_val, kv_info_table},
     },
 };
-use ferrix_lib::ram::{RAM, Swaps};
-use iced::widget::{column, container, scrollable, text};
+use ferrix_lib::ram::{RAM, SwapKind, Swaps, Zram};
+use iced::widget::{button, column, container, scrollable, text};
 
 pub fn ram_page<'a>(
     ram: &'a DataLoadingState<RAM>,
     swaps: &'a DataLoadingState<Swaps>,
+    zram: &'a DataLoadingState<Zram>,
+    show_advanced: bool,
+    byte_unit: ByteUnit,
 ) -> container::Container<'a, Message> {
+    let mut tables = column![ram_table(ram, byte_unit), swap_table(swaps, byte_unit)].spacing(5);
+    if let DataLoadingState::Loaded(ram) = ram {
+        tables = tables.push(advanced_table(ram, show_advanced, byte_unit));
+    }
+    // The table is shown only when zram is used
+    if let DataLoadingState::Loaded(zram) = zram
+        && !zram.devices.is_empty()
+    {
+        tables = tables.push(zram_table(zram, byte_unit));
+    }
+
     container(
-        scrollable(column![ram_table(ram), swap_table(swaps),].spacing(5))
+        scrollable(tables)
             .spacing(5)
             .id(super::Page::Memory.page_id()),
     )
 }
 
-fn ram_table<'a>(ram: &'a DataLoadingState<RAM>) -> container::Container<'a, Message> {
+fn ram_table<'a>(
+    ram: &'a DataLoadingState<RAM>,
+    byte_unit: ByteUnit,
+) -> container::Container<'a, Message> {
     match ram {
         DataLoadingState::Loaded(ram) => {
             let mut ram_data = column![header(fl!("ram-hdr"))].spacing(5);
             let rows = vec![
-                InfoRow::new(fl!("ram-total"), fmt_val(ram.total.round(2))),
-                InfoRow::new(fl!("ram-free"), fmt_val(ram.free.round(2))),
-                InfoRow::new(fl!("ram-available"), fmt_val(ram.available.round(2))),
-                InfoRow::new(fl!("ram-buffers"), fmt_val(ram.buffers.round(2))),
-                InfoRow::new(fl!("ram-cached"), fmt_val(ram.cached.round(2))),
-                InfoRow::new(fl!("ram-swap-cached"), fmt_val(ram.swap_cached.round(2))),
-                InfoRow::new(fl!("ram-active"), fmt_val(ram.active.round(2))),
-                InfoRow::new(fl!("ram-inactive"), fmt_val(ram.inactive.round(2))),
-                InfoRow::new(fl!("ram-active-anon"), fmt_val(ram.active_anon.round(2))),
+                InfoRow::new(fl!("ram-total"), byte_unit.format_size(&ram.total)),
+                InfoRow::new(fl!("ram-free"), byte_unit.format_size(&ram.free)),
+                InfoRow::new(fl!("ram-available"), byte_unit.format_size(&ram.available)),
+                InfoRow::new(fl!("ram-buffers"), byte_unit.format_size(&ram.buffers)),
+                InfoRow::new(fl!("ram-cached"), byte_unit.format_size(&ram.cached)),
+                InfoRow::new(
+                    fl!("ram-swap-cached"),
+                    byte_unit.format_size(&ram.swap_cached),
+                ),
+                InfoRow::new(fl!("ram-active"), byte_unit.format_size(&ram.active)),
+                InfoRow::new(fl!("ram-inactive"), byte_unit.format_size(&ram.inactive)),
+                InfoRow::new(
+                    fl!("ram-active-anon"),
+                    byte_unit.format_size(&ram.active_anon),
+                ),
                 InfoRow::new(
                     fl!("ram-inactive-anon"),
-                    fmt_val(ram.inactive_anon.round(2)),
+                    byte_unit.format_size(&ram.inactive_anon),
+                ),
+                InfoRow::new(
+                    fl!("ram-active-file"),
+                    byte_unit.format_size(&ram.active_file),
                 ),
-                InfoRow::new(fl!("ram-active-file"), fmt_val(ram.active_file.round(2))),
                 InfoRow::new(
                     fl!("ram-inactive-file"),
-                    fmt_val(ram.inactive_file.round(2)),
-                ),
-                InfoRow::new(fl!("ram-unevictable"), fmt_val(ram.unevictable.round(2))),
-                InfoRow::new(fl!("ram-locked"), fmt_val(ram.mlocked.round(2))),
-                InfoRow::new(fl!("ram-swap-total"), fmt_val(ram.swap_total.round(2))),
-                InfoRow::new(fl!("ram-swap-free"), fmt_val(ram.swap_free.round(2))),
-                InfoRow::new(fl!("ram-zswap"), fmt_val(ram.zswap.round(2))),
-                InfoRow::new(fl!("ram-zswapped"), fmt_val(ram.zswapped.round(2))),
-                InfoRow::new(fl!("ram-dirty"), fmt_val(ram.dirty.round(2))),
-                InfoRow::new(fl!("ram-writeback"), fmt_val(ram.writeback.round(2))),
-                InfoRow::new(fl!("ram-anon-pages"), fmt_val(ram.anon_pages.round(2))),
-                InfoRow::new(fl!("ram-mapped"), fmt_val(ram.mapped.round(2))),
-                InfoRow::new(fl!("ram-shmem"), fmt_val(ram.shmem.round(2))),
-                InfoRow::new(fl!("ram-kreclaimable"), fmt_val(ram.kreclaimable.round(2))),
-                InfoRow::new(fl!("ram-slab"), fmt_val(ram.slab.round(2))),
-                InfoRow::new(fl!("ram-sreclaimable"), fmt_val(ram.sreclaimable.round(2))),
-                InfoRow::new(fl!("ram-sunreclaim"), fmt_val(ram.sunreclaim.round(2))),
-                InfoRow::new(fl!("ram-kernel-stack"), fmt_val(ram.kernel_stack.round(2))),
-                InfoRow::new(fl!("ram-page-tables"), fmt_val(ram.page_tables.round(2))),
+                    byte_unit.format_size(&ram.inactive_file),
+                ),
+                InfoRow::new(
+                    fl!("ram-unevictable"),
+                    byte_unit.format_size(&ram.unevictable),
+                ),
+                InfoRow::new(fl!("ram-locked"), byte_unit.format_size(&ram.mlocked)),
+                InfoRow::new(
+                    fl!("ram-swap-total"),
+                    byte_unit.format_size(&ram.swap_total),
+                ),
+                InfoRow::new(fl!("ram-swap-free"), byte_unit.format_size(&ram.swap_free)),
+                InfoRow::new(fl!("ram-zswap"), byte_unit.format_size(&ram.zswap)),
+                InfoRow::new(fl!("ram-zswapped"), byte_unit.format_size(&ram.zswapped)),
+                InfoRow::new(fl!("ram-dirty"), byte_unit.format_size(&ram.dirty)),
+                InfoRow::new(fl!("ram-writeback"), byte_unit.format_size(&ram.writeback)),
+                InfoRow::new(
+                    fl!("ram-anon-pages"),
+                    byte_unit.format_size(&ram.anon_pages),
+                ),
+                InfoRow::new(fl!("ram-mapped"), byte_unit.format_size(&ram.mapped)),
+                InfoRow::new(fl!("ram-shmem"), byte_unit.format_size(&ram.shmem)),
+                InfoRow::new(
+                    fl!("ram-kreclaimable"),
+                    byte_unit.format_size(&ram.kreclaimable),
+                ),
+                InfoRow::new(fl!("ram-slab"), byte_unit.format_size(&ram.slab)),
+                InfoRow::new(
+                    fl!("ram-sreclaimable"),
+                    byte_unit.format_size(&ram.sreclaimable),
+                ),
+                InfoRow::new(
+                    fl!("ram-sunreclaim"),
+                    byte_unit.format_size(&ram.sunreclaim),
+                ),
+                InfoRow::new(
+                    fl!("ram-kernel-stack"),
+                    byte_unit.format_size(&ram.kernel_stack),
+                ),
+                InfoRow::new(
+                    fl!("ram-page-tables"),
+                    byte_unit.format_size(&ram.page_tables),
+                ),
                 InfoRow::new(
                     fl!("ram-sec-page-tables"),
-                    fmt_val(ram.sec_page_tables.round(2)),
+                    byte_unit.format_size(&ram.sec_page_tables),
+                ),
+                InfoRow::new(
+                    fl!("ram-nfs-unstable"),
+                    byte_unit.format_size(&ram.nfs_unstable),
                 ),
-                InfoRow::new(fl!("ram-nfs-unstable"), fmt_val(ram.nfs_unstable.round(2))),
-                InfoRow::new(fl!("ram-bounce"), fmt_val(ram.bounce.round(2))),
+                InfoRow::new(fl!("ram-bounce"), byte_unit.format_size(&ram.bounce)),
                 InfoRow::new(
                     fl!("ram-writeback-tmp"),
-                    fmt_val(ram.writeback_tmp.round(2)),
+                    byte_unit.format_size(&ram.writeback_tmp),
+                ),
+                InfoRow::new(
+                    fl!("ram-commit-limit"),
+                    byte_unit.format_size(&ram.commit_limit),
                 ),
-                InfoRow::new(fl!("ram-commit-limit"), fmt_val(ram.commit_limit.round(2))),
             ];
 
             ram_data = ram_data.push(container(kv_info_table(rows)).style(container::rounded_box));
@@ -105,7 +163,10 @@ fn ram_table<'a>(ram: &'a DataLoadingState<RAM>) -> container::Container<'a, Mes
     }
 }
 
-fn swap_table<'a>(swaps: &'a DataLoadingState<Swaps>) -> container::Container<'a, Message> {
+fn swap_table<'a>(
+    swaps: &'a DataLoadingState<Swaps>,
+    byte_unit: ByteUnit,
+) -> container::Container<'a, Message> {
     match swaps {
         DataLoadingState::Loaded(swaps) => {
             let mut swap_data = column![header(fl!("ram-swp-hdr"))].spacing(5);
@@ -117,8 +178,9 @@ fn swap_table<'a>(swaps: &'a DataLoadingState<Swaps>) -> container::Container<'a
 
             for swap in &swaps.swaps {
                 let rows = vec![
-                    InfoRow::new(fl!("ram-swp-size"), fmt_val(swap.size.round(2))),
-                    InfoRow::new(fl!("ram-swp-used"), fmt_val(swap.used.round(2))),
+                    InfoRow::new(fl!("ram-swp-kind"), Some(fmt_swap_kind(swap.kind))),
+                    InfoRow::new(fl!("ram-swp-size"), byte_unit.format_size(&swap.size)),
+                    InfoRow::new(fl!("ram-swp-used"), byte_unit.format_size(&swap.used)),
                     InfoRow::new(fl!("ram-swp-prior"), fmt_val(Some(swap.priority))),
                 ];
                 swap_data = swap_data.push(
@@ -138,3 +200,106 @@ fn swap_table<'a>(swaps: &'a DataLoadingState<Swaps>) -> container::Container<'a
         DataLoadingState::Loading => container(text("Loading data...").style(text::warning)),
     }
 }
+
+/// Huge pages settings hidden under the "Advanced" expander
+fn advanced_table<'a>(
+    ram: &'a RAM,
+    show: bool,
+    byte_unit: ByteUnit,
+) -> container::Container<'a, Message> {
+    let header = button(text(format!(
+        "{} {}",
+        if show { "▾" } else { "▸" },
+        fl!("ram-advanced")
+    )))
+    .style(button::text)
+    .padding(0)
+    .on_press(Message::Buttons(ButtonsMessage::ChangeRAMAdvancedShow(
+        !show,
+    )));
+
+    if !show {
+        return container(header);
+    }
+
+    let rows = vec![
+        InfoRow::new(fl!("ram-hp-total"), fmt_val(Some(ram.huge_pages_total))),
+        InfoRow::new(fl!("ram-hp-free"), fmt_val(Some(ram.huge_pages_free))),
+        InfoRow::new(fl!("ram-hp-rsvd"), fmt_val(Some(ram.huge_pages_rsvd))),
+        InfoRow::new(fl!("ram-hp-surp"), fmt_val(Some(ram.huge_pages_surp))),
+        InfoRow::new(
+            fl!("ram-hp-size"),
+            byte_unit.format_size(&ram.huge_page_size),
+        ),
+        InfoRow::new(fl!("ram-hp-tlb"), byte_unit.format_size(&ram.huge_tlb)),
+        InfoRow::new(
+            fl!("ram-thp-mode"),
+            ram.thp_mode.map(|mode| mode.to_string()),
+        ),
+        InfoRow::new(
+            fl!("ram-thp-anon"),
+            byte_unit.format_size(&ram.anon_huge_pages),
+        ),
+        InfoRow::new(
+            fl!("ram-thp-shmem"),
+            byte_unit.format_size(&ram.shmem_huge_pages),
+        ),
+        InfoRow::new(
+            fl!("ram-thp-file"),
+            byte_unit.format_size(&ram.file_huge_pages),
+        ),
+    ];
+    container(
+        column![
+            header,
+            container(kv_info_table(rows)).style(container::rounded_box),
+        ]
+        .spacing(5),
+    )
+}
+
+fn zram_table<'a>(zram: &'a Zram, byte_unit: ByteUnit) -> container::Container<'a, Message> {
+    let mut zram_data = column![header(fl!("ram-zram-hdr"))].spacing(5);
+    for dev in &zram.devices {
+        let rows = vec![
+            InfoRow::new(fl!("ram-zram-alg"), dev.algorithm.clone()),
+            InfoRow::new(
+                fl!("ram-zram-disksize"),
+                byte_unit.format_size(&dev.disksize),
+            ),
+            InfoRow::new(
+                fl!("ram-zram-orig"),
+                byte_unit.format_size(&dev.orig_data_size),
+            ),
+            InfoRow::new(
+                fl!("ram-zram-compr"),
+                byte_unit.format_size(&dev.compr_data_size),
+            ),
+            InfoRow::new(
+                fl!("ram-zram-mem-used"),
+                byte_unit.format_size(&dev.mem_used_total),
+            ),
+            InfoRow::new(
+                fl!("ram-zram-ratio"),
+                dev.ratio().map(|ratio| format!("{ratio:.2}")),
+            ),
+        ];
+        zram_data = zram_data.push(
+            column![
+                text(dev.name.as_str()).style(text::warning),
+                container(kv_info_table(rows)).style(container::rounded_box),
+            ]
+            .spacing(5),
+        );
+    }
+    container(zram_data)
+}
+
+fn fmt_swap_kind(kind: SwapKind) -> String {
+    match kind {
+        SwapKind::Partition => fl!("ram-swp-partition"),
+        SwapKind::File => fl!("ram-swp-file"),
+        SwapKind::Zram => fl!("ram-swp-zram"),
+        SwapKind::Unknown => fl!("ram-swp-unknown"),
+    }
+}
@@ -0,0 +1,106 @@
+/* sensors.rs
+ *
+ * Copyright 2026 Michail Krasnov <mskrasnov07@ya.ru>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Hardware sensors page
+
+use crate::{
+    DataLoadingState, Message, fl,
+    settings::TempUnit,
+    widgets::table::{InfoRow, kv_info_table},
+};
+use ferrix_lib::sensors::{Chip, Sensor, SensorKind, Sensors};
+
+use iced::widget::{Id, center, column, container, scrollable, text};
+
+pub fn sensors_page<'a>(
+    sensors: &'a DataLoadingState<Sensors>,
+    temp_unit: TempUnit,
+) -> container::Container<'a, Message> {
+    match sensors {
+        DataLoadingState::Loaded(sensors) => {
+            let mut chips_list = column![].spacing(5);
+            if sensors.chips.iter().all(|chip| chip.sensors.is_empty()) {
+                chips_list = chips_list.push(center(
+                    column![
+                        text(fl!("sensors-not-found"))
+                            .style(text::secondary)
+                            .size(16)
+                    ]
+                    .spacing(5),
+                ));
+                return container(chips_list);
+            }
+
+            for chip in sensors.chips.iter().filter(|chip| !chip.sensors.is_empty()) {
+                chips_list = chips_list.push(
+                    text(fl!(
+                        "sensors-chip",
+                        name = match &chip.name {
+                            Some(name) => name.to_string(),
+                            None => fl!("sensors-unknown-name"),
+                        },
+                        hwmon = chip.hwmon.as_str()
+                    ))
+                    .style(text::warning),
+                );
+                chips_list = chips_list.push(chip_table(chip, temp_unit));
+            }
+            container(
+                scrollable(chips_list)
+                    .spacing(5)
+                    .id(Id::new(super::Page::Sensors.page_id())),
+            )
+        }
+        DataLoadingState::Error(why) => super::error_page(why),
+        DataLoadingState::Loading => super::loading_page(),
+    }
+}
+
+fn chip_table<'a>(chip: &'a Chip, temp_unit: TempUnit) -> container::Container<'a, Message> {
+    let rows = chip
+        .sensors
+        .iter()
+        .map(|sensor| InfoRow::new(sensor.name(), Some(fmt_sensor(sensor, temp_unit))))
+        .collect::<Vec<_>>();
+    container(kv_info_table(rows)).style(container::rounded_box)
+}
+
+fn fmt_sensor(sensor: &Sensor, temp_unit: TempUnit) -> String {
+    let unit = sensor.kind.unit();
+    let fmt = |val: f64| match sensor.kind {
+        SensorKind::Temperature => temp_unit.format(val),
+        SensorKind::Fan => format!("{val:.0} {unit}"),
+        SensorKind::Voltage => format!("{val:.3} {unit}"),
+    };
+
+    let mut s = fmt(sensor.input);
+    let limits = [
+        (fl!("sensors-min"), sensor.min),
+        (fl!("sensors-max"), sensor.max),
+        (fl!("sensors-crit"), sensor.crit),
+    ]
+    .into_iter()
+    .filter_map(|(name, val)| val.map(|val| format!("{name} {}", fmt(val))))
+    .collect::<Vec<_>>();
+    if !limits.is_empty() {
+        s += &format!(" ({})", limits.join(", "));
+    }
+    s
+}
@@ -24,7 +24,9 @@ use crate::{
     ferrix::Ferrix,
     fl,
     messages::{ButtonsMessage, Message, SettingsMessage},
-    settings::{ChartLineThickness, Style},
+    settings::{
+        ByteUnit, ChartLineThickness, FONT_SIZES, Language, Style, TempUnit, UpdateSection,
+    },
     widgets::icon_tooltip,
 };
 use iced::{
@@ -35,16 +37,34 @@ use iced::{
 use std::ops::RangeInclusive;
 
 pub fn settings_page<'a>(state: &'a Ferrix) -> Element<'a, Message> {
+    let periods = &state.settings.update_periods;
+    let period_slider = |section, range| {
+        let per = periods.get(section);
+        time_slider(
+            range,
+            per,
+            per,
+            move |per| Message::Settings(SettingsMessage::ChangeUpdatePeriod(section, per)),
+            Message::Settings(SettingsMessage::Save),
+        )
+    };
     let update_changer = settings_group(
         column![
             settings_item(
                 fl!("settings-uper-main"),
-                time_slider(
-                    1..=15,
-                    state.settings.update_period,
-                    state.settings.update_period,
-                    |per| { Message::Settings(SettingsMessage::ChangeUpdatePeriod(per)) }
-                ),
+                period_slider(UpdateSection::Hardware, 1..=15),
+            ),
+            settings_item(
+                fl!("settings-uper-system"),
+                period_slider(UpdateSection::System, 1..=15),
+            ),
+            settings_item(
+                fl!("settings-uper-storage"),
+                period_slider(UpdateSection::Storage, 1..=60),
+            ),
+            settings_item(
+                fl!("settings-uper-services"),
+                period_slider(UpdateSection::Services, 1..=60),
             ),
             settings_item(
                 fl!("page-sysmon"),
@@ -56,6 +76,7 @@ pub fn settings_page<'a>(state: &'a Ferrix) -> Element<'a, Message> {
                         state.settings.charts_update_period_nsecs as f32 * 0.1,
                     ),
                     |per| { Message::Settings(SettingsMessage::ChangeChartsUpdatePeriod(per)) },
+                    Message::Settings(SettingsMessage::Save),
                 ),
             ),
         ]
@@ -73,10 +94,40 @@ pub fn settings_page<'a>(state: &'a Ferrix) -> Element<'a, Message> {
     )
     .padding(3);
 
+    let lang_selector = pick_list(
+        Language::all(),
+        Some(Language(state.settings.language.clone())),
+        |lang| Message::Settings(SettingsMessage::ChangeLanguage(lang.0)),
+    )
+    .padding(3);
+
+    let temp_unit_selector = pick_list(TempUnit::ALL, Some(state.settings.temp_unit), |unit| {
+        Message::Settings(SettingsMessage::ChangeTempUnit(unit))
+    })
+    .padding(3);
+
+    let byte_unit_selector = pick_list(ByteUnit::ALL, Some(state.settings.byte_unit), |unit| {
+        Message::Settings(SettingsMessage::ChangeByteUnit(unit))
+    })
+    .padding(3);
+
     let theme_changer = settings_group(
         column![
             settings_item(fl!("settings-look-select"), theme_selector),
+            settings_item(fl!("settings-lang"), lang_selector),
+            settings_item(
+                fl!("settings-font-size"),
+                time_slider(
+                    FONT_SIZES,
+                    state.settings.font_size,
+                    state.settings.font_size,
+                    |size| Message::Settings(SettingsMessage::ChangeFontSize(size)),
+                    Message::Settings(SettingsMessage::Save),
+                ),
+            ),
             settings_item(fl!("settings-look-thick"), chart_line_thick_selector),
+            settings_item(fl!("settings-temp-unit"), temp_unit_selector),
+            settings_item(fl!("settings-byte-unit"), byte_unit_selector),
         ]
         .spacing(5),
     );
@@ -150,13 +201,16 @@ fn time_slider<'a, D, Message>(
     val: u8,
     txt: D,
     on_change: impl Fn(u8) -> Message + 'a,
+    on_release: Message,
 ) -> Element<'a, Message>
 where
     D: text::IntoFragment<'a>,
     Message: Clone + 'a,
 {
     row![
-        slider(range, val, on_change).width(250),
+        slider(range, val, on_change)
+            .on_release(on_release)
+            .width(250),
         container(center(text(txt).size(12)))
             .style(container::rounded_box)
             .width(25)
@@ -20,32 +20,65 @@
 
 //! CPU page
 
-use crate::{DataLoadingState, Message, fl, messages::ButtonsMessage, widgets::table::hdr_name};
-use ferrix_lib::soft::{InstalledPackages, Package};
+use crate::{
+    DataLoadingState, Message, fl,
+    messages::{ButtonsMessage, DataReceiverMessage},
+    widgets::table::hdr_name,
+};
+use ferrix_lib::{
+    soft::{InstalledPackages, Package},
+    utils::fmt_bytes,
+};
 
 use iced::{
     Length,
     widget::{
-        Id, button, column, container, row as _row, scrollable, space::horizontal, table, text,
+        Id, Row, button, column, container, row as _row, scrollable, space::horizontal, table,
+        text, text_input,
     },
 };
 
 pub fn soft_page<'a>(
-    software: &'a DataLoadingState<InstalledPackages>,
+    software: &'a DataLoadingState<Vec<InstalledPackages>>,
+    selected: usize,
+    query: &'a str,
 ) -> container::Container<'a, Message> {
-    container(scrollable(column![soft_list(software),].spacing(5)).spacing(5))
+    container(scrollable(column![soft_list(software, selected, query),].spacing(5)).spacing(5))
 }
 
 fn soft_list<'a>(
-    software: &'a DataLoadingState<InstalledPackages>,
+    software: &'a DataLoadingState<Vec<InstalledPackages>>,
+    selected: usize,
+    query: &'a str,
 ) -> container::Container<'a, Message> {
     match software {
         DataLoadingState::Loaded(soft) => {
-            let pkgs = &soft.packages;
+            let Some(current) = soft.get(selected).or(soft.first()) else {
+                return container(text(fl!("soft-no-managers")));
+            };
+            let managers = Row::from_iter(soft.iter().enumerate().map(|(id, list)| {
+                button(text(format!("{} ({})", list.manager, list.count())))
+                    .on_press(Message::Buttons(ButtonsMessage::PackageManagerSelected(id)))
+                    .style(if list.manager == current.manager {
+                        button::subtle
+                    } else {
+                        button::text
+                    })
+                    .padding(2)
+                    .into()
+            }))
+            .spacing(5);
+
+            let search = text_input(&fl!("soft-search"), query)
+                .on_input(|q| Message::DataReceiver(DataReceiverMessage::PackagesSearchChanged(q)))
+                .padding(5);
+
+            let pkgs = current.search(query);
             let table = container(soft_table(pkgs)).style(container::rounded_box);
-            let services_count = text(fl!("soft-total", total = pkgs.len()));
+            let total = soft.iter().map(|list| list.count()).sum::<usize>();
+            let services_count = text(fl!("soft-total", total = total));
 
-            let layout = column![services_count, table].spacing(5);
+            let layout = column![services_count, managers, search, table].spacing(5);
             container(
                 scrollable(layout)
                     .spacing(5)
@@ -57,7 +90,7 @@ fn soft_list<'a>(
     }
 }
 
-fn soft_table<'a>(rows: &'a [Package]) -> table::Table<'a, Message> {
+fn soft_table<'a>(rows: Vec<&'a Package>) -> table::Table<'a, Message> {
     let columns = [
         table::column(
             _row![horizontal(), hdr_name(fl!("soft-hdr-name"))],
@@ -84,6 +117,12 @@ fn soft_table<'a>(rows: &'a [Package]) -> table::Table<'a, Message> {
         table::column(hdr_name(fl!("soft-hdr-type")), |row: &'a Package| {
             text(row.pkg_type.to_string())
         }),
+        table::column(hdr_name(fl!("soft-hdr-size")), |row: &'a Package| {
+            text(match row.installed_size {
+                Some(size) => fmt_bytes(size, 2),
+                None => "N/A".to_string(),
+            })
+        }),
     ];
 
     table(columns, rows).padding(2).width(Length::Fill)
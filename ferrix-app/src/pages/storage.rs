@@ -23,19 +23,30 @@
 use crate::{
     Message, fl,
     load_state::LoadState,
-    widgets::{icon_tooltip, table::hdr_name},
+    settings::{ByteUnit, TempUnit},
+    widgets::{
+        glassy_container, icon_tooltip,
+        line_charts::LineChart,
+        table::{hdr_name, text_fmt_val},
+    },
 };
 use ferrix_lib::{
-    parts::{MountEntry, Mounts},
+    parts::{MountEntry, Mounts, Smart, SmartHealth},
     utils::Size,
 };
 use iced::{
     Alignment::Center,
     Color, Element, Font, Length,
-    widget::{center, container, progress_bar, row, scrollable, stack, table, text},
+    widget::{center, column, container, progress_bar, row, scrollable, stack, table, text},
 };
 
-pub fn storage_page<'a>(storages: &'a LoadState<Mounts>) -> container::Container<'a, Message> {
+pub fn storage_page<'a>(
+    storages: &'a LoadState<Mounts>,
+    smart: &'a LoadState<Vec<Smart>>,
+    disk_io_chart: &'a LineChart,
+    temp_unit: TempUnit,
+    byte_unit: ByteUnit,
+) -> container::Container<'a, Message> {
     match storages {
         LoadState::Loaded(storage) => {
             let mut rows = Vec::with_capacity(storage.mounts.len());
@@ -48,9 +59,23 @@ pub fn storage_page<'a>(storages: &'a LoadState<Mounts>) -> container::Container
                 s2.cmp(&s1)
             });
 
-            container(
-                scrollable(container(storage_table(rows)).style(container::rounded_box)).spacing(5),
-            )
+            let mut content =
+                column![container(storage_table(rows, byte_unit)).style(container::rounded_box)]
+                    .spacing(5);
+            if let LoadState::Loaded(smart) = smart
+                && !smart.is_empty()
+            {
+                content = content.push(glassy_container(
+                    fl!("storage-health-hdr"),
+                    container(health_table(smart, temp_unit)).style(container::rounded_box),
+                ));
+            }
+            content = content.push(glassy_container(
+                fl!("storage-io-hdr"),
+                disk_io_chart.view(),
+            ));
+
+            container(scrollable(content).spacing(5))
         }
         LoadState::Error(why) => super::error_page(why),
         LoadState::Loading => super::loading_page(),
@@ -62,11 +87,13 @@ struct TableRow<'a> {
     pub device: &'a str,
     pub mount_point: &'a str,
     pub filesystem: &'a str,
-    pub options: &'a str,
+    pub options: String,
+    pub read_only: bool,
     pub total_size: Size,
     pub free_size: Size,
     pub used_size: Size,
     pub usage_percent: f32,
+    pub inodes: Option<(u64, u64)>,
 }
 
 impl<'a> From<&'a MountEntry> for TableRow<'a> {
@@ -75,21 +102,15 @@ impl<'a> From<&'a MountEntry> for TableRow<'a> {
         let total_size = match fstats {
             Some(fstats) => fstats.total_size(),
             None => Size::None,
-        }
-        .round(2)
-        .unwrap_or_default();
+        };
         let free_size = match fstats {
             Some(fstats) => fstats.free_size(),
             None => Size::None,
-        }
-        .round(2)
-        .unwrap_or_default();
+        };
         let used_size = match fstats {
             Some(fstats) => fstats.used_size(),
             None => Size::None,
-        }
-        .round(2)
-        .unwrap_or_default();
+        };
         let usage_percent = match fstats {
             Some(fstats) => fstats.usage_percent() as f32,
             None => 0.,
@@ -99,36 +120,63 @@ impl<'a> From<&'a MountEntry> for TableRow<'a> {
             device: &value.device,
             mount_point: &value.mount_point,
             filesystem: &value.filesystem,
-            options: &value.options,
+            options: value.options.join(","),
+            read_only: value.is_read_only(),
             total_size,
             free_size,
             used_size,
             usage_percent,
+            inodes: value.inodes_total.zip(value.inodes_free),
         }
     }
 }
 
-fn storage_table<'a>(rows: Vec<TableRow<'a>>) -> Element<'a, Message> {
+fn storage_table<'a>(rows: Vec<TableRow<'a>>, byte_unit: ByteUnit) -> Element<'a, Message> {
     let columns = [
         table::column(hdr_name(fl!("storage-dev")), |row: TableRow| {
             row![
                 text(row.device).font(Font::MONOSPACE),
-                icon_tooltip("about", format!("{}\n{}", row.mount_point, row.options))
+                icon_tooltip(
+                    "about",
+                    match row.inodes {
+                        Some((total, free)) => format!(
+                            "{}\n{}",
+                            row.options,
+                            fl!("storage-inodes", free = free, total = total)
+                        ),
+                        None => row.options.to_string(),
+                    }
+                )
             ]
             .spacing(5)
             .align_y(Center)
         }),
+        table::column(hdr_name(fl!("storage-mnt")), |row: TableRow| {
+            let mount_point = text(row.mount_point).font(Font::MONOSPACE);
+            // The filesystem may be remounted read-only after disk errors
+            if row.read_only {
+                row![
+                    mount_point,
+                    text(fl!("storage-ro"))
+                        .font(Font::MONOSPACE)
+                        .style(text::danger),
+                ]
+                .spacing(5)
+            } else {
+                row![mount_point]
+            }
+        }),
         table::column(hdr_name(fl!("storage-fs")), |row: TableRow| {
             text(row.filesystem).font(Font::MONOSPACE)
         }),
-        table::column(hdr_name(fl!("storage-total")), |row: TableRow| {
-            text(row.total_size.to_string())
+        table::column(hdr_name(fl!("storage-total")), move |row: TableRow| {
+            text(byte_unit.format_size(&row.total_size).unwrap_or_default())
         }),
-        table::column(hdr_name(fl!("storage-free")), |row: TableRow| {
-            text(row.free_size.to_string())
+        table::column(hdr_name(fl!("storage-free")), move |row: TableRow| {
+            text(byte_unit.format_size(&row.free_size).unwrap_or_default())
         }),
-        table::column(hdr_name(fl!("storage-used")), |row: TableRow| {
-            text(row.used_size.to_string())
+        table::column(hdr_name(fl!("storage-used")), move |row: TableRow| {
+            text(byte_unit.format_size(&row.used_size).unwrap_or_default())
         }),
         table::column(hdr_name(fl!("storage-usage")), |row: TableRow| {
             stack![
@@ -152,3 +200,45 @@ fn storage_table<'a>(rows: Vec<TableRow<'a>>) -> Element<'a, Message> {
     ];
     table(columns, rows).padding(2).width(Length::Fill).into()
 }
+
+fn health_table<'a>(smart: &'a [Smart], temp_unit: TempUnit) -> Element<'a, Message> {
+    let columns = [
+        table::column(hdr_name(fl!("storage-dev")), |row: &'a Smart| {
+            let health = match row.health {
+                Some(SmartHealth::Passed) if !row.is_failing() => fl!("storage-health-ok"),
+                Some(_) => fl!("storage-health-failing"),
+                None => fl!("storage-health-unknown"),
+            };
+            let is_failing = row.is_failing();
+            let is_known = row.health.is_some();
+            row![
+                text("●").style(move |s: &iced::Theme| text::Style {
+                    color: Some(if is_failing {
+                        s.palette().danger
+                    } else if is_known {
+                        s.palette().success
+                    } else {
+                        s.extended_palette().secondary.base.color
+                    }),
+                }),
+                text(&row.devname).font(Font::MONOSPACE),
+                icon_tooltip("about", health),
+            ]
+            .spacing(5)
+            .align_y(Center)
+        }),
+        table::column(hdr_name(fl!("storage-temp")), move |row: &'a Smart| {
+            text_fmt_val(row.temperature.map(|t| temp_unit.format(t as f64)))
+        }),
+        table::column(hdr_name(fl!("storage-power-on")), |row: &'a Smart| {
+            text_fmt_val(row.power_on_hours)
+        }),
+        table::column(hdr_name(fl!("storage-realloc")), |row: &'a Smart| {
+            text_fmt_val(row.reallocated_sectors)
+        }),
+        table::column(hdr_name(fl!("storage-wearout")), |row: &'a Smart| {
+            text_fmt_val(row.percentage_used.map(|p| format!("{p}%")))
+        }),
+    ];
+    table(columns, smart).padding(2).width(Length::Fill).into()
+}
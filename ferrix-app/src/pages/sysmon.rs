@@ -21,16 +21,30 @@
 //! CPU usage charts
 
 use crate::{
-    DataLoadingState, Message, ferrix::FerrixData, fl, messages::ButtonsMessage,
-    widgets::glassy_container,
+    DataLoadingState, Message,
+    export::ChartKind,
+    ferrix::FerrixData,
+    fl,
+    messages::{ButtonsMessage, ExportManagerMessage},
+    widgets::{
+        glassy_container,
+        table::{hdr_name, text_fmt_val},
+    },
+};
+use ferrix_lib::{
+    cpu::Stat,
+    proc::{Process, Processes},
 };
-use ferrix_lib::cpu::Stat;
 
 use iced::{
     Alignment::Center,
-    widget::{column, container, row, slider, space, text, toggler},
+    Element, Font, Length,
+    widget::{button, column, container, row, slider, space, table, text, toggler},
 };
 
+/// Number of processes in the "Top processes" tables
+const TOP_PROCESSES: usize = 5;
+
 pub fn usage_charts_page<'a>(
     fx: &'a FerrixData,
     cur_stat: &'a DataLoadingState<Stat>,
@@ -64,15 +78,148 @@ pub fn usage_charts_page<'a>(
             toggler(fx.show_charts_legend)
                 .label(fl!("sysmon-toggle"))
                 .on_toggle(|show| Message::Buttons(ButtonsMessage::ChangeLegendShow(show))),
+            toggler(fx.show_ram_breakdown)
+                .label(fl!("sysmon-ram-breakdown"))
+                .on_toggle(|show| Message::Buttons(ButtonsMessage::ChangeRAMBreakdownShow(show))),
             space::horizontal(),
+            button(text(if fx.cpu_usage_chart.is_paused() {
+                fl!("sysmon-resume")
+            } else {
+                fl!("sysmon-pause")
+            }))
+            .on_press(Message::Buttons(ButtonsMessage::ToggleChartsPause))
+            .padding(2),
             mx,
         ]
         .align_y(Center)
         .spacing(5),
-        glassy_container(fl!("sysmon-cpu-hdr"), fx.cpu_usage_chart.view()),
-        glassy_container(fl!("sysmon-ram-hdr"), fx.ram_usage_chart.view()),
+        counters(fx, cur_stat, prev_stat),
+        glassy_container(
+            fl!("sysmon-cpu-hdr"),
+            chart_with_save(fx.cpu_usage_chart.view(), ChartKind::Cpu)
+        ),
+        if fx.show_ram_breakdown {
+            glassy_container(
+                fl!("sysmon-ram-breakdown"),
+                chart_with_save(fx.ram_breakdown_chart.view(), ChartKind::Ram),
+            )
+        } else {
+            glassy_container(
+                fl!("sysmon-ram-hdr"),
+                chart_with_save(fx.ram_usage_chart.view(), ChartKind::Ram),
+            )
+        },
+        glassy_container(
+            fl!("sysmon-net-hdr"),
+            chart_with_save(fx.net_chart.view(), ChartKind::Net),
+        ),
+        top_processes(&fx.processes),
     ]
     .spacing(5);
 
     container(line_widget)
 }
+
+/// Context switches and interrupts rates and the run queue depth
+fn counters<'a>(
+    fx: &'a FerrixData,
+    cur_stat: &'a Stat,
+    prev_stat: &'a Stat,
+) -> Element<'a, Message> {
+    let rates = match (fx.prev_proc_stat_time, fx.curr_proc_stat_time) {
+        (Some(prev), Some(curr)) => Some(cur_stat.rates(prev_stat, curr - prev)),
+        _ => None,
+    };
+    let fmt_rate = |rate: Option<f64>| match rate {
+        Some(rate) => format!("{rate:.0}"),
+        None => "—".to_string(),
+    };
+    let fmt_count = |count: Option<u64>| match count {
+        Some(count) => count.to_string(),
+        None => "—".to_string(),
+    };
+
+    row![
+        text(fl!(
+            "sysmon-ctxt",
+            rate = fmt_rate(rates.map(|r| r.context_switches))
+        )),
+        text(fl!(
+            "sysmon-intr",
+            rate = fmt_rate(rates.map(|r| r.interrupts))
+        )),
+        text(fl!("sysmon-forks", rate = fmt_rate(rates.map(|r| r.forks)))),
+        text(fl!(
+            "sysmon-run-queue",
+            running = fmt_count(cur_stat.processes_running),
+            blocked = fmt_count(cur_stat.processes_blocked)
+        )),
+    ]
+    .spacing(15)
+    .into()
+}
+
+fn top_processes<'a>(procs: &'a DataLoadingState<Processes>) -> Element<'a, Message> {
+    match procs {
+        DataLoadingState::Loaded(procs) => row![
+            glassy_container(
+                fl!("sysmon-top-cpu-hdr"),
+                procs_table(procs.top_by_cpu_time(TOP_PROCESSES))
+            ),
+            glassy_container(
+                fl!("sysmon-top-mem-hdr"),
+                procs_table(procs.top_by_mem(TOP_PROCESSES))
+            ),
+        ]
+        .spacing(5)
+        .into(),
+        DataLoadingState::Error(why) => text(fl!("sysmon-top-error", error = why.as_str()))
+            .style(text::danger)
+            .into(),
+        DataLoadingState::Loading => text(fl!("sysmon-top-loading")).into(),
+    }
+}
+
+fn procs_table<'a>(rows: Vec<&'a Process>) -> Element<'a, Message> {
+    let columns = [
+        table::column(hdr_name(fl!("sysmon-top-pid")), |row: &'a Process| {
+            text(row.pid.to_string()).font(Font::MONOSPACE)
+        }),
+        table::column(hdr_name(fl!("sysmon-top-name")), |row: &'a Process| {
+            text(&row.comm)
+        })
+        .width(Length::Fill),
+        table::column(hdr_name(fl!("sysmon-top-user")), |row: &'a Process| {
+            text(match (&row.user, row.uid) {
+                (Some(user), _) => user.to_string(),
+                (None, Some(uid)) => uid.to_string(),
+                (None, None) => String::new(),
+            })
+        }),
+        table::column(hdr_name(fl!("sysmon-top-cpu")), |row: &'a Process| {
+            let secs = row.cpu_time.as_secs();
+            text(format!("{}:{:02}", secs / 60, secs % 60)).font(Font::MONOSPACE)
+        }),
+        table::column(hdr_name(fl!("sysmon-top-rss")), |row: &'a Process| {
+            text_fmt_val(row.rss.round(2))
+        }),
+    ];
+    table(columns, rows).padding(2).width(Length::Fill).into()
+}
+
+fn chart_with_save<'a>(chart: Element<'a, Message>, kind: ChartKind) -> Element<'a, Message> {
+    column![
+        row![
+            space::horizontal(),
+            button(text(fl!("sysmon-save-png")))
+                .on_press(Message::ExportManager(
+                    ExportManagerMessage::SaveChartImage(kind, kind.file_name().to_string(),)
+                ))
+                .style(button::secondary)
+                .padding(2),
+        ],
+        chart,
+    ]
+    .spacing(5)
+    .into()
+}
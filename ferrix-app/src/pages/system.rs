@@ -23,14 +23,19 @@
 use crate::{
     Message, fl,
     load_state::DataLoadingState,
+    messages::ButtonsMessage,
     widgets::table::{InfoRow, kv_info_table},
 };
 
-use ferrix_lib::sys::{LoadAVG, Uptime};
-use iced::widget::{Id, container, scrollable};
+use ferrix_lib::{
+    sys::{LoadAVG, Uptime},
+    utils::local_time_string,
+};
+use iced::widget::{Id, button, column, container, scrollable, text};
 
 pub fn system_page<'a>(
     system: &'a DataLoadingState<crate::System>,
+    show_boot_params: bool,
 ) -> container::Container<'a, Message> {
     match system {
         DataLoadingState::Loaded(sys) => {
@@ -50,14 +55,40 @@ pub fn system_page<'a>(
                         None => format!("???"),
                     }),
                 ),
+                InfoRow::new(
+                    fl!("misc-boot-time"),
+                    sys.boot_time.and_then(local_time_string),
+                ),
+                InfoRow::new(fl!("misc-timezone"), sys.timezone.clone()),
+                InfoRow::new(
+                    fl!("misc-rtc"),
+                    sys.rtc_utc.map(|utc| match utc {
+                        true => fl!("misc-rtc-utc"),
+                        false => fl!("misc-rtc-local"),
+                    }),
+                ),
                 InfoRow::new(fl!("misc-de"), sys.desktop.clone()),
                 InfoRow::new(fl!("misc-lang"), sys.language.clone()),
             ];
 
             let sys_table = container(kv_info_table(rows)).style(container::rounded_box);
+            let boot_params = boot_params(&sys.cmdline_params, show_boot_params);
+            let sysctl_rows = sys
+                .sysctls
+                .iter()
+                .map(|(key, val)| match val {
+                    Ok(val) => InfoRow::new(key, Some(val.clone())),
+                    Err(why) => InfoRow::new(key, None).or_missing(why.into()),
+                })
+                .collect::<Vec<_>>();
+            let sysctl_table = column![
+                text(fl!("misc-sysctl")).style(text::warning),
+                container(kv_info_table(sysctl_rows)).style(container::rounded_box),
+            ]
+            .spacing(5);
 
             container(
-                scrollable(sys_table)
+                scrollable(column![sys_table, sysctl_table, boot_params].spacing(5))
                     .spacing(5)
                     .id(Id::new(super::Page::SystemMisc.page_id())),
             )
@@ -67,6 +98,38 @@ pub fn system_page<'a>(
     }
 }
 
+fn boot_params<'a>(
+    params: &'a [(String, Option<String>)],
+    show: bool,
+) -> container::Container<'a, Message> {
+    let header = button(text(format!(
+        "{} {}",
+        if show { "▾" } else { "▸" },
+        fl!("misc-boot-params")
+    )))
+    .style(button::text)
+    .padding(0)
+    .on_press(Message::Buttons(ButtonsMessage::ChangeBootParamsShow(
+        !show,
+    )));
+
+    if !show {
+        return container(header);
+    }
+
+    let rows = params
+        .iter()
+        .map(|(key, val)| InfoRow::new(key, val.clone()))
+        .collect::<Vec<_>>();
+    container(
+        column![
+            header,
+            container(kv_info_table(rows)).style(container::rounded_box),
+        ]
+        .spacing(5),
+    )
+}
+
 fn string_loadavg(lavg: &LoadAVG) -> String {
     format!("1min: {}\n5min: {}\n15min: {}", lavg.0, lavg.1, lavg.2)
 }
@@ -21,7 +21,10 @@
 //! systemd services list
 
 use crate::{
-    Message, fl, load_state::DataLoadingState, messages::ButtonsMessage, widgets::table::hdr_name,
+    Message, fl,
+    load_state::DataLoadingState,
+    messages::{ButtonsMessage, DataReceiverMessage},
+    widgets::table::hdr_name,
 };
 use ferrix_lib::init::{ActiveState, LoadState, ServiceInfo, SystemdServices, WorkState};
 
@@ -108,6 +111,32 @@ fn srv_table<'a>(rows: &'a [ServiceInfo]) -> table::Table<'a, Message> {
                 _ => text::secondary,
             })
         }),
+        table::column(hdr_name(fl!("sysd-hdr-file")), |row: &'a ServiceInfo| {
+            let state = row.unit_file_state.as_deref().unwrap_or("N/A");
+            let txt = text(state).style(match state {
+                "enabled" | "enabled-runtime" | "static" => text::success,
+                "masked" | "masked-runtime" | "bad" => text::danger,
+                _ => text::secondary,
+            });
+            match &row.fragment_path {
+                Some(path) => {
+                    button(txt)
+                        .style(button::text)
+                        .padding(0)
+                        .on_press(Message::Buttons(ButtonsMessage::CopyButtonPressed(
+                            path.clone(),
+                        )))
+                }
+                // The unit file wasn't found by the unit name, so ask
+                // systemd about this unit only
+                None => button(txt)
+                    .style(button::text)
+                    .padding(0)
+                    .on_press(Message::DataReceiver(DataReceiverMessage::GetUnitFile(
+                        row.clone(),
+                    ))),
+            }
+        }),
     ];
 
     table(columns, rows).padding(2).width(Length::Fill)
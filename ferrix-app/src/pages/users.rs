@@ -23,17 +23,72 @@
 use crate::{
     Message, fl,
     load_state::DataLoadingState,
-    widgets::table::{InfoRow, fmt_val, kv_info_table},
+    messages::ButtonsMessage,
+    widgets::table::{
+        InfoRow, SortState, fmt_bool, fmt_val, hdr_name, kv_info_table, sortable_hdr,
+    },
+};
+use ferrix_lib::{
+    sys::{Session, Sessions, Users},
+    utils::local_time_string,
 };
-use ferrix_lib::sys::Users;
 
-use iced::widget::{Id, column, container, scrollable, text};
+use iced::{
+    Element, Font, Length,
+    widget::{Id, column, container, row, scrollable, table, text},
+};
 
-pub fn users_page<'a>(users: &'a DataLoadingState<Users>) -> container::Container<'a, Message> {
+/// Fields by which the users list can be sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsersSortColumn {
+    Uid,
+    Name,
+}
+
+pub fn users_page<'a>(
+    users: &'a DataLoadingState<Users>,
+    sessions: &'a DataLoadingState<Sessions>,
+    sort: SortState<UsersSortColumn>,
+) -> container::Container<'a, Message> {
     match users {
         DataLoadingState::Loaded(users) => {
             let mut users_list = column![].spacing(5);
-            for usr in &users.users {
+            // Sessions are optional: utmp may be missing or unreadable
+            if let Some(sessions) = sessions.to_option() {
+                users_list = users_list.push(text(fl!("users-sessions")).style(text::warning));
+                users_list = users_list.push(if sessions.active.is_empty() {
+                    container(text(fl!("users-sessions-empty")).style(text::secondary))
+                } else {
+                    container(sessions_table(&sessions.active)).style(container::rounded_box)
+                });
+                if !sessions.last_logins.is_empty() {
+                    users_list =
+                        users_list.push(text(fl!("users-last-logins")).style(text::warning));
+                    users_list = users_list.push(
+                        container(sessions_table(&sessions.last_logins))
+                            .style(container::rounded_box),
+                    );
+                }
+            }
+
+            let on_sort = |column| Message::Buttons(ButtonsMessage::UsersSortChanged(column));
+            users_list = users_list.push(
+                row![
+                    hdr_name(fl!("users-sort")),
+                    sortable_hdr(fl!("users-id"), UsersSortColumn::Uid, &sort, on_sort),
+                    sortable_hdr(fl!("users-name"), UsersSortColumn::Name, &sort, on_sort),
+                ]
+                .spacing(10),
+            );
+
+            let mut users = users.users.iter().collect::<Vec<_>>();
+            users.sort_by(|a, b| {
+                sort.apply(match sort.column {
+                    UsersSortColumn::Uid => a.uid.cmp(&b.uid),
+                    UsersSortColumn::Name => a.name.cmp(&b.name),
+                })
+            });
+            for usr in users {
                 let rows = vec![
                     InfoRow::new(fl!("users-name"), Some(usr.name.clone())),
                     InfoRow::new(fl!("users-id"), fmt_val(Some(usr.uid))),
@@ -41,9 +96,19 @@ pub fn users_page<'a>(users: &'a DataLoadingState<Users>) -> container::Containe
                     InfoRow::new(fl!("users-gecos"), usr.gecos.clone()),
                     InfoRow::new(fl!("users-home"), Some(usr.home_dir.clone())),
                     InfoRow::new(fl!("users-shell"), Some(usr.login_shell.clone())),
+                    InfoRow::new(fl!("users-can-login"), fmt_bool(Some(usr.shell_is_valid))),
+                    InfoRow::new(
+                        fl!("users-groups"),
+                        (!usr.groups.is_empty()).then(|| usr.groups.join(", ")),
+                    ),
                 ];
+                let hdr = if usr.shell_is_valid {
+                    text(fl!("users-hdr", id = usr.uid)).style(text::warning)
+                } else {
+                    text(fl!("users-hdr-service", id = usr.uid)).style(text::secondary)
+                };
                 let usr_view = column![
-                    text(fl!("users-hdr", id = usr.uid)).style(text::warning),
+                    hdr,
                     container(kv_info_table(rows)).style(container::rounded_box),
                 ]
                 .spacing(5);
@@ -59,3 +124,26 @@ pub fn users_page<'a>(users: &'a DataLoadingState<Users>) -> container::Containe
         DataLoadingState::Loading => super::loading_page(),
     }
 }
+
+fn sessions_table<'a>(rows: &'a [Session]) -> Element<'a, Message> {
+    let columns = [
+        table::column(hdr_name(fl!("users-session-user")), |row: &'a Session| {
+            text(&row.user)
+        }),
+        table::column(hdr_name(fl!("users-session-tty")), |row: &'a Session| {
+            text(&row.tty).font(Font::MONOSPACE)
+        }),
+        table::column(
+            hdr_name(fl!("users-session-host")),
+            |row: &'a Session| match &row.host {
+                Some(host) => text(host),
+                None => text(fl!("users-session-local")).style(text::secondary),
+            },
+        ),
+        table::column(hdr_name(fl!("users-session-time")), |row: &'a Session| {
+            text(local_time_string(row.login_time).unwrap_or_default())
+        })
+        .width(Length::Fill),
+    ];
+    table(columns, rows).padding(2).width(Length::Fill).into()
+}
@@ -21,11 +21,11 @@
 //! CPU Vulnerabilities page
 
 use crate::{DataLoadingState, Message, fl, messages::ButtonsMessage, widgets::table::hdr_name};
-use ferrix_lib::vulnerabilities::Vulnerabilities;
+use ferrix_lib::vulnerabilities::{Status, Vuln, Vulnerabilities};
 
 use iced::{
     Length,
-    widget::{Id, button, container, scrollable, table, text},
+    widget::{Id, button, column, container, scrollable, table, text},
 };
 
 pub fn vulnerabilities_page<'a>(
@@ -33,10 +33,16 @@ pub fn vulnerabilities_page<'a>(
 ) -> container::Container<'a, Message> {
     match vulnerabilities {
         DataLoadingState::Loaded(vulns) => {
-            let vulns = &vulns.list;
-            let table = container(vuln_table(vulns)).style(container::rounded_box);
+            let affected_count = vulns.affected().len();
+            let affected =
+                text(fl!("vuln-affected", count = affected_count)).style(if affected_count > 0 {
+                    text::danger
+                } else {
+                    text::success
+                });
+            let table = container(vuln_table(&vulns.list)).style(container::rounded_box);
             container(
-                scrollable(table)
+                scrollable(column![affected, table].spacing(5))
                     .spacing(5)
                     .id(Id::new(super::Page::CPUVulnerabilities.page_id())),
             )
@@ -54,16 +60,12 @@ enum VulnType {
 }
 
 impl VulnType {
-    fn detect(descr: &str) -> Self {
-        let descr = descr.to_lowercase();
-        if descr.contains("vulnerable") {
-            Self::Danger
-        } else if descr.contains("mitigation") {
-            Self::Warning
-        } else if descr.contains("not affected") {
-            Self::Safe
-        } else {
-            Self::Unknown
+    fn detect(status: Status) -> Self {
+        match status {
+            Status::Vulnerable => Self::Danger,
+            Status::Mitigated => Self::Warning,
+            Status::NotAffected => Self::Safe,
+            Status::Unknown => Self::Unknown,
         }
     }
 
@@ -81,42 +83,38 @@ impl VulnType {
     }
 }
 
-fn vuln_table<'a>(rows: &'a [(String, String)]) -> table::Table<'a, Message> {
+fn vuln_table<'a>(rows: &'a [Vuln]) -> table::Table<'a, Message> {
     let columns = [
-        table::column(
-            hdr_name(fl!("vuln-hdr-name")),
-            |row: &'a (String, String)| text(row.0.trim()).wrapping(text::Wrapping::Word),
-        )
+        table::column(hdr_name(fl!("vuln-hdr-name")), |row: &'a Vuln| {
+            text(&row.name).wrapping(text::Wrapping::Word)
+        })
         .width(Length::FillPortion(1)),
-        table::column(
-            hdr_name(fl!("vuln-hdr-descr")),
-            |row: &'a (String, String)| {
-                let s = row.1.trim();
-                let vuln_type = VulnType::detect(s);
-                let vuln_str = vuln_type.format(s);
+        table::column(hdr_name(fl!("vuln-hdr-descr")), |row: &'a Vuln| {
+            let s = &row.description;
+            let vuln_type = VulnType::detect(row.status);
+            let vuln_str = vuln_type.format(s);
 
-                button(
-                    text(vuln_str.clone())
-                        .wrapping(text::Wrapping::WordOrGlyph)
-                        .style(move |t: &iced::Theme| {
-                            let p = t.palette();
-                            text::Style {
-                                color: Some(match vuln_type {
-                                    VulnType::Safe => p.success,
-                                    VulnType::Warning => p.warning,
-                                    VulnType::Danger => p.danger,
-                                    VulnType::Unknown => p.text,
-                                }),
-                            }
-                        }),
-                )
-                .style(button::text)
-                .padding(0)
-                .on_press(Message::Buttons(ButtonsMessage::CopyButtonPressed(
-                    format!("{}: {}", &row.0, &row.1),
-                )))
-            },
-        )
+            button(
+                text(vuln_str.clone())
+                    .wrapping(text::Wrapping::WordOrGlyph)
+                    .style(move |t: &iced::Theme| {
+                        let p = t.palette();
+                        text::Style {
+                            color: Some(match vuln_type {
+                                VulnType::Safe => p.success,
+                                VulnType::Warning => p.warning,
+                                VulnType::Danger => p.danger,
+                                VulnType::Unknown => p.text,
+                            }),
+                        }
+                    }),
+            )
+            .style(button::text)
+            .padding(0)
+            .on_press(Message::Buttons(ButtonsMessage::CopyButtonPressed(
+                format!("{}: {}", &row.name, &row.description),
+            )))
+        })
         .width(Length::FillPortion(3)),
     ];
     table(columns, rows).padding(2).width(Length::Fill)
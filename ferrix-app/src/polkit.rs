@@ -0,0 +1,103 @@
+/* polkit.rs
+ *
+ * Copyright 2026 Michail Krasnov <mskrasnov07@ya.ru>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Running privileged operations with `ferrix-polkit`
+
+use async_std::task;
+use std::{env, path::Path, process::Command, sync::LazyLock};
+
+static PATH: LazyLock<Vec<String>> = LazyLock::new(|| path());
+
+fn path() -> Vec<String> {
+    env::var("PATH")
+        .ok()
+        .and_then(|pth| Some(pth.split(':').map(|p| p.to_string()).collect::<Vec<_>>()))
+        .unwrap_or(vec!["/usr/bin".to_string()])
+}
+
+fn auth_app() -> Option<String> {
+    let apps = ["pkexec", "gksudo"];
+    let bin_dirs = &PATH;
+
+    for a in apps {
+        for b in bin_dirs.as_slice() {
+            let s = Path::new(b).join(a);
+            if s.exists() {
+                return Some(s.display().to_string());
+            }
+        }
+    }
+
+    None
+}
+
+fn fx_polkit_app() -> Option<String> {
+    let app = "ferrix-polkit";
+    let bin_dirs = &PATH;
+    for b in bin_dirs.as_slice() {
+        let s = Path::new(b).join(app);
+        if s.exists() {
+            return Some(s.display().to_string());
+        }
+    }
+    None
+}
+
+/// Runs `ferrix-polkit` with the selected arguments as root and
+/// returns its stdout
+pub async fn run(args: Vec<String>) -> Result<String, String> {
+    let auth_app = match auth_app() {
+        Some(auth_app) => auth_app,
+        None => return Err("No authentication software found".to_string()),
+    };
+    let fx_app = match fx_polkit_app() {
+        Some(fx_app) => fx_app,
+        None => return Err("No `ferrix-polkit` program found".to_string()),
+    };
+
+    let output =
+        task::spawn_blocking(move || Command::new(auth_app).arg(fx_app).args(args).output())
+            .await
+            .map_err(|why| why.to_string())?;
+
+    if output.status.code().unwrap_or(0) != 0 {
+        return Err(format!(
+            "[ferrix-polkit] Non-zero return code:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Sets the frequency governor for the selected CPU core
+pub async fn set_governor(core: usize, governor: String) -> Result<(), String> {
+    run(vec!["governor".to_string(), core.to_string(), governor])
+        .await
+        .map(|_| ())
+}
+
+/// Sets the charge level (%) at which the selected battery stops
+/// charging
+pub async fn set_charge_limit(bat: String, stop: u8) -> Result<(), String> {
+    run(vec!["charge-limit".to_string(), bat, stop.to_string()])
+        .await
+        .map(|_| ())
+}
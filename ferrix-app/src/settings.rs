@@ -21,26 +21,81 @@
 use anyhow::Result;
 use iced::{Theme, color};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fmt::Display, fs, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    fs,
+    ops::RangeInclusive,
+    path::Path,
+};
 
-use crate::{fl, styles::CPU_CHARTS_COLORS};
+use ferrix_lib::utils::{Size, fmt_bytes, fmt_bytes10};
+
+use crate::{
+    SETTINGS_PATH, fl, i18n, sidebar::SidebarGroup, styles::CPU_CHARTS_COLORS, utils::get_home,
+};
+
+/// Allowed values of the default text size (in pixels)
+pub const FONT_SIZES: RangeInclusive<u8> = 10..=20;
+const DEFAULT_FONT_SIZE: u8 = 12;
+
+fn default_font_size() -> u8 {
+    DEFAULT_FONT_SIZE
+}
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct FXSettings {
-    pub update_period: u8,
+    /// Single update period of old configs. Replaced with
+    /// [`FXSettings::update_periods`] on load
+    #[serde(default, rename = "update_period", skip_serializing)]
+    legacy_update_period: Option<u8>,
+
+    #[serde(default)]
+    pub update_periods: UpdatePeriods,
     pub charts_update_period_nsecs: u8,
     pub style: Style,
     pub chart_line_thickness: ChartLineThickness,
     pub chart_colors: ChartColors,
+
+    /// Collapsed groups of the sidebar
+    #[serde(default)]
+    pub collapsed_groups: HashSet<SidebarGroup>,
+
+    /// Interface language. If `None`, it is detected from the
+    /// desktop settings
+    #[serde(default)]
+    pub language: Option<String>,
+
+    /// Default text size (in pixels). Applied after restart
+    #[serde(default = "default_font_size")]
+    pub font_size: u8,
+
+    /// Unit of the displayed temperatures
+    #[serde(default)]
+    pub temp_unit: TempUnit,
+
+    /// Units of the displayed sizes
+    #[serde(default)]
+    pub byte_unit: ByteUnit,
 }
 
 impl FXSettings {
     pub fn read<P: AsRef<Path>>(pth: P) -> Result<Self> {
         let contents = fs::read_to_string(pth)?;
-        let data = toml::from_str(&contents)?;
+        let mut data: Self = toml::from_str(&contents)?;
+        if let Some(period) = data.legacy_update_period.take() {
+            data.update_periods = UpdatePeriods::uniform(period);
+        }
+        data.font_size = data.font_size.clamp(*FONT_SIZES.start(), *FONT_SIZES.end());
         Ok(data)
     }
 
+    /// Reads settings from the default config file or returns
+    /// default settings if it can't be read
+    pub fn load() -> Self {
+        Self::read(get_home().join(".config").join(SETTINGS_PATH)).unwrap_or_default()
+    }
+
     pub fn write<'a, P: AsRef<Path>>(&'a self, pth: P) -> Result<()> {
         let contents = toml::to_string(&self)?;
         fs::write(pth, contents)?;
@@ -51,24 +106,117 @@ impl FXSettings {
 impl Default for FXSettings {
     fn default() -> Self {
         Self {
-            update_period: 1,
+            legacy_update_period: None,
+            update_periods: UpdatePeriods::default(),
             charts_update_period_nsecs: 5,
             style: Style::default(),
             chart_line_thickness: ChartLineThickness::default(),
             chart_colors: ChartColors::default(),
+            collapsed_groups: HashSet::new(),
+            language: None,
+            font_size: DEFAULT_FONT_SIZE,
+            temp_unit: TempUnit::default(),
+            byte_unit: ByteUnit::default(),
+        }
+    }
+}
+
+/// Groups of data updated with their own period
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateSection {
+    /// CPU frequency, zram, battery, sensors and screens
+    Hardware,
+
+    /// Uptime, load average and other system information
+    System,
+
+    /// Mounted file systems
+    Storage,
+
+    /// systemd services
+    Services,
+}
+
+/// Update periods (in seconds) of the data sections
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct UpdatePeriods {
+    pub hardware: u8,
+    pub system: u8,
+    pub storage: u8,
+    pub services: u8,
+}
+
+impl UpdatePeriods {
+    /// Uses the same period for all sections
+    pub fn uniform(secs: u8) -> Self {
+        Self {
+            hardware: secs,
+            system: secs,
+            storage: secs,
+            services: secs,
+        }
+    }
+
+    pub fn get(&self, section: UpdateSection) -> u8 {
+        match section {
+            UpdateSection::Hardware => self.hardware,
+            UpdateSection::System => self.system,
+            UpdateSection::Storage => self.storage,
+            UpdateSection::Services => self.services,
+        }
+    }
+
+    pub fn set(&mut self, section: UpdateSection, secs: u8) {
+        match section {
+            UpdateSection::Hardware => self.hardware = secs,
+            UpdateSection::System => self.system = secs,
+            UpdateSection::Storage => self.storage = secs,
+            UpdateSection::Services => self.services = secs,
+        }
+    }
+}
+
+impl Default for UpdatePeriods {
+    fn default() -> Self {
+        Self {
+            hardware: 1,
+            system: 1,
+            storage: 10,
+            services: 10,
         }
     }
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, Default, PartialEq)]
+#[serde(from = "String")]
 pub enum Style {
     Light,
     #[default]
     Dark,
+    Nord,
+    Dracula,
+    CatppuccinLatte,
+    CatppuccinMocha,
+    TokyoNight,
+    TokyoNightLight,
+    SolarizedLight,
+    SolarizedDark,
 }
 
 impl Style {
-    pub const ALL: &[Self] = &[Self::Light, Self::Dark];
+    pub const ALL: &[Self] = &[
+        Self::Light,
+        Self::Dark,
+        Self::Nord,
+        Self::Dracula,
+        Self::CatppuccinLatte,
+        Self::CatppuccinMocha,
+        Self::TokyoNight,
+        Self::TokyoNightLight,
+        Self::SolarizedLight,
+        Self::SolarizedDark,
+    ];
 
     pub fn to_theme(&self) -> Theme {
         match self {
@@ -91,6 +239,34 @@ impl Style {
 
                 Theme::custom("Ferrix Dark Theme", palette)
             }
+            Self::Nord => Theme::Nord,
+            Self::Dracula => Theme::Dracula,
+            Self::CatppuccinLatte => Theme::CatppuccinLatte,
+            Self::CatppuccinMocha => Theme::CatppuccinMocha,
+            Self::TokyoNight => Theme::TokyoNight,
+            Self::TokyoNightLight => Theme::TokyoNightLight,
+            Self::SolarizedLight => Theme::SolarizedLight,
+            Self::SolarizedDark => Theme::SolarizedDark,
+        }
+    }
+}
+
+/// Styles removed in newer versions (or misspelled in the config
+/// file) fall back to the default one
+impl From<String> for Style {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "Light" => Self::Light,
+            "Dark" => Self::Dark,
+            "Nord" => Self::Nord,
+            "Dracula" => Self::Dracula,
+            "CatppuccinLatte" => Self::CatppuccinLatte,
+            "CatppuccinMocha" => Self::CatppuccinMocha,
+            "TokyoNight" => Self::TokyoNight,
+            "TokyoNightLight" => Self::TokyoNightLight,
+            "SolarizedLight" => Self::SolarizedLight,
+            "SolarizedDark" => Self::SolarizedDark,
+            _ => Self::default(),
         }
     }
 }
@@ -103,11 +279,40 @@ impl Display for Style {
             match self {
                 Self::Light => fl!("style-light"),
                 Self::Dark => fl!("style-dark"),
+                Self::Nord => "Nord".to_string(),
+                Self::Dracula => "Dracula".to_string(),
+                Self::CatppuccinLatte => "Catppuccin Latte".to_string(),
+                Self::CatppuccinMocha => "Catppuccin Mocha".to_string(),
+                Self::TokyoNight => "Tokyo Night".to_string(),
+                Self::TokyoNightLight => "Tokyo Night Light".to_string(),
+                Self::SolarizedLight => "Solarized Light".to_string(),
+                Self::SolarizedDark => "Solarized Dark".to_string(),
             }
         )
     }
 }
 
+/// Item of the language selector
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Language(pub Option<String>);
+
+impl Language {
+    pub fn all() -> Vec<Self> {
+        let mut langs = vec![Self(None)];
+        langs.extend(i18n::available_locales().into_iter().map(|l| Self(Some(l))));
+        langs
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(lang) => write!(f, "{lang}"),
+            None => write!(f, "{}", fl!("settings-lang-system")),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default, Deserialize, Serialize)]
 pub enum ChartLineThickness {
     #[default]
@@ -139,6 +344,101 @@ impl Display for ChartLineThickness {
     }
 }
 
+/// Unit of the displayed temperatures. Temperatures are stored (and
+/// exported) in Celsius and converted only for rendering
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub enum TempUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+impl TempUnit {
+    pub const ALL: &[Self] = &[Self::Celsius, Self::Fahrenheit];
+
+    pub fn convert(&self, celsius: f64) -> f64 {
+        match self {
+            Self::Celsius => celsius,
+            Self::Fahrenheit => celsius * 9. / 5. + 32.,
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Celsius => "°C",
+            Self::Fahrenheit => "°F",
+        }
+    }
+
+    /// Formats the temperature in Celsius with the selected unit
+    pub fn format(&self, celsius: f64) -> String {
+        format!("{:.1} {}", self.convert(celsius), self.symbol())
+    }
+}
+
+impl Display for TempUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Celsius => fl!("temp-celsius"),
+                Self::Fahrenheit => fl!("temp-fahrenheit"),
+            }
+        )
+    }
+}
+
+/// Units of the displayed sizes. Sizes are stored (and exported) in
+/// bytes and converted only for rendering
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub enum ByteUnit {
+    /// KiB, MiB, GiB (used for RAM)
+    #[default]
+    Binary,
+
+    /// kB, MB, GB (used by disk vendors)
+    Decimal,
+}
+
+impl ByteUnit {
+    pub const ALL: &[Self] = &[Self::Binary, Self::Decimal];
+
+    /// Formats the number of bytes with the selected units
+    pub fn format(&self, bytes: u64) -> String {
+        self.format_prec(bytes, 2)
+    }
+
+    /// Same as [`ByteUnit::format()`], but with `precision` digits
+    /// after the point
+    pub fn format_prec(&self, bytes: u64, precision: usize) -> String {
+        match self {
+            Self::Binary => fmt_bytes(bytes, precision),
+            Self::Decimal => fmt_bytes10(bytes, precision),
+        }
+    }
+
+    pub fn format_size(&self, size: &Size) -> Option<String> {
+        match self {
+            Self::Binary => size.human(2),
+            Self::Decimal => size.human10(2),
+        }
+    }
+}
+
+impl Display for ByteUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Binary => fl!("bytes-binary"),
+                Self::Decimal => fl!("bytes-decimal"),
+            }
+        )
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChartColors {
     pub colors: HashMap<String, (u8, u8, u8)>,
@@ -23,17 +23,26 @@
 use iced::{
     Alignment::Center,
     Element, Length,
-    widget::{Id, column, container, row, scrollable, text},
+    widget::{Id, button, column, container, row, scrollable, text, text_input},
 };
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 use crate::{
     Page, fl,
-    messages::Message,
+    messages::{ButtonsMessage, DataReceiverMessage, Message},
     widgets::{icon_button, sidebar_button},
 };
 
-pub fn sidebar<'a>(cur_page: Page) -> Element<'a, Message> {
+pub fn sidebar<'a>(
+    cur_page: Page,
+    filter: &'a str,
+    collapsed: &HashSet<SidebarGroup>,
+    failed_units: usize,
+) -> Element<'a, Message> {
     let buttons = row![
+        icon_button("refresh", fl!("sidebar-refresh"))
+            .on_press(Message::Buttons(ButtonsMessage::RefreshCurrentPage)),
         icon_button("export", fl!("sidebar-export")).on_press(Message::SelectPage(Page::Export)),
         icon_button("settings", fl!("sidebar-settings"))
             .on_press(Message::SelectPage(Page::Settings)),
@@ -43,10 +52,10 @@ pub fn sidebar<'a>(cur_page: Page) -> Element<'a, Message> {
     .align_y(Center);
 
     let pages = [
-        Item::Group(fl!("sidebar-basic")),
+        Item::Group(SidebarGroup::Basic),
         Item::Page(Page::Dashboard),
         Item::Page(Page::SystemMonitor),
-        Item::Group(fl!("sidebar-hardware")),
+        Item::Group(SidebarGroup::Hardware),
         Item::Page(Page::Processors),
         Item::Page(Page::CPUFrequency),
         Item::Page(Page::CPUVulnerabilities),
@@ -56,47 +65,130 @@ pub fn sidebar<'a>(cur_page: Page) -> Element<'a, Message> {
         Item::Page(Page::Battery),
         Item::Page(Page::Screen),
         Item::Page(Page::Sensors),
-        Item::Group(fl!("sidebar-admin")),
+        Item::Group(SidebarGroup::Admin),
         Item::Page(Page::Distro),
         Item::Page(Page::Users),
         Item::Page(Page::Groups),
         Item::Page(Page::Environment),
         Item::Page(Page::SystemManager),
         Item::Page(Page::Software),
-        Item::Group(fl!("sidebar-system")),
+        Item::Group(SidebarGroup::System),
         Item::Page(Page::Kernel),
         Item::Page(Page::KModules),
         Item::Page(Page::SystemMisc),
-        Item::Group(fl!("sidebar-manage")),
+        Item::Page(Page::Development),
+        Item::Group(SidebarGroup::Manage),
         Item::Page(Page::Settings),
         Item::Page(Page::About),
     ];
     let mut pages_list = iced::widget::Column::with_capacity(pages.len()).spacing(3);
 
-    for page in pages {
-        pages_list = pages_list.push(page.widget(cur_page));
+    let query = filter.trim().to_lowercase();
+    if query.is_empty() {
+        let mut hidden = false;
+        for page in pages {
+            match page {
+                Item::Group(group) => {
+                    hidden = collapsed.contains(&group);
+                    pages_list = pages_list.push(group.widget(hidden));
+                }
+                Item::Page(_) if hidden => {}
+                page => pages_list = pages_list.push(page.widget(cur_page, failed_units)),
+            }
+        }
+    } else {
+        // Groups make no sense in the filtered list
+        let found = pages
+            .into_iter()
+            .filter(|item| match item {
+                Item::Page(page) => page.title_str().to_lowercase().contains(&query),
+                Item::Group(_) => false,
+            })
+            .collect::<Vec<_>>();
+        if found.is_empty() {
+            pages_list = pages_list.push(text(fl!("sidebar-not-found")).style(text::secondary));
+        }
+        for page in found {
+            pages_list = pages_list.push(page.widget(cur_page, failed_units));
+        }
     }
 
-    container(column![
-        buttons,
-        scrollable(pages_list).spacing(5).id(Id::new("sidebar")),
-    ])
+    let search = text_input(&fl!("sidebar-search"), filter)
+        .on_input(|q| Message::DataReceiver(DataReceiverMessage::SidebarFilterChanged(q)))
+        .padding(3);
+
+    container(
+        column![
+            buttons,
+            search,
+            scrollable(pages_list).spacing(5).id(Id::new("sidebar")),
+        ]
+        .spacing(5),
+    )
     .padding(5)
     .style(container::bordered_box)
     .height(Length::Fill)
     .into()
 }
 
+/// Returns `true` if the systemd services page button (and so the
+/// failed units badge) is visible in the sidebar
+pub fn services_badge_shown(filter: &str, collapsed: &HashSet<SidebarGroup>) -> bool {
+    let query = filter.trim().to_lowercase();
+    if query.is_empty() {
+        !collapsed.contains(&SidebarGroup::Admin)
+    } else {
+        Page::SystemManager
+            .title_str()
+            .to_lowercase()
+            .contains(&query)
+    }
+}
+
+/// Category of pages in the sidebar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum SidebarGroup {
+    Basic,
+    Hardware,
+    Admin,
+    System,
+    Manage,
+}
+
+impl SidebarGroup {
+    pub fn title_str(&self) -> String {
+        match self {
+            Self::Basic => fl!("sidebar-basic"),
+            Self::Hardware => fl!("sidebar-hardware"),
+            Self::Admin => fl!("sidebar-admin"),
+            Self::System => fl!("sidebar-system"),
+            Self::Manage => fl!("sidebar-manage"),
+        }
+    }
+
+    fn widget<'a>(self, collapsed: bool) -> Element<'a, Message> {
+        let arrow = if collapsed { "▸" } else { "▾" };
+        button(text(format!("{arrow} {}", self.title_str())).style(text::secondary))
+            .style(button::text)
+            .padding(0)
+            .on_press(Message::Buttons(ButtonsMessage::ToggleSidebarGroup(self)))
+            .into()
+    }
+}
+
 enum Item {
-    Group(String),
+    Group(SidebarGroup),
     Page(Page),
 }
 
 impl Item {
-    pub fn widget<'a>(self, cur_page: Page) -> Element<'a, Message> {
+    pub fn widget<'a>(self, cur_page: Page, failed_units: usize) -> Element<'a, Message> {
         match self {
-            Self::Group(name) => text(name).style(text::secondary).into(),
-            Self::Page(page) => sidebar_button(page, cur_page).into(),
+            Self::Group(group) => group.widget(false),
+            Self::Page(page) => {
+                let badge = (page == Page::SystemManager).then_some(failed_units);
+                sidebar_button(page, cur_page, badge).into()
+            }
         }
     }
 }
@@ -18,7 +18,10 @@
  * SPDX-License-Identifier: GPL-3.0-or-later
  */
 
-use iced::{Color, Theme, color, widget::button};
+use iced::{
+    Color, Theme, color,
+    widget::{button, container},
+};
 
 pub const CPU_CHARTS_COLORS: &'static [Color] = &[
     color!(0xe6194b),
@@ -54,6 +57,18 @@ pub fn link_button(theme: &Theme, status: button::Status) -> button::Style {
     }
 }
 
+/// A small counter (e.g. number of failed units in the sidebar)
+pub fn badge(theme: &Theme) -> container::Style {
+    let palette = theme.extended_palette();
+
+    container::Style {
+        background: Some(palette.danger.base.color.into()),
+        text_color: Some(palette.danger.base.text),
+        border: iced::border::rounded(8),
+        ..container::Style::default()
+    }
+}
+
 pub fn button_disabled(style: button::Style) -> button::Style {
     button::Style {
         background: style.background.map(|b| b.scale_alpha(0.5)),
@@ -23,6 +23,8 @@ use crate::{
     load_state::LoadState,
     messages::{DataReceiverMessage, KeyboardMessage, Message},
     pages::Page,
+    settings::UpdateSection,
+    sidebar::services_badge_shown,
 };
 use iced::{Subscription, event, time};
 use std::time::Duration;
@@ -46,34 +48,59 @@ impl<T> PushMaybe<T> for Vec<T> {
 
 impl Ferrix {
     pub fn subscription(&self) -> Script<Message> {
+        let keyboard =
+            event::listen().map(|event| Message::Keyboard(KeyboardMessage::Event(event)));
+
+        // Snapshot data mustn't be replaced with the data of this
+        // machine
+        if self.data.snapshot.is_some() {
+            let mut scripts = vec![keyboard];
+            scripts.push_maybe(self.toasts_subscription());
+            return Subscription::batch(scripts);
+        }
+
         let charts_uperiod = self.settings.charts_update_period_nsecs as f32 * 0.1;
         let mut scripts = vec![
-            event::listen().map(|event| Message::Keyboard(KeyboardMessage::Event(event))),
+            keyboard,
             // Charts
             time::every(Duration::from_secs_f32(charts_uperiod))
                 .map(|_| Message::DataReceiver(DataReceiverMessage::AddCPUCoreLineSeries)),
             time::every(Duration::from_secs_f32(charts_uperiod))
                 .map(|_| Message::DataReceiver(DataReceiverMessage::AddTotalRAMUsage)),
+            time::every(Duration::from_secs_f32(charts_uperiod))
+                .map(|_| Message::DataReceiver(DataReceiverMessage::AddRAMBreakdown)),
         ];
+
         let oscripts = [
             self.cpu_basic_data(),
             self.cpu_stat_data(),
             self.ram_data(),
             self.swap_data(),
+            self.zram_subscription(),
             self.cpu_freq_subscription(),
+            self.cpu_freq_chart_subscription(),
             self.cpu_vuln_subscription(),
             self.storage_subscription(),
+            self.disk_io_subscription(),
+            self.smart_subscription(),
+            self.net_subscription(),
+            self.processes_subscription(),
             self.dmi_subscription(),
             self.battery_subscription(),
+            self.sensors_subscription(),
             self.drm_subscription(),
             self.osrel_subscription(),
+            self.boot_times_subscription(),
             self.users_subscription(),
+            self.sessions_subscription(),
             self.groups_subscription(),
             self.sysd_subscription(),
             self.soft_subscription(),
             self.env_and_sys_subscription(),
             self.kernel_subscription(),
             self.kmods_subscription(),
+            self.dev_subscription(),
+            self.toasts_subscription(),
         ];
         for oscr in oscripts {
             scripts.push_maybe(oscr);
@@ -81,8 +108,17 @@ impl Ferrix {
         Subscription::batch(scripts)
     }
 
-    fn u(&self) -> u64 {
-        self.settings.update_period as u64
+    /// Returns the update period of the section in seconds
+    fn u(&self, section: UpdateSection) -> u64 {
+        self.settings.update_periods.get(section) as u64
+    }
+
+    fn toasts_subscription(&self) -> OScript<Message> {
+        if self.data.toasts.is_empty() {
+            None
+        } else {
+            Some(time::every(Duration::from_secs(1)).map(|_| Message::ExpireToasts))
+        }
     }
 
     fn cpu_basic_data(&self) -> OScript<Message> {
@@ -149,6 +185,23 @@ impl Ferrix {
         }
     }
 
+    fn zram_subscription(&self) -> OScript<Message> {
+        if self.current_page == Page::Memory {
+            match self.data.zram_data {
+                LoadState::Loaded(_) => Some(
+                    time::every(Duration::from_secs(self.u(UpdateSection::Hardware)))
+                        .map(|_| Message::DataReceiver(DataReceiverMessage::GetZramData)),
+                ),
+                _ => Some(
+                    time::every(Duration::from_millis(START_UPERIOD))
+                        .map(|_| Message::DataReceiver(DataReceiverMessage::GetZramData)),
+                ),
+            }
+        } else {
+            None
+        }
+    }
+
     fn cpu_freq_subscription(&self) -> OScript<Message> {
         if self.current_page == Page::CPUFrequency && self.data.cpu_freq.is_none() {
             Some(
@@ -157,7 +210,7 @@ impl Ferrix {
             )
         } else if self.current_page == Page::CPUFrequency {
             Some(
-                time::every(Duration::from_secs(self.u()))
+                time::every(Duration::from_secs(self.u(UpdateSection::Hardware)))
                     .map(|_| Message::DataReceiver(DataReceiverMessage::GetCPUFrequency)),
             )
         } else {
@@ -165,6 +218,21 @@ impl Ferrix {
         }
     }
 
+    /// Frequency chart is shown only on the Processors page, so the
+    /// frequency is updated as fast as other charts only there
+    fn cpu_freq_chart_subscription(&self) -> OScript<Message> {
+        if self.current_page != Page::Processors {
+            return None;
+        }
+        let uperiod = self.settings.charts_update_period_nsecs as f32 * 0.1;
+        Some(Subscription::batch([
+            time::every(Duration::from_secs_f32(uperiod))
+                .map(|_| Message::DataReceiver(DataReceiverMessage::GetCPUFrequency)),
+            time::every(Duration::from_secs_f32(uperiod))
+                .map(|_| Message::DataReceiver(DataReceiverMessage::AddCPUFreqSeries)),
+        ]))
+    }
+
     fn cpu_vuln_subscription(&self) -> OScript<Message> {
         if self.current_page == Page::CPUVulnerabilities && self.data.cpu_vulnerabilities.is_none()
         {
@@ -187,7 +255,7 @@ impl Ferrix {
             )
         } else if self.current_page == Page::FileSystems {
             Some(
-                time::every(Duration::from_secs(10))
+                time::every(Duration::from_secs(self.u(UpdateSection::Storage)))
                     .map(|_| Message::DataReceiver(DataReceiverMessage::GetStorageData)),
             )
         } else {
@@ -195,8 +263,68 @@ impl Ferrix {
         }
     }
 
+    fn smart_subscription(&self) -> OScript<Message> {
+        // `smartctl` is slow, so SMART data is read only once
+        if self.current_page == Page::FileSystems && !self.data.smart.some_value() {
+            Some(
+                time::every(Duration::from_millis(START_UPERIOD))
+                    .map(|_| Message::DataReceiver(DataReceiverMessage::GetSmartData)),
+            )
+        } else {
+            None
+        }
+    }
+
+    fn disk_io_subscription(&self) -> OScript<Message> {
+        if self.current_page == Page::FileSystems {
+            let uperiod = self.settings.charts_update_period_nsecs as f32 * 0.1;
+            Some(Subscription::batch([
+                time::every(Duration::from_secs_f32(uperiod))
+                    .map(|_| Message::DataReceiver(DataReceiverMessage::GetDiskStats)),
+                time::every(Duration::from_secs_f32(uperiod))
+                    .map(|_| Message::DataReceiver(DataReceiverMessage::AddDiskIOSeries)),
+            ]))
+        } else {
+            None
+        }
+    }
+
+    fn net_subscription(&self) -> OScript<Message> {
+        if self.current_page == Page::SystemMonitor {
+            let uperiod = self.settings.charts_update_period_nsecs as f32 * 0.1;
+            Some(Subscription::batch([
+                time::every(Duration::from_secs_f32(uperiod))
+                    .map(|_| Message::DataReceiver(DataReceiverMessage::GetNetStats)),
+                time::every(Duration::from_secs_f32(uperiod))
+                    .map(|_| Message::DataReceiver(DataReceiverMessage::AddNetSeries)),
+            ]))
+        } else {
+            None
+        }
+    }
+
+    fn processes_subscription(&self) -> OScript<Message> {
+        if self.current_page == Page::SystemMonitor {
+            match self.data.processes {
+                LoadState::Loaded(_) => Some(
+                    time::every(Duration::from_secs(self.u(UpdateSection::System)))
+                        .map(|_| Message::DataReceiver(DataReceiverMessage::GetProcesses)),
+                ),
+                _ => Some(
+                    time::every(Duration::from_millis(START_UPERIOD))
+                        .map(|_| Message::DataReceiver(DataReceiverMessage::GetProcesses)),
+                ),
+            }
+        } else {
+            None
+        }
+    }
+
     fn dmi_subscription(&self) -> OScript<Message> {
-        if self.current_page == Page::DMI && !self.data.is_polkit && self.data.dmi_data.is_none() {
+        if self.current_page == Page::DMI
+            && !self.data.is_polkit
+            && !self.data.dmi_data.some_value()
+        {
             Some(
                 time::every(Duration::from_millis(START_UPERIOD))
                     .map(|_| Message::DataReceiver(DataReceiverMessage::GetDMIData)),
@@ -210,7 +338,7 @@ impl Ferrix {
         if self.current_page == Page::Dashboard || self.current_page == Page::Battery {
             match self.data.bat_data {
                 LoadState::Loaded(_) => Some(
-                    time::every(Duration::from_secs(self.u()))
+                    time::every(Duration::from_secs(self.u(UpdateSection::Hardware)))
                         .map(|_| Message::DataReceiver(DataReceiverMessage::GetBatInfo)),
                 ),
                 _ => Some(
@@ -223,6 +351,34 @@ impl Ferrix {
         }
     }
 
+    fn sensors_subscription(&self) -> OScript<Message> {
+        if self.current_page == Page::Sensors {
+            match self.data.sensors {
+                LoadState::Loaded(_) => Some(
+                    time::every(Duration::from_secs(self.u(UpdateSection::Hardware)))
+                        .map(|_| Message::DataReceiver(DataReceiverMessage::GetSensorsData)),
+                ),
+                _ => Some(
+                    time::every(Duration::from_millis(START_UPERIOD))
+                        .map(|_| Message::DataReceiver(DataReceiverMessage::GetSensorsData)),
+                ),
+            }
+        } else {
+            None
+        }
+    }
+
+    fn dev_subscription(&self) -> OScript<Message> {
+        if self.current_page == Page::Development && self.data.toolchains.is_none() {
+            Some(
+                time::every(Duration::from_millis(START_UPERIOD))
+                    .map(|_| Message::DataReceiver(DataReceiverMessage::GetToolchains)),
+            )
+        } else {
+            None
+        }
+    }
+
     fn drm_subscription(&self) -> OScript<Message> {
         if self.current_page == Page::Screen && self.data.drm_data.is_none() {
             Some(
@@ -231,7 +387,7 @@ impl Ferrix {
             )
         } else if self.current_page == Page::Screen && self.data.drm_data.is_some() {
             Some(
-                time::every(Duration::from_secs(self.u()))
+                time::every(Duration::from_secs(self.u(UpdateSection::Hardware)))
                     .map(|_| Message::DataReceiver(DataReceiverMessage::GetDRMData)),
             )
         } else {
@@ -239,6 +395,18 @@ impl Ferrix {
         }
     }
 
+    fn boot_times_subscription(&self) -> OScript<Message> {
+        // Isn't retried on errors (e.g. on systems without `systemd`)
+        if self.current_page == Page::Distro && !self.data.boot_times.some_value() {
+            Some(
+                time::every(Duration::from_millis(START_UPERIOD))
+                    .map(|_| Message::DataReceiver(DataReceiverMessage::GetBootTimes)),
+            )
+        } else {
+            None
+        }
+    }
+
     fn osrel_subscription(&self) -> OScript<Message> {
         if (self.current_page == Page::Dashboard || self.current_page == Page::Distro)
             && self.data.osrel_data.is_none()
@@ -263,8 +431,22 @@ impl Ferrix {
         }
     }
 
+    fn sessions_subscription(&self) -> OScript<Message> {
+        if self.current_page == Page::Users && self.data.sessions.is_none() {
+            Some(
+                time::every(Duration::from_millis(START_UPERIOD))
+                    .map(|_| Message::DataReceiver(DataReceiverMessage::GetSessionsData)),
+            )
+        } else {
+            None
+        }
+    }
+
     fn groups_subscription(&self) -> OScript<Message> {
-        if self.current_page == Page::Groups && self.data.groups_list.is_none() {
+        // Groups are also needed to show memberships on the Users page
+        if (self.current_page == Page::Groups || self.current_page == Page::Users)
+            && self.data.groups_list.is_none()
+        {
             Some(
                 time::every(Duration::from_millis(START_UPERIOD))
                     .map(|_| Message::DataReceiver(DataReceiverMessage::GetGroupsData)),
@@ -275,15 +457,20 @@ impl Ferrix {
     }
 
     fn sysd_subscription(&self) -> OScript<Message> {
-        if self.current_page == Page::SystemManager && self.data.sysd_services_list.is_none() {
+        // Errors (e.g. the bus isn't reachable on early boot) are
+        // retried with the regular update period
+        if self.current_page == Page::SystemManager && !self.data.sysd_services_list.some_value() {
             Some(
                 time::every(Duration::from_millis(START_UPERIOD))
                     .map(|_| Message::DataReceiver(DataReceiverMessage::GetSystemdServices)),
             )
-        } else if self.current_page == Page::SystemManager && self.data.sysd_services_list.is_some()
+        } else if self.current_page == Page::SystemManager
+            // Also updated on other pages while the number of failed
+            // units is shown in the sidebar
+            || services_badge_shown(&self.data.sidebar_filter, &self.settings.collapsed_groups)
         {
             Some(
-                time::every(Duration::from_secs(self.u() * 10))
+                time::every(Duration::from_secs(self.u(UpdateSection::Services)))
                     .map(|_| Message::DataReceiver(DataReceiverMessage::GetSystemdServices)),
             )
         } else {
@@ -313,9 +500,10 @@ impl Ferrix {
                         .map(|_| Message::DataReceiver(DataReceiverMessage::GetSystemData)),
                 )
             } else {
+                // The Dashboard shows the load average
                 match self.current_page {
-                    Page::SystemMisc => Some(
-                        time::every(Duration::from_secs(self.u()))
+                    Page::SystemMisc | Page::Dashboard => Some(
+                        time::every(Duration::from_secs(self.u(UpdateSection::System)))
                             .map(|_| Message::DataReceiver(DataReceiverMessage::GetSystemData)),
                     ),
                     _ => None,
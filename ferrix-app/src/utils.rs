@@ -20,7 +20,7 @@
 
 //! Utilities and helper functions
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use iced::Color;
 use std::{
     env,
@@ -28,10 +28,14 @@ use std::{
     process::Command,
 };
 
+/// Opens the URL or the file in the preferred application. Blocks
+/// until `xdg-open` exits to report its errors (e.g. when no browser
+/// is installed)
 pub fn xdg_open<O: ToString>(object: O) -> Result<()> {
-    Command::new("/usr/bin/xdg-open")
-        .arg(object.to_string())
-        .spawn()?;
+    let status = Command::new("xdg-open").arg(object.to_string()).status()?;
+    if !status.success() {
+        return Err(anyhow!("xdg-open exited with {status}"));
+    }
     Ok(())
 }
 
@@ -30,12 +30,13 @@ use iced::{
 };
 
 pub mod card;
+pub mod filterable_table;
 pub mod line_charts;
 pub mod table;
 pub mod separated_view;
 
 use crate::{
-    icons::{ABOUT_ICON, ERROR_ICON, EXPORT_ICON, SETTINGS_ICON},
+    icons::{ABOUT_ICON, ERROR_ICON, EXPORT_ICON, REFRESH_ICON, SETTINGS_ICON},
     messages::{ButtonsMessage, Message},
     pages::Page,
 };
@@ -49,6 +50,7 @@ where
         "error" => ERROR_ICON,
         "export" => EXPORT_ICON,
         "settings" => SETTINGS_ICON,
+        "refresh" => REFRESH_ICON,
         _ => &[],
     };
     let icon = svg(Handle::from_memory(svg_bytes))
@@ -118,8 +120,28 @@ pub fn icon_button<'a>(icon_name: &'a str, tooltip: String) -> button::Button<'a
     .padding(2)
 }
 
-pub fn sidebar_button<'a>(page: Page, cur_page: Page) -> button::Button<'a, Message> {
-    button(text(page.title_str()))
+/// Page button in the sidebar. `badge` is shown to the right of the
+/// title if it isn't zero
+pub fn sidebar_button<'a>(
+    page: Page,
+    cur_page: Page,
+    badge: Option<usize>,
+) -> button::Button<'a, Message> {
+    let title = text(page.title_str());
+    let content: Element<'a, Message> = match badge {
+        Some(count) if count > 0 => row![
+            title,
+            container(text(count.to_string()).size(11))
+                .padding([0, 5])
+                .style(super::styles::badge),
+        ]
+        .spacing(5)
+        .align_y(Center)
+        .into(),
+        _ => title.into(),
+    };
+
+    button(content)
         .style(if page != cur_page {
             button::subtle
         } else {
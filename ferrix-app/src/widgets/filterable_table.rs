@@ -0,0 +1,111 @@
+/* filterable_table.rs
+ *
+ * Copyright 2026 Michail Krasnov <mskrasnov07@ya.ru>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Table with the text filter above it
+
+use crate::{fl, messages::Message};
+use iced::{
+    Alignment::Center,
+    Element,
+    widget::{Id, center, column, container, row, scrollable, text, text_input},
+};
+
+/// Wraps the table with the text input which filters its rows. The
+/// input isn't scrolled together with the table
+pub struct FilterableTable<'a, T> {
+    pub rows: &'a [T],
+    pub query: &'a str,
+    pub on_input: Box<dyn Fn(String) -> Message + 'a>,
+    pub placeholder: String,
+    pub controls: Vec<Element<'a, Message>>,
+    pub id: Option<&'static str>,
+}
+
+impl<'a, T> FilterableTable<'a, T> {
+    pub fn new(rows: &'a [T], query: &'a str, on_input: impl Fn(String) -> Message + 'a) -> Self {
+        Self {
+            rows,
+            query,
+            on_input: Box::new(on_input),
+            placeholder: fl!("table-filter"),
+            controls: Vec::new(),
+            id: None,
+        }
+    }
+
+    pub fn set_placeholder<S: Into<String>>(mut self, placeholder: S) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Adds the widget (e.g. toggler) to the right of the text input
+    pub fn push_control(mut self, control: impl Into<Element<'a, Message>>) -> Self {
+        self.controls.push(control.into());
+        self
+    }
+
+    /// Sets the ID of the scrollable (see [`crate::pages::Page::page_id()`])
+    pub fn set_id(mut self, id: &'static str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Builds the widget. `key` returns the text matched against the
+    /// query and `table` creates the table from the filtered rows
+    pub fn view<K, V, E>(self, key: K, table: V) -> Element<'a, Message>
+    where
+        K: Fn(&T) -> &str,
+        V: FnOnce(Vec<&'a T>) -> E,
+        E: Into<Element<'a, Message>>,
+    {
+        let rows = filter_rows(self.rows, self.query, key);
+
+        let input = text_input(&self.placeholder, self.query)
+            .on_input(self.on_input)
+            .padding(5);
+        let mut controls = row![input].spacing(5).align_y(Center);
+        for control in self.controls {
+            controls = controls.push(control);
+        }
+
+        let content: Element<'a, Message> = if rows.is_empty() && !self.query.is_empty() {
+            center(text(fl!("table-filter-empty")).style(text::secondary)).into()
+        } else {
+            let mut list = scrollable(table(rows)).spacing(5);
+            if let Some(id) = self.id {
+                list = list.id(Id::new(id));
+            }
+            list.into()
+        };
+
+        container(column![controls, content].spacing(5)).into()
+    }
+}
+
+/// Returns rows which keys contain the query (case-insensitive)
+pub fn filter_rows<'a, T, K>(rows: &'a [T], query: &str, key: K) -> Vec<&'a T>
+where
+    K: Fn(&T) -> &str,
+{
+    let query = query.trim().to_lowercase();
+    rows.iter()
+        .filter(|row| query.is_empty() || key(row).to_lowercase().contains(&query))
+        .collect()
+}
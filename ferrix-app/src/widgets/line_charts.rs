@@ -20,6 +20,7 @@
 
 //! Linear charts
 
+use anyhow::anyhow;
 use iced::{
     Color as IColor, Element, Size, Theme,
     widget::{
@@ -29,9 +30,12 @@ use iced::{
 };
 use plotters::prelude::*;
 use plotters_iced2::{Chart, ChartBuilder, ChartWidget, DrawingBackend};
-use std::collections::VecDeque;
+use std::{collections::VecDeque, path::Path};
 
-use crate::{messages::Message, settings::ChartLineThickness};
+use crate::{
+    messages::Message,
+    settings::{ByteUnit, ChartLineThickness},
+};
 
 #[derive(Debug, Clone)]
 pub struct LineChart {
@@ -39,6 +43,25 @@ pub struct LineChart {
     max_points: usize,
     style: Style,
     show_legend: bool,
+
+    /// Fixed upper bound of the Y axis
+    y_max: f64,
+
+    /// Compute the upper bound of the Y axis from the visible values
+    /// instead of using the fixed [`LineChart::y_max`]
+    auto_scale: bool,
+    unit: &'static str,
+
+    /// Values are transfer rates in bytes per second, formatted with
+    /// these units instead of [`LineChart::unit`]
+    byte_rate: Option<ByteUnit>,
+
+    /// Draw series as stacked areas (each series on top of the
+    /// previous ones)
+    stacked: bool,
+
+    /// New values are ignored while the chart is paused
+    paused: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -100,9 +123,85 @@ impl LineChart {
             max_points: 100,
             style: Style::default(),
             show_legend: true,
+            y_max: 100.,
+            auto_scale: false,
+            unit: "%",
+            byte_rate: None,
+            stacked: false,
+            paused: false,
         }
     }
 
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn toggle_stacked(&mut self) {
+        self.stacked = !self.stacked;
+    }
+
+    /// Sets the units of values (`%` by default)
+    pub fn set_unit(&mut self, unit: &'static str) {
+        self.unit = unit;
+    }
+
+    /// Marks values as transfer rates in bytes per second and sets the
+    /// units used to display them
+    pub fn set_byte_rate(&mut self, unit: ByteUnit) {
+        self.byte_rate = Some(unit);
+    }
+
+    fn fmt_value(&self, value: f64, precision: usize) -> String {
+        match self.byte_rate {
+            Some(unit) => format!("{}/s", unit.format_prec(value as u64, precision)),
+            None => format!("{value:.precision$}{}", self.unit),
+        }
+    }
+
+    /// Sets the fixed upper bound of the Y axis (`100` by default)
+    pub fn set_y_max(&mut self, y_max: f64) {
+        self.y_max = y_max;
+    }
+
+    /// Enables or disables scaling of the Y axis by the maximum of
+    /// visible values (disabled by default)
+    pub fn set_auto_scale(&mut self, auto_scale: bool) {
+        self.auto_scale = auto_scale;
+    }
+
+    fn y_max(&self) -> f64 {
+        if !self.auto_scale {
+            return self.y_max;
+        }
+
+        let max = if self.stacked {
+            let mut sums = Vec::<f64>::new();
+            for series in &self.data {
+                sums.resize(sums.len().max(series.data.len()), 0.);
+                for (sum, val) in sums.iter_mut().zip(&series.data) {
+                    *sum += val;
+                }
+            }
+            sums.into_iter().fold(0., f64::max)
+        } else {
+            self.data
+                .iter()
+                .flat_map(|s| s.data.iter())
+                .fold(0., |max: f64, val| max.max(*val))
+        };
+        // Leave some space above the highest point so the line
+        // doesn't stick to the top border
+        (max * 1.1).max(1.)
+    }
+
     pub fn set_style(&mut self, theme: &Theme) {
         let style = Style {
             y_axis_color: theme.palette().text,
@@ -132,14 +231,14 @@ impl LineChart {
     }
 
     pub fn push_to(&mut self, idx: usize, value: f64) {
-        if self.data.len() < idx {
+        if self.paused || self.data.len() < idx {
             return;
         }
         self.data[idx].push(value);
     }
 
     pub fn push_value(&mut self, value: f64, idx: usize) {
-        if self.data.len() < idx {
+        if self.paused || self.data.len() < idx {
             return;
         }
         self.update_axis();
@@ -165,7 +264,7 @@ impl LineChart {
                     text(format!("{}:", &line.name))
                         .color(to_icolor(line.color))
                         .font(bold_font),
-                    text(format!("{:.2}%", line.data[last])),
+                    text(self.fmt_value(line.data[last], 2)),
                 ]
                 .spacing(3),
             );
@@ -198,28 +297,18 @@ impl LineChart {
             }
         }
     }
-}
 
-impl Chart<Message> for LineChart {
-    type State = ();
-
-    #[inline]
-    fn draw<R: plotters_iced2::Renderer, F: Fn(&mut Frame)>(
+    /// Draws the chart on any `plotters` backend
+    fn draw_chart<DB: DrawingBackend>(
         &self,
-        renderer: &R,
-        size: Size,
-        f: F,
-    ) -> Geometry {
-        renderer.draw_cache(&Cache::new(), size, f)
-    }
-
-    fn build_chart<DB: DrawingBackend>(&self, _state: &Self::State, mut builder: ChartBuilder<DB>) {
+        mut builder: ChartBuilder<DB>,
+        legend: bool,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let mut chart = builder
             .x_label_area_size(0)
             .y_label_area_size(35)
             .margin(5)
-            .build_cartesian_2d(0..(self.max_points), 0.0..100.0)
-            .expect("Failed to build chart");
+            .build_cartesian_2d(0..(self.max_points), 0.0..self.y_max())?;
 
         chart
             .configure_mesh()
@@ -236,27 +325,97 @@ impl Chart<Message> for LineChart {
                     .color(&to_rgbcolor(self.style.y_axis_color))
                     .transform(FontTransform::Rotate270),
             )
-            .y_label_formatter(&|y: &f64| format!("{y:.0}%"))
-            .draw()
-            .expect("Failed to draw chart mesh");
+            .y_label_formatter(&|y: &f64| self.fmt_value(*y, 0))
+            .draw()?;
 
-        for series in &self.data {
+        let layers = if self.stacked {
+            // Sum of values of the current and all previous series.
+            // Upper layers are drawn first, so they don't hide the
+            // lower ones
+            let mut sums = Vec::<f64>::new();
+            let mut layers = Vec::with_capacity(self.data.len());
+            for series in &self.data {
+                sums.resize(sums.len().max(series.data.len()), 0.);
+                for (sum, val) in sums.iter_mut().zip(&series.data) {
+                    *sum += val;
+                }
+                layers.push((series, sums.clone(), series.color.mix(0.4)));
+            }
+            layers.reverse();
+            layers
+        } else {
+            self.data
+                .iter()
+                .map(|series| {
+                    let values = series.data.iter().copied().collect::<Vec<_>>();
+                    (series, values, plotters::style::TRANSPARENT)
+                })
+                .collect()
+        };
+
+        for (series, values, fill) in layers {
             chart
                 .draw_series(
-                    AreaSeries::new(
-                        series.data.iter().enumerate().map(|x| (x.0, *x.1 as f64)),
-                        0.,
-                        plotters::style::TRANSPARENT,
-                    )
-                    .border_style(
+                    AreaSeries::new(values.into_iter().enumerate(), 0., fill).border_style(
                         ShapeStyle::from(series.color).stroke_width(self.style.line_thickness),
                     ),
-                )
-                .expect("Failed to draw chart data")
+                )?
                 .label(&series.name)
                 .legend(|(x, y)| {
                     Rectangle::new([(x - 5, y - 3), (x + 15, y + 8)], series.color.filled())
                 });
         }
+
+        if legend {
+            chart
+                .configure_series_labels()
+                .position(SeriesLabelPosition::UpperLeft)
+                .label_font(
+                    ("sans-serif", 12)
+                        .into_font()
+                        .color(&to_rgbcolor(self.style.y_axis_color)),
+                )
+                .border_style(to_rgbcolor(self.style.y_axis_color).mix(0.3))
+                .draw()?;
+        }
+
+        Ok(())
+    }
+
+    /// Saves the chart (with the legend) into the PNG image
+    pub fn save_png<P: AsRef<Path>>(&self, path: P, size: (u32, u32)) -> anyhow::Result<()> {
+        let root = BitMapBackend::new(path.as_ref(), size).into_drawing_area();
+        // The axis color is the text color of the theme, so the
+        // light text means the dark theme
+        let text = self.style.y_axis_color;
+        let background = if text.r + text.g + text.b > 1.5 {
+            RGBColor(32, 32, 32)
+        } else {
+            WHITE
+        };
+        root.fill(&background).map_err(|e| anyhow!("{e}"))?;
+        self.draw_chart(ChartBuilder::on(&root), true)
+            .map_err(|e| anyhow!("{e}"))?;
+        root.present().map_err(|e| anyhow!("{e}"))?;
+        Ok(())
+    }
+}
+
+impl Chart<Message> for LineChart {
+    type State = ();
+
+    #[inline]
+    fn draw<R: plotters_iced2::Renderer, F: Fn(&mut Frame)>(
+        &self,
+        renderer: &R,
+        size: Size,
+        f: F,
+    ) -> Geometry {
+        renderer.draw_cache(&Cache::new(), size, f)
+    }
+
+    fn build_chart<DB: DrawingBackend>(&self, _state: &Self::State, builder: ChartBuilder<DB>) {
+        self.draw_chart(builder, false)
+            .expect("Failed to draw chart");
     }
 }
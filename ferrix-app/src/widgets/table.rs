@@ -27,6 +27,9 @@ use iced::{
     widget::{table, text},
 };
 
+use ferrix_lib::sys::SysctlError;
+use std::{cmp::Ordering, fmt::Display};
+
 use crate::messages::ButtonsMessage;
 use crate::{Message, fl, widgets::link_button};
 
@@ -34,6 +37,10 @@ use crate::{Message, fl, widgets::link_button};
 pub struct InfoRow<V> {
     pub param_header: String,
     pub value: Option<V>,
+
+    /// Why the value is `None`. Rows without the reason show an empty
+    /// cell
+    pub missing: Option<MissingReason>,
 }
 
 impl<V> InfoRow<V> {
@@ -45,10 +52,52 @@ impl<V> InfoRow<V> {
         Self {
             param_header: param.into(),
             value,
+            missing: None,
+        }
+    }
+
+    /// Sets the reason shown instead of the missing value. Doesn't
+    /// override the reason set earlier
+    pub fn or_missing(mut self, reason: MissingReason) -> Self {
+        self.missing = self.missing.or(Some(reason));
+        self
+    }
+}
+
+/// Why the value can't be shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingReason {
+    /// Hardware, driver or kernel doesn't provide this value
+    NotSupported,
+
+    /// The value can only be read by root
+    PermissionDenied,
+
+    /// The device or the record doesn't exist
+    NotPresent,
+}
+
+impl From<&SysctlError> for MissingReason {
+    fn from(value: &SysctlError) -> Self {
+        match value {
+            SysctlError::PermissionDenied(_) => Self::PermissionDenied,
+            SysctlError::NotFound(_) => Self::NotPresent,
+            SysctlError::InvalidKey(_) | SysctlError::Io(_) => Self::NotSupported,
         }
     }
 }
 
+impl Display for MissingReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::NotSupported => fl!("missing-not-supported"),
+            Self::PermissionDenied => fl!("missing-permission-denied"),
+            Self::NotPresent => fl!("missing-not-present"),
+        };
+        write!(f, "{s}")
+    }
+}
+
 pub fn kv_info_table<'a, V>(rows: Vec<InfoRow<V>>) -> Element<'a, Message>
 where
     V: ToString + Clone + 'a,
@@ -57,10 +106,8 @@ where
         table::column(hdr_name(fl!("hdr-param")), |row: InfoRow<V>| {
             text(row.param_header)
         }),
-        table::column(hdr_name(fl!("hdr-value")), |row: InfoRow<V>| {
-            text_fmt_val(row.value)
-        })
-        .width(Length::Fill),
+        table::column(hdr_name(fl!("hdr-value")), |row: InfoRow<V>| row_value(row))
+            .width(Length::Fill),
     ];
 
     table(columns, rows).padding(2).width(Length::Fill).into()
@@ -88,6 +135,87 @@ where
     }
 }
 
+/// Formats the value of the row or the reason why it's missing
+pub fn row_value<'a, V>(row: InfoRow<V>) -> Element<'a, Message>
+where
+    V: ToString + 'a,
+{
+    match (row.value, row.missing) {
+        (None, Some(reason)) => text(reason.to_string()).style(text::secondary).into(),
+        (value, _) => text_fmt_val(value),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// Column by which the table is sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortState<C> {
+    pub column: C,
+    pub order: SortOrder,
+}
+
+impl<C: PartialEq + Copy> SortState<C> {
+    pub fn new(column: C) -> Self {
+        Self {
+            column,
+            order: SortOrder::default(),
+        }
+    }
+
+    /// Reverses the order if the same column is selected again.
+    /// Otherwise sorts by the new column in ascending order
+    pub fn toggle(self, column: C) -> Self {
+        if self.column != column {
+            return Self::new(column);
+        }
+        let order = match self.order {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        };
+        Self { column, order }
+    }
+
+    /// Applies the order to the result of ascending comparison
+    pub fn apply(&self, ordering: Ordering) -> Ordering {
+        match self.order {
+            SortOrder::Ascending => ordering,
+            SortOrder::Descending => ordering.reverse(),
+        }
+    }
+}
+
+/// Clickable column header which changes the sorting of the table
+pub fn sortable_hdr<'a, C>(
+    name: String,
+    column: C,
+    sort: &SortState<C>,
+    on_press: impl Fn(C) -> Message,
+) -> Element<'a, Message>
+where
+    C: PartialEq + Copy,
+{
+    let name = if sort.column == column {
+        let arrow = match sort.order {
+            SortOrder::Ascending => "▲",
+            SortOrder::Descending => "▼",
+        };
+        format!("{name} {arrow}")
+    } else {
+        name
+    };
+    button(hdr_name(name))
+        .style(button::text)
+        .padding(0)
+        .on_press(on_press(column))
+        .into()
+}
+
 pub fn hdr_name<'a, S: IntoFragment<'a>>(s: S) -> text::Text<'a> {
     text(s).style(text::secondary)
 }
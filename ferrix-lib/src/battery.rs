@@ -20,19 +20,22 @@
 
 //! Get information about notebook's battery
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 use std::{
-    fs::{read_dir, read_to_string},
+    fs::{read_dir, read_to_string, write},
     path::Path,
+    time::Duration,
 };
 
 use crate::traits::ToJson;
 
 /// Information about all installed batteries
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct BatInfo {
     pub bats: Vec<Battery>,
+    pub power_supply: PowerSupply,
 }
 
 impl BatInfo {
@@ -45,7 +48,10 @@ impl BatInfo {
             let dir = dir?.path();
             let bat_path = dir.join("type");
             let bat_type = read_to_string(&bat_path)?;
-            if bat_type.trim() == "Battery" {
+            // Batteries of HID peripherals (mice, keyboards, gamepads)
+            // have `scope` = `Device`
+            let scope = read_to_string(dir.join("scope")).unwrap_or_default();
+            if bat_type.trim() == "Battery" && scope.trim() != "Device" {
                 let uevent_path = dir.join("uevent");
                 if uevent_path.is_file() {
                     bats.push(Battery::new(uevent_path)?);
@@ -54,19 +60,138 @@ impl BatInfo {
                 continue;
             }
         }
-        Ok(Self { bats })
+        // `read_dir()` doesn't guarantee any order, but `BAT0` should
+        // always go before `BAT1`
+        bats.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(Self {
+            bats,
+            // The batteries are still worth showing if the adapters
+            // can't be read
+            power_supply: PowerSupply::new().unwrap_or_else(|why| PowerSupply {
+                error: Some(why.to_string()),
+                ..PowerSupply::default()
+            }),
+        })
     }
+
+    /// Returns the first (main) battery
+    pub fn primary(&self) -> Option<&Battery> {
+        self.bats.first()
+    }
+
+    /// Returns the total charge of all batteries in percents
+    ///
+    /// If the energy values are known for all batteries, the charge
+    /// is weighted by their capacity. Otherwise the average of
+    /// `capacity` values is returned.
+    pub fn total_percentage(&self) -> Option<f32> {
+        if self.bats.is_empty() {
+            return None;
+        }
+
+        let energy = self
+            .bats
+            .iter()
+            .map(|b| b.energy_now.zip(b.energy_full))
+            .collect::<Option<Vec<_>>>();
+        if let Some(energy) = energy {
+            let (now, full) = energy
+                .iter()
+                .fold((0., 0.), |acc, e| (acc.0 + e.0, acc.1 + e.1));
+            if full > 0. {
+                return Some((now / full * 100.).min(100.));
+            }
+        }
+
+        let capacities = self
+            .bats
+            .iter()
+            .filter_map(|b| b.capacity)
+            .collect::<Vec<_>>();
+        if capacities.is_empty() {
+            None
+        } else {
+            Some(capacities.iter().map(|c| *c as f32).sum::<f32>() / capacities.len() as f32)
+        }
+    }
+}
+
+impl ToJson for BatInfo {}
+
+/// State of the external power supply (AC adapter)
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PowerSupply {
+    /// Is the system powered from the mains. `None` if no AC adapter
+    /// was found
+    pub on_ac: Option<bool>,
+
+    /// Name of the adapter (e.g. `AC`, `ADP1`). If there are several
+    /// adapters, the online one is preferred
+    pub adapter: Option<String>,
+
+    /// Error that occurred while reading the state of the adapters
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl PowerSupply {
+    pub fn new() -> Result<Self> {
+        let mut adapters = Vec::new();
+        let base_path = Path::new("/sys/class/power_supply/");
+
+        for dir in read_dir(base_path)? {
+            let dir = dir?.path();
+            let name = match dir.file_name() {
+                Some(name) => name.to_string_lossy().to_string(),
+                None => continue,
+            };
+            let supply_type = read_to_string(dir.join("type")).unwrap_or_default();
+            if supply_type.trim() != "Mains" && !is_adapter_name(&name) {
+                continue;
+            }
+
+            let online = read_to_string(dir.join("online"))
+                .map(|online| online.trim() == "1")
+                .unwrap_or(false);
+            adapters.push((name, online));
+        }
+        adapters.sort();
+
+        let adapter = adapters
+            .iter()
+            .find(|(_, online)| *online)
+            .or(adapters.first());
+        Ok(Self {
+            on_ac: adapter.map(|(_, online)| *online),
+            adapter: adapter.map(|(name, _)| name.clone()),
+            error: None,
+        })
+    }
+}
+
+impl ToJson for PowerSupply {}
+
+fn is_adapter_name(name: &str) -> bool {
+    name.starts_with("AC") || name.starts_with("ADP")
 }
 
 /// Information from the `uevent` file
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Battery {
     pub name: Option<String>,
     pub status: Option<Status>,
     pub technology: Option<String>,
-    pub cycle_count: Option<usize>,
+    /// Number of charge cycles. `None` if the battery doesn't
+    /// report it
+    pub cycle_count: Option<u32>,
     pub voltage_min_design: Option<f32>,
     pub voltage_now: Option<f32>,
+    pub current_now: Option<f32>,
+
+    /// Instantaneous power draw in watts. Computed as
+    /// `current_now * voltage_now` if the firmware doesn't report it
     pub power_now: Option<f32>,
     pub energy_full_design: Option<f32>,
     pub energy_full: Option<f32>,
@@ -76,9 +201,18 @@ pub struct Battery {
     pub model_name: Option<String>,
     pub manufacturer: Option<String>,
     pub serial_number: Option<String>,
-    pub health: Option<f32>,
+    /// Battery health: `energy_full / energy_full_design * 100`,
+    /// clamped to 0-100%
+    pub health_percentage: Option<f64>,
     pub estimated_time: Option<f32>,
     pub charge_time: Option<f32>,
+
+    /// Charge level (%) below which the battery starts charging.
+    /// `None` if the hardware doesn't support charge thresholds
+    pub charge_start_threshold: Option<u8>,
+
+    /// Charge level (%) at which the battery stops charging
+    pub charge_stop_threshold: Option<u8>,
 }
 
 impl ToJson for Battery {}
@@ -99,9 +233,73 @@ impl Battery {
         calculate_time(&mut bat);
         calculate_health(&mut bat);
         polish_values(&mut bat);
+        calculate_power(&mut bat);
+
+        if let Some(dir) = path.as_ref().parent() {
+            read_thresholds(&mut bat, dir);
+        }
 
         Ok(bat)
     }
+
+    /// Estimated time until the battery is empty. Available only
+    /// when the battery is discharging
+    pub fn time_to_empty(&self) -> Option<Duration> {
+        match self.status {
+            Some(Status::Discharging) => {}
+            _ => return None,
+        }
+        let energy = self.energy_now? as f64;
+        let power = self.power_now? as f64;
+        if power < 0.001 {
+            return None;
+        }
+
+        Some(Duration::from_secs_f64(energy / power * 3600.))
+    }
+}
+
+/// Sets the charge level (%) at which the battery `bat` (e.g.
+/// `BAT0`) stops charging. Requires root privileges
+///
+/// `bat` must be the name of an existing battery in
+/// `/sys/class/power_supply/`, not a path
+pub fn set_charge_limit(bat: &str, stop: u8) -> Result<()> {
+    if !(1..=100).contains(&stop) {
+        return Err(anyhow!("Charge limit must be in 1-100% (got {stop}%)"));
+    }
+    if !is_valid_supply_name(bat) {
+        return Err(anyhow!("Invalid battery name: {bat:?}"));
+    }
+
+    let dir = Path::new("/sys/class/power_supply/").join(bat);
+    let supply_type = read_to_string(dir.join("type")).unwrap_or_default();
+    if supply_type.trim() != "Battery" {
+        return Err(anyhow!("{bat} is not a battery"));
+    }
+
+    let file = dir.join("charge_control_end_threshold");
+    if !file.is_file() {
+        return Err(anyhow!("Battery {bat} doesn't support charge thresholds"));
+    }
+
+    write(file, stop.to_string())?;
+    Ok(())
+}
+
+/// Power supply names are single path components like `BAT0`
+fn is_valid_supply_name(name: &str) -> bool {
+    !name.is_empty() && name != "." && name != ".." && !name.contains('/')
+}
+
+fn read_thresholds(bat: &mut Battery, dir: &Path) {
+    let read = |file: &str| {
+        read_to_string(dir.join(file))
+            .ok()
+            .and_then(|val| val.trim().parse().ok())
+    };
+    bat.charge_start_threshold = read("charge_control_start_threshold");
+    bat.charge_stop_threshold = read("charge_control_end_threshold");
 }
 
 fn parse_chunks(bat: &mut Battery, key: &str, val: &str) {
@@ -110,9 +308,11 @@ fn parse_chunks(bat: &mut Battery, key: &str, val: &str) {
         "POWER_SUPPLY_NAME" => bat.name = Some(val.to_string()),
         "POWER_SUPPLY_STATUS" => bat.status = Some(Status::from(val)),
         "POWER_SUPPLY_TECHNOLOGY" => bat.technology = Some(val.to_string()),
-        "POWER_SUPPLY_CYCLE_COUNT" => bat.cycle_count = val.parse().ok(),
+        // Many batteries report `0` if they don't count the cycles
+        "POWER_SUPPLY_CYCLE_COUNT" => bat.cycle_count = val.parse().ok().filter(|c| *c > 0),
         "POWER_SUPPLY_VOLTAGE_MIN_DESIGN" => bat.voltage_min_design = val.parse().ok(),
         "POWER_SUPPLY_VOLTAGE_NOW" => bat.voltage_now = val.parse().ok(),
+        "POWER_SUPPLY_CURRENT_NOW" => bat.current_now = val.parse().ok(),
         "POWER_SUPPLY_POWER_NOW" => bat.power_now = val.parse().ok(),
         "POWER_SUPPLY_ENERGY_FULL_DESIGN" => bat.energy_full_design = val.parse().ok(),
         "POWER_SUPPLY_ENERGY_FULL" => bat.energy_full = val.parse().ok(),
@@ -136,6 +336,9 @@ fn polish_values(bat: &mut Battery) {
     if let Some(vn) = bat.voltage_now {
         bat.voltage_now = Some(vn / 1_000_000.);
     }
+    if let Some(cn) = bat.current_now {
+        bat.current_now = Some(cn / 1_000_000.);
+    }
     if let Some(efd) = bat.energy_full_design {
         bat.energy_full_design = Some(efd / 1_000_000.);
     }
@@ -147,11 +350,20 @@ fn polish_values(bat: &mut Battery) {
     }
 }
 
+fn calculate_power(bat: &mut Battery) {
+    if bat.power_now.is_none()
+        && let (Some(c), Some(v)) = (bat.current_now, bat.voltage_now)
+    {
+        bat.power_now = Some(c * v);
+    }
+}
+
 fn calculate_health(bat: &mut Battery) {
-    if bat.energy_full.is_some() && bat.energy_full_design.is_some() {
-        let (energy_full, energy_full_design) =
-            (bat.energy_full.unwrap(), bat.energy_full_design.unwrap());
-        bat.health = Some((energy_full / energy_full_design * 100.).min(100.));
+    if let (Some(energy_full), Some(energy_full_design)) = (bat.energy_full, bat.energy_full_design)
+        && energy_full_design > 0.
+    {
+        let health = energy_full as f64 / energy_full_design as f64 * 100.;
+        bat.health_percentage = Some(health.clamp(0., 100.));
     }
 }
 
@@ -176,6 +388,7 @@ fn calculate_time(bat: &mut Battery) {
 
 /// Charging status
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum Status {
     Full,
     Discharging,
@@ -200,6 +413,7 @@ impl From<&str> for Status {
 
 /// Capacity level
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum Level {
     Full,
     Normal,
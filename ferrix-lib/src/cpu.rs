@@ -34,27 +34,47 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::fs::read_to_string;
+use std::{fs::read_to_string, thread, time::Duration};
 
-use crate::traits::{ToJson, ToPlainText, print_opt_val};
-use crate::utils::Size;
+use crate::traits::{ToJson, ToMarkdown, ToPlainText, md_opt_val, md_table_header, print_opt_val};
+use crate::utils::{Size, read_optional};
 
 /// A structure containing data from the `/proc/cpuinfo` file
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Processors {
     /// Information about all core/thread
     pub entries: Vec<CPU>,
+
+    /// Deduplicated information about physical processors
+    pub packages: Vec<CpuPackage>,
 }
 
 impl Processors {
     pub fn new() -> Result<Self> {
-        Ok(Self {
-            entries: read_info()?,
-        })
+        let entries = read_info()?;
+        let packages = CpuPackage::from_entries(&entries);
+        Ok(Self { entries, packages })
+    }
+
+    /// Checks whether all physical processors support the `flag`
+    /// (e.g. `avx2`)
+    pub fn has_flag(&self, flag: &str) -> bool {
+        !self.packages.is_empty() && self.packages.iter().all(|p| p.has_flag(flag))
     }
 }
 
 impl ToJson for Processors {}
+
+impl ToMarkdown for Processors {
+    fn to_markdown(&self) -> String {
+        let mut s = String::from("## Processors\n");
+        for proc in &self.entries {
+            s += &proc.to_markdown();
+        }
+        s
+    }
+}
 impl ToPlainText for Processors {
     fn to_plain(&self) -> String {
         let mut s = format!("Information about processors");
@@ -65,8 +85,69 @@ impl ToPlainText for Processors {
     }
 }
 
+/// Normalized information about a physical processor (package)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CpuPackage {
+    /// Physical ID. `None` if it isn't reported (e.g. on AArch64 or
+    /// in some virtual machines)
+    pub physical_id: Option<u32>,
+    pub vendor_id: Option<String>,
+
+    /// Model name without extra spaces
+    pub model_name: Option<String>,
+
+    /// Number of threads (`/proc/cpuinfo` entries)
+    pub threads: usize,
+
+    /// Sorted list of flags supported by all threads
+    pub flags: Vec<String>,
+}
+
+impl CpuPackage {
+    /// Groups `/proc/cpuinfo` entries by their physical ID
+    pub fn from_entries(entries: &[CPU]) -> Vec<Self> {
+        let mut packages: Vec<Self> = Vec::new();
+        for cpu in entries {
+            let mut flags = cpu.flags.clone().unwrap_or_default();
+            flags.sort();
+            flags.dedup();
+
+            match packages
+                .iter_mut()
+                .find(|p| p.physical_id == cpu.physical_id)
+            {
+                Some(package) => {
+                    package.threads += 1;
+                    // Flags may differ between threads on hybrid CPUs,
+                    // so only the common ones are kept
+                    package.flags.retain(|f| flags.binary_search(f).is_ok());
+                }
+                None => packages.push(Self {
+                    physical_id: cpu.physical_id,
+                    vendor_id: cpu.vendor_id.clone(),
+                    model_name: cpu
+                        .model_name
+                        .as_deref()
+                        .map(|name| name.split_whitespace().collect::<Vec<_>>().join(" ")),
+                    threads: 1,
+                    flags,
+                }),
+            }
+        }
+        packages
+    }
+
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags
+            .binary_search_by(|f| f.as_str().cmp(flag))
+            .is_ok()
+    }
+}
+
 /// A structure with data about each processor core/thread
-#[derive(Debug, Serialize, Default, Clone)]
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CPU {
     /// Entry number (index)
     pub processor: Option<usize>,
@@ -189,6 +270,43 @@ impl ToPlainText for CPU {
     }
 }
 
+#[cfg(not(target_arch = "aarch64"))]
+impl ToMarkdown for CPU {
+    fn to_markdown(&self) -> String {
+        let mut s = match self.processor {
+            Some(proc) => format!("\n### Processor #{proc}\n\n"),
+            None => String::from("\n### Processor #unknown\n\n"),
+        };
+        s += &md_table_header();
+        s += "| Architecture | x86_64 |\n";
+        s += &md_opt_val("Vendor ID", &self.vendor_id);
+        s += &md_opt_val("CPU Family", &self.cpu_family);
+        s += &md_opt_val("CPU Model ID", &self.model);
+        s += &md_opt_val("CPU Model Name", &self.model_name);
+        s += &md_opt_val("Stepping", &self.stepping);
+        s += &md_opt_val("Microcode", &self.microcode);
+        s += &md_opt_val("Current frequency", &self.cpu_mhz);
+        s += &md_opt_val("L3 Cache Size", &self.cache_size);
+        s += &md_opt_val("Physical ID of CPU Core", &self.physical_id);
+        s += &md_opt_val("Siblings", &self.siblings);
+        s += &md_opt_val("Core ID", &self.core_id);
+        s += &md_opt_val("CPU cores", &self.cpu_cores);
+        s += &md_opt_val("APIC ID", &self.apicid);
+        s += &md_opt_val("Initial APIC ID", &self.initial_apicid);
+        s += &md_opt_val("FPU", &self.fpu);
+        s += &md_opt_val("FPU Exception", &self.fpu_exception);
+        s += &md_opt_val("CPUID Level", &self.cpuid_level);
+        s += &md_opt_val("WP", &self.wp);
+        s += &md_opt_val("Bogo MIPS", &self.bogomips);
+        s += &md_opt_val("Clflush Size", &self.clflush_size);
+        s += &md_opt_val("Cache alignment", &self.cache_alignment);
+        s += &md_opt_val("Address sizes", &self.address_sizes);
+        s += &md_opt_val("Power management", &self.power_management);
+
+        s
+    }
+}
+
 #[cfg(target_arch = "aarch64")]
 impl ToPlainText for CPU {
     fn to_plain(&self) -> String {
@@ -206,6 +324,24 @@ impl ToPlainText for CPU {
     }
 }
 
+#[cfg(target_arch = "aarch64")]
+impl ToMarkdown for CPU {
+    fn to_markdown(&self) -> String {
+        let mut s = match self.processor {
+            Some(proc) => format!("\n### Processor #{proc}\n\n"),
+            None => String::from("\n### Processor #unknown\n\n"),
+        };
+        s += &md_table_header();
+        s += &md_opt_val("CPU Implementer", &self.cpu_implementer);
+        s += &md_opt_val("CPU Architecture", &self.cpu_architecture);
+        s += &md_opt_val("CPU Variant", &self.cpu_variant);
+        s += &md_opt_val("CPU Part", &self.cpu_part);
+        s += &md_opt_val("CPU Revision", &self.cpu_revision);
+
+        s
+    }
+}
+
 fn read_info() -> Result<Vec<CPU>> {
     let blocks = read_to_string("/proc/cpuinfo")?;
     let blocks = blocks
@@ -221,11 +357,25 @@ fn read_info() -> Result<Vec<CPU>> {
         for line in block.lines() {
             parse_cpuinfo(&mut cpu, line);
         }
+        if cpu.microcode.is_none() {
+            cpu.microcode = cpu.processor.and_then(read_microcode);
+        }
         processors.push(cpu);
     }
     Ok(processors)
 }
 
+/// Reads the microcode revision from `sysfs` (it isn't present in
+/// `/proc/cpuinfo` on some kernels and architectures)
+fn read_microcode(processor: usize) -> Option<String> {
+    read_to_string(format!(
+        "/sys/devices/system/cpu/cpu{processor}/microcode/version"
+    ))
+    .ok()
+    .map(|ver| ver.trim().to_string())
+    .filter(|ver| !ver.is_empty())
+}
+
 fn get_parts(s: &str) -> impl Iterator<Item = &str> {
     s.splitn(2, ':').map(|item| item.trim())
 }
@@ -293,8 +443,13 @@ fn get_bool(s: &str) -> bool {
     }
 }
 
+/// Interval between two `/proc/stat` samples which is enough to
+/// compute the CPU usage without waiting for the next update
+pub const USAGE_SAMPLE_INTERVAL: Duration = Duration::from_millis(250);
+
 /// Processor usage statistics
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Stat {
     pub cpu: Option<CpuUsage>,
     pub cpus: Vec<CpuUsage>,
@@ -308,12 +463,77 @@ pub struct Stat {
 }
 
 impl Stat {
+    /// Returns empty statistics if `/proc/stat` isn't available
     pub fn new() -> Result<Self> {
-        parse_proc_stat()
+        Ok(read_optional("/proc/stat")
+            .map(|content| parse_proc_stat(&content))
+            .unwrap_or_default())
+    }
+
+    /// Takes two samples [`USAGE_SAMPLE_INTERVAL`] apart and returns
+    /// usage of every core (in percents) between them. Blocks the
+    /// current thread for this interval
+    pub fn sample_usage() -> Result<Vec<f32>> {
+        let prev = Self::new()?;
+        thread::sleep(USAGE_SAMPLE_INTERVAL);
+        Ok(Self::new()?.per_core_usage(&prev))
     }
+
+    /// Returns usage of every core (in percents) since the `prev`
+    /// snapshot. If the number of cores has changed (e.g. a core was
+    /// taken offline), an empty vector is returned
+    pub fn per_core_usage(&self, prev: &Self) -> Vec<f32> {
+        if self.cpus.len() != prev.cpus.len() {
+            return Vec::new();
+        }
+        self.cpus
+            .iter()
+            .zip(&prev.cpus)
+            .map(|(curr, prev)| curr.usage_percentage(Some(*prev)))
+            .collect()
+    }
+
+    /// Returns total usage of all cores (in percents) since the
+    /// `prev` snapshot
+    pub fn total_usage(&self, prev: &Self) -> f32 {
+        match &self.cpu {
+            Some(cpu) => cpu.usage_percentage(prev.cpu),
+            None => 0.0,
+        }
+    }
+
+    /// Computes rates of the system-wide counters since the `prev`
+    /// snapshot taken `elapsed` time ago
+    pub fn rates(&self, prev: &Self, elapsed: Duration) -> StatRates {
+        let secs = elapsed.as_secs_f64();
+        let rate = |curr: Option<u64>, prev: Option<u64>| match (curr, prev) {
+            (Some(curr), Some(prev)) if secs > 0. => curr.saturating_sub(prev) as f64 / secs,
+            _ => 0.,
+        };
+        StatRates {
+            context_switches: rate(self.context_switches, prev.context_switches),
+            interrupts: rate(self.interrupts, prev.interrupts),
+            forks: rate(self.processes_created, prev.processes_created),
+        }
+    }
+}
+
+/// Per-second rates of the `/proc/stat` counters
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct StatRates {
+    /// Context switches per second
+    pub context_switches: f64,
+
+    /// Interrupts per second
+    pub interrupts: f64,
+
+    /// Created processes (forks) per second
+    pub forks: f64,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CpuUsage {
     pub user: Option<u64>,
     pub nice: Option<u64>,
@@ -383,6 +603,7 @@ impl From<&str> for CpuUsage {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SoftIrq {
     pub total: Option<u64>,
     pub hi: Option<u64>,
@@ -420,8 +641,7 @@ impl From<&str> for SoftIrq {
     }
 }
 
-fn parse_proc_stat() -> Result<Stat> {
-    let content = read_to_string("/proc/stat")?;
+fn parse_proc_stat(content: &str) -> Stat {
     let mut stat = Stat::default();
 
     for line in content.lines() {
@@ -484,5 +704,93 @@ fn parse_proc_stat() -> Result<Stat> {
             _ => {}
         }
     }
-    Ok(stat)
+    stat
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(active: u64, idle: u64) -> CpuUsage {
+        CpuUsage {
+            user: Some(active),
+            idle: Some(idle),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn packages_test() {
+        let cpu = |id, flags: &str| CPU {
+            physical_id: Some(id),
+            model_name: Some("Intel(R)  Core(TM) i5 ".to_string()),
+            flags: Some(flags.split_whitespace().map(String::from).collect()),
+            ..Default::default()
+        };
+        let entries = [cpu(0, "sse avx2 fpu"), cpu(0, "avx2 sse"), cpu(1, "sse")];
+        let packages = CpuPackage::from_entries(&entries);
+
+        assert_eq!(packages.len(), 2);
+        assert_eq!(packages[0].threads, 2);
+        assert_eq!(packages[0].flags, vec!["avx2", "sse"]);
+        assert_eq!(
+            packages[0].model_name.as_deref(),
+            Some("Intel(R) Core(TM) i5")
+        );
+        assert!(packages[0].has_flag("avx2"));
+        assert!(!packages[0].has_flag("fpu"));
+        assert!(!packages[1].has_flag("avx2"));
+    }
+
+    #[test]
+    fn per_core_usage_test() {
+        let prev = Stat {
+            cpu: Some(usage(100, 100)),
+            cpus: vec![usage(50, 50), usage(50, 50)],
+            ..Default::default()
+        };
+        let curr = Stat {
+            cpu: Some(usage(175, 125)),
+            cpus: vec![usage(100, 50), usage(75, 75)],
+            ..Default::default()
+        };
+        assert_eq!(curr.per_core_usage(&prev), vec![100., 50.]);
+        assert_eq!(curr.total_usage(&prev), 75.);
+
+        let offline = Stat {
+            cpus: vec![usage(100, 50)],
+            ..Default::default()
+        };
+        assert!(offline.per_core_usage(&prev).is_empty());
+    }
+
+    #[test]
+    fn rates_test() {
+        let prev = Stat {
+            context_switches: Some(1000),
+            interrupts: Some(500),
+            ..Default::default()
+        };
+        let curr = Stat {
+            context_switches: Some(3000),
+            interrupts: Some(1500),
+            processes_created: Some(10),
+            ..Default::default()
+        };
+        let rates = curr.rates(&prev, Duration::from_secs(2));
+        assert_eq!(rates.context_switches, 1000.);
+        assert_eq!(rates.interrupts, 500.);
+        assert_eq!(rates.forks, 0.);
+        assert_eq!(curr.rates(&prev, Duration::ZERO).context_switches, 0.);
+    }
+
+    #[test]
+    fn truncated_proc_stat_test() {
+        // The last line is cut in the middle
+        let stat = parse_proc_stat("cpu  10 0 5 100 0 0 0 0 0 0\nctxt 42\nbtime");
+        assert!(stat.cpu.is_some());
+        assert_eq!(stat.context_switches, Some(42));
+        assert_eq!(stat.boot_time, None);
+        assert_eq!(parse_proc_stat("").cpus.len(), 0);
+    }
 }
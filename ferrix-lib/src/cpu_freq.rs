@@ -24,7 +24,7 @@ use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 use std::{
     ffi::OsString,
-    fs::{read_dir, read_to_string},
+    fs::{read_dir, read_to_string, write},
     path::{Path, PathBuf},
     str::FromStr,
 };
@@ -32,6 +32,7 @@ use std::{
 use crate::traits::ToJson;
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CpuFreq {
     pub policy: Vec<Policy>,
     pub boost: Option<bool>,
@@ -55,21 +56,72 @@ impl CpuFreq {
         };
 
         let mut policy = Vec::new();
+        let mut names = Vec::new();
         for dir in read_dir(CPU_FREQ_DIR)? {
             let dir = dir?;
             let fname = dir.file_name();
             if fname.to_string_lossy().contains("policy") {
-                policy.push(Policy::new(fname)?);
+                names.push(fname);
             }
         }
+        // `read_dir()` doesn't guarantee any order, so `policy10` may
+        // go before `policy2`
+        names.sort_by_key(|name| {
+            name.to_string_lossy()
+                .trim_start_matches("policy")
+                .parse::<usize>()
+                .unwrap_or(usize::MAX)
+        });
+        for name in names {
+            policy.push(Policy::new(name)?);
+        }
 
         Ok(Self { policy, boost })
     }
+
+    /// Checks whether the system is able to manage CPU frequencies
+    /// (it is usually not available in virtual machines)
+    pub fn is_available() -> bool {
+        Path::new(CPU_FREQ_DIR).is_dir()
+    }
+
+    /// Returns the policy which manages the selected CPU core
+    pub fn for_core(&self, core: usize) -> Option<&Policy> {
+        self.policy.iter().find(|p| p.affected_cpus.contains(&core))
+    }
 }
 
 impl ToJson for CpuFreq {}
 
+/// Sets the frequency governor for the selected CPU core
+///
+/// The governor is validated against `scaling_available_governors`
+/// before writing.
+///
+/// > **NOTE:** this function requires root privileges. `ferrix-app`
+/// > calls it via `ferrix-polkit`.
+pub fn set_governor(core: usize, governor: &str) -> Result<()> {
+    let dir = Path::new("/sys/devices/system/cpu/").join(format!("cpu{core}/cpufreq"));
+    if !dir.is_dir() {
+        return Err(anyhow!(
+            "CPU core #{core} doesn't support frequency scaling"
+        ));
+    }
+
+    let available = read_to_string(dir.join("scaling_available_governors"))?;
+    if !available.split_whitespace().any(|gov| gov == governor) {
+        return Err(anyhow!(
+            "Governor '{governor}' is not available for CPU core #{core} (available: {})",
+            available.trim(),
+        ));
+    }
+
+    write(dir.join("scaling_governor"), governor)?;
+    Ok(())
+}
+
 #[derive(Debug, Deserialize, Serialize, Default, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Policy {
     /// Maximum frequency from the BIOS
     pub bios_limit: Option<u32>,
@@ -104,6 +156,9 @@ pub struct Policy {
     pub scaling_max_freq: Option<u32>,
     pub scaling_min_freq: Option<u32>,
     pub scaling_setspeed: Option<String>,
+
+    /// CPU cores managed by this policy
+    pub affected_cpus: Vec<usize>,
 }
 
 impl Policy {
@@ -167,6 +222,33 @@ impl Policy {
             scaling_setspeed: read(&tgt, "scaling_setspeed")
                 .ok()
                 .and_then(|s| Some(s.trim().to_string())),
+            affected_cpus: read(&tgt, "affected_cpus")
+                .map(|d| {
+                    d.split_whitespace()
+                        .filter_map(|cpu| cpu.parse::<usize>().ok())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default(),
         })
     }
+
+    /// Using frequency governor (e.g. `powersave` or `performance`)
+    pub fn governor(&self) -> Option<&str> {
+        self.scaling_governor.as_deref()
+    }
+
+    /// Lower limit of the scaling window, kHz
+    pub fn scaling_min_khz(&self) -> Option<u32> {
+        self.scaling_min_freq
+    }
+
+    /// Upper limit of the scaling window, kHz
+    pub fn scaling_max_khz(&self) -> Option<u32> {
+        self.scaling_max_freq
+    }
+
+    /// List of governors which can be set for this policy
+    pub fn available_governors(&self) -> Vec<String> {
+        self.scaling_available_governors.clone().unwrap_or_default()
+    }
 }
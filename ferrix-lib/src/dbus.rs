@@ -0,0 +1,144 @@
+/* dbus.rs
+ *
+ * Copyright 2026 Michail Krasnov <mskrasnov07@ya.ru>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! D-Bus service which serves Ferrix data to other programs (e.g.
+//! panel applets)
+//!
+//! ## Example
+//! ```no-test
+//! let _conn = ferrix_lib::dbus::serve().await?;
+//! // The service works while the connection is alive
+//! std::future::pending::<()>().await;
+//! ```
+//!
+//! The data can be requested with `busctl`:
+//!
+//! ```bash
+//! busctl --user call com.mskrasnov.Ferrix /com/mskrasnov/Ferrix \
+//!     com.mskrasnov.Ferrix GetSection s ram
+//! ```
+
+use anyhow::{Result, anyhow};
+use futures::{channel::oneshot, lock::Mutex};
+use serde::Serialize;
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+use zbus::{connection, fdo, interface};
+
+use crate::{Ferrix, FormatVersion, cpu, ram, sys};
+
+/// Well-known name of the service on the session bus
+pub const BUS_NAME: &str = "com.mskrasnov.Ferrix";
+
+/// Path of the object implementing [`BUS_NAME`] interface
+pub const OBJECT_PATH: &str = "/com/mskrasnov/Ferrix";
+
+/// Period during which `GetJson` returns the previously collected
+/// data instead of collecting it again
+pub const CACHE_TTL: Duration = Duration::from_secs(2);
+
+/// The `com.mskrasnov.Ferrix` D-Bus interface
+#[derive(Default)]
+pub struct FerrixService {
+    cache: Mutex<Option<(Instant, serde_json::Value)>>,
+}
+
+fn failed(why: impl ToString) -> fdo::Error {
+    fdo::Error::Failed(why.to_string())
+}
+
+fn to_value<T: Serialize>(data: Result<T>) -> fdo::Result<serde_json::Value> {
+    serde_json::to_value(data.map_err(failed)?).map_err(failed)
+}
+
+/// Runs a blocking collector in a separate thread, so the connection
+/// keeps serving other requests while the data is being read
+async fn unblock<T: Send + 'static>(collector: fn() -> Result<T>) -> Result<T> {
+    let (tx, rx) = oneshot::channel();
+    thread::spawn(move || {
+        let _ = tx.send(collector());
+    });
+    rx.await
+        .map_err(|_| anyhow!("Data collector thread panicked"))?
+}
+
+impl FerrixService {
+    /// Returns all data. It's collected again only when the cached
+    /// data is older than [`CACHE_TTL`]
+    async fn collect(&self) -> fdo::Result<serde_json::Value> {
+        let mut cache = self.cache.lock().await;
+        if let Some((time, data)) = &*cache
+            && time.elapsed() < CACHE_TTL
+        {
+            return Ok(data.clone());
+        }
+
+        let data = to_value(Ferrix::new().await)?;
+        *cache = Some((Instant::now(), data.clone()));
+        Ok(data)
+    }
+
+    /// Collects only the requested section
+    async fn collect_section(name: &str) -> fdo::Result<serde_json::Value> {
+        match name {
+            "_ferrix" => to_value(Ok(FormatVersion::default())),
+            "cpu" => to_value(unblock(cpu::Processors::new).await),
+            "ram" => to_value(unblock(ram::RAM::new).await),
+            "swaps" => to_value(unblock(ram::Swaps::new).await),
+            #[cfg(feature = "dmi")]
+            "dmi" => to_value(unblock(crate::dmi::DMITable::new).await),
+            #[cfg(feature = "drm")]
+            "drm" => to_value(unblock(crate::drm::Video::new).await),
+            "sys" => to_value(unblock(sys::Sys::new).await),
+            #[cfg(feature = "systemd")]
+            "init" => {
+                let conn = zbus::Connection::system().await.map_err(failed)?;
+                to_value(crate::init::SystemdServices::new_from_connection(&conn).await)
+            }
+            _ => Err(fdo::Error::InvalidArgs(format!("Unknown section: {name}"))),
+        }
+    }
+}
+
+#[interface(name = "com.mskrasnov.Ferrix")]
+impl FerrixService {
+    /// Returns all data in JSON
+    async fn get_json(&self) -> fdo::Result<String> {
+        Ok(self.collect().await?.to_string())
+    }
+
+    /// Returns a single section (e.g. `cpu`, `ram`, `sys`) in JSON
+    async fn get_section(&self, name: &str) -> fdo::Result<String> {
+        Ok(Self::collect_section(name).await?.to_string())
+    }
+}
+
+/// Registers [`FerrixService`] on the session bus. The service is
+/// available until the returned connection is dropped
+pub async fn serve() -> Result<zbus::Connection> {
+    let conn = connection::Builder::session()?
+        .name(BUS_NAME)?
+        .serve_at(OBJECT_PATH, FerrixService::default())?
+        .build()
+        .await?;
+    Ok(conn)
+}
@@ -0,0 +1,154 @@
+/* devtools.rs
+ *
+ * Copyright 2026 Michail Krasnov <mskrasnov07@ya.ru>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Get information about installed compilers and interpreters
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+    env,
+    io::Read,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::traits::ToJson;
+
+/// Programs which versions are probed by [`Toolchains::new()`]
+pub const TOOLCHAINS: &[&str] = &["rustc", "gcc", "clang", "python3", "node", "go", "java"];
+
+/// Maximum time to wait for `<program> --version`
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Installed development tools
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Toolchains {
+    pub list: Vec<Toolchain>,
+}
+
+impl Toolchains {
+    pub fn new() -> Result<Self> {
+        // Some interpreters (e.g. `java`) start slowly, so all programs
+        // are probed simultaneously
+        let list = thread::scope(|s| {
+            let handles = TOOLCHAINS
+                .iter()
+                .map(|name| s.spawn(|| Toolchain::new(name)))
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .filter_map(|handle| handle.join().ok())
+                .collect()
+        });
+        Ok(Self { list })
+    }
+
+    /// Returns only installed tools
+    pub fn installed(&self) -> Vec<&Toolchain> {
+        self.list.iter().filter(|tc| tc.path.is_some()).collect()
+    }
+}
+
+impl ToJson for Toolchains {}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Toolchain {
+    /// Program name (e.g. `rustc`)
+    pub name: String,
+
+    /// Full path to the program. `None` if it isn't installed
+    pub path: Option<PathBuf>,
+
+    /// First line of `<program> --version` output. `None` if the
+    /// program isn't installed or doesn't respond in time
+    pub version: Option<String>,
+}
+
+impl Toolchain {
+    pub fn new(name: &str) -> Self {
+        let path = which(name);
+        let version = path
+            .as_deref()
+            .and_then(|path| get_version(path, version_arg(name)));
+        Self {
+            name: name.to_string(),
+            path,
+            version,
+        }
+    }
+}
+
+/// Searches the program in the `$PATH` directories
+pub fn which(name: &str) -> Option<PathBuf> {
+    let paths = env::var_os("PATH")?;
+    env::split_paths(&paths)
+        .map(|dir| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+/// `go` doesn't support `--version` and uses a subcommand instead
+fn version_arg(name: &str) -> &'static str {
+    match name {
+        "go" => "version",
+        _ => "--version",
+    }
+}
+
+fn get_version(path: &Path, arg: &str) -> Option<String> {
+    let mut child = Command::new(path)
+        .arg(arg)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .ok()?;
+
+    let start = Instant::now();
+    loop {
+        match child.try_wait().ok()? {
+            Some(_) => break,
+            None if start.elapsed() >= TIMEOUT => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+            None => thread::sleep(Duration::from_millis(10)),
+        }
+    }
+
+    // Some programs (e.g. old `java`) print the version to stderr
+    let mut out = String::new();
+    child.stdout.take()?.read_to_string(&mut out).ok()?;
+    if out.trim().is_empty() {
+        child.stderr.take()?.read_to_string(&mut out).ok()?;
+    }
+    parse_version(&out)
+}
+
+fn parse_version(out: &str) -> Option<String> {
+    out.lines()
+        .map(|line| line.trim())
+        .find(|line| !line.is_empty())
+        .map(|line| line.to_string())
+}
@@ -0,0 +1,254 @@
+/* diff.rs
+ *
+ * Copyright 2026 Michail Krasnov <mskrasnov07@ya.ru>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Compare two snapshots of the system (e.g. before and after the
+//! BIOS update)
+//!
+//! Snapshots are compared as JSON trees, so the files written by
+//! `ferrix-app --export json` can be compared too. Changed fields are
+//! identified by their paths: keys of objects and indexes of arrays
+//! separated by dots (e.g. `cpu.entries.0.model_name`). Elements of
+//! arrays of named objects (services, modules) are identified by
+//! their `name` instead of the index.
+//!
+//! ## Ignore list
+//! Each pattern of the ignore list is a path in which `*` matches any
+//! single segment. A pattern matches the field and all its children.
+//! Patterns starting with `!` un-ignore fields. As in `.gitignore`,
+//! the last matching pattern wins.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::Ferrix;
+
+/// The version marker and fields which change on every run (usage,
+/// uptime, frequencies)
+pub const DEFAULT_IGNORE: &[&str] = &[
+    "_ferrix",
+    "sys.uptime",
+    "sys.loadavg",
+    "sys.boot_time",
+    "sys.env_vars",
+    "cpu.entries.*.cpu_mhz",
+    "ram.*",
+    "!ram.total",
+    "!ram.swap_total",
+    "!ram.vmalloc_total",
+    "!ram.cma_total",
+    "!ram.huge_pages_total",
+    "swaps.swaps.*.used",
+    "init.units.*.active_state",
+    "init.units.*.work_state",
+    "init.units.*.job_id",
+];
+
+/// Changed field
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Change {
+    /// Path to the field (e.g. `ram.total`)
+    pub path: String,
+
+    /// Old value. `None` if the field is added
+    pub before: Option<Value>,
+
+    /// New value. `None` if the field is removed
+    pub after: Option<Value>,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fmt = |val: &Option<Value>| match val {
+            Some(val) => val.to_string(),
+            None => "(none)".to_string(),
+        };
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            fmt(&self.before),
+            fmt(&self.after)
+        )
+    }
+}
+
+/// Compares two snapshots ignoring fields from [`DEFAULT_IGNORE`]
+pub fn diff(a: &Ferrix, b: &Ferrix) -> Result<Vec<Change>> {
+    Ok(diff_values(
+        &serde_json::to_value(a)?,
+        &serde_json::to_value(b)?,
+        DEFAULT_IGNORE,
+    ))
+}
+
+/// Compares two JSON trees. See the [module docs](self) for the
+/// format of `ignore` patterns
+pub fn diff_values<S: AsRef<str>>(a: &Value, b: &Value, ignore: &[S]) -> Vec<Change> {
+    let mut changes = Vec::new();
+    walk(&mut Vec::new(), Some(a), Some(b), ignore, &mut changes);
+    changes
+}
+
+fn walk<S: AsRef<str>>(
+    path: &mut Vec<String>,
+    a: Option<&Value>,
+    b: Option<&Value>,
+    ignore: &[S],
+    changes: &mut Vec<Change>,
+) {
+    if a == b || is_ignored(path, ignore) {
+        return;
+    }
+
+    let children = match (a, b) {
+        (Some(Value::Object(a)), Some(Value::Object(b))) => Some(pair(
+            a.iter().map(|(key, val)| (key.clone(), val)),
+            b.iter().map(|(key, val)| (key.clone(), val)),
+        )),
+        (Some(Value::Array(a)), Some(Value::Array(b))) => Some(array_children(a, b)),
+        _ => None,
+    };
+
+    match children {
+        Some(children) => {
+            for (key, (a, b)) in children {
+                path.push(key);
+                walk(path, a, b, ignore, changes);
+                path.pop();
+            }
+        }
+        None => changes.push(Change {
+            path: path.join("."),
+            before: a.cloned(),
+            after: b.cloned(),
+        }),
+    }
+}
+
+type Children<'a> = Vec<(String, (Option<&'a Value>, Option<&'a Value>))>;
+
+/// Pairs elements of arrays by their names or, if some elements
+/// aren't named, by indexes
+fn array_children<'a>(a: &'a [Value], b: &'a [Value]) -> Children<'a> {
+    let names = |arr: &'a [Value]| {
+        arr.iter()
+            .map(|val| Some((val.get("name")?.as_str()?.to_string(), val)))
+            .collect::<Option<BTreeMap<_, _>>>()
+            .filter(|map| map.len() == arr.len())
+    };
+
+    if let (Some(a), Some(b)) = (names(a), names(b)) {
+        return pair(a.into_iter(), b.into_iter());
+    }
+
+    (0..a.len().max(b.len()))
+        .map(|i| (i.to_string(), (a.get(i), b.get(i))))
+        .collect()
+}
+
+/// Joins two lists of `(key, value)` by keys
+fn pair<'a>(
+    a: impl Iterator<Item = (String, &'a Value)>,
+    b: impl Iterator<Item = (String, &'a Value)>,
+) -> Children<'a> {
+    let mut children = BTreeMap::new();
+    for (key, val) in a {
+        children.entry(key).or_insert((None, None)).0 = Some(val);
+    }
+    for (key, val) in b {
+        children.entry(key).or_insert((None, None)).1 = Some(val);
+    }
+    children.into_iter().collect()
+}
+
+fn is_ignored<S: AsRef<str>>(path: &[String], ignore: &[S]) -> bool {
+    let mut ignored = false;
+    for pattern in ignore {
+        let pattern = pattern.as_ref();
+        let (negated, pattern) = match pattern.strip_prefix('!') {
+            Some(pattern) => (true, pattern),
+            None => (false, pattern),
+        };
+        if matches(path, pattern) {
+            ignored = !negated;
+        }
+    }
+    ignored
+}
+
+/// Returns `true` if the path is the field described by the pattern
+/// or its child
+fn matches(path: &[String], pattern: &str) -> bool {
+    let segments = pattern.split('.').collect::<Vec<_>>();
+    segments.len() <= path.len()
+        && segments
+            .iter()
+            .zip(path)
+            .all(|(seg, key)| *seg == "*" || seg == key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn diff_test() {
+        let a = json!({
+            "ram": { "total": { "KB": 8000000 }, "free": { "KB": 100 } },
+            "sys": { "uptime": [10.0, 20.0], "hostname": "pc" },
+            "units": [{ "name": "a.service", "state": "on" }, { "name": "b.service" }],
+        });
+        let b = json!({
+            "ram": { "total": { "KB": 16000000 }, "free": { "KB": 200 } },
+            "sys": { "uptime": [30.0, 40.0], "hostname": "pc" },
+            "units": [{ "name": "b.service" }, { "name": "a.service", "state": "off" }],
+        });
+
+        let changes = diff_values(&a, &b, &["sys.uptime", "ram.*", "!ram.total"]);
+        assert_eq!(
+            changes,
+            vec![
+                Change {
+                    path: "ram.total.KB".to_string(),
+                    before: Some(json!(8000000)),
+                    after: Some(json!(16000000)),
+                },
+                Change {
+                    path: "units.a.service.state".to_string(),
+                    before: Some(json!("on")),
+                    after: Some(json!("off")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_array_test() {
+        let changes = diff_values(&json!([1, 2]), &json!([1, 3, 4]), &[] as &[&str]);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[1].path, "2");
+        assert_eq!(changes[1].before, None);
+        assert_eq!(changes[1].after, Some(json!(4)));
+    }
+}
@@ -30,7 +30,7 @@
 
 use std::fmt::Display;
 
-use crate::traits::ToJson;
+use crate::traits::{ToJson, ToMarkdown, ToPlainText, md_opt_val, md_table_header, print_opt_val};
 use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 pub use smbioslib::SMBiosData;
@@ -44,7 +44,8 @@ pub use smbioslib::SMBiosData;
 /// let dmi = DMITable::new().unwrap();
 /// dbg!(dmi);
 /// ```
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DMITable {
     /// Information about BIOS (Type 0)
     pub bios: Bios,
@@ -61,17 +62,31 @@ pub struct DMITable {
     /// Information about processor - Type 4
     pub processor: Processor,
 
-    // /// Information about memory controller (Type 5)
-    // pub memory_controller: MemoryController,
-    //
-    // /// Information about memory module (Type 6)
-    // pub memory_module: MemoryModule,
+    /// Information about memory controller (Type 5)
+    ///
+    /// This structure is obsolete starting with SMBIOS 2.1 and is
+    /// absent on most modern machines
+    pub memory_controller: Option<MemoryController>,
+
+    /// Information about memory modules (Type 6)
+    ///
+    /// This structure is obsolete starting with SMBIOS 2.1 and is
+    /// absent on most modern machines
+    pub memory_modules: MemoryModules,
+
     /// Information about CPU cache (Type 7)
     pub caches: Caches,
 
     /// Information about port connectors (Type 8)
     pub ports: PortConnectors,
 
+    /// Information about system slots (Type 9)
+    pub slots: SystemSlots,
+
+    /// OEM strings (Type 11) and system configuration options
+    /// (Type 12)
+    pub extras: DMIExtras,
+
     /// Information about physical memory array (Type 16)
     pub mem_array: MemoryArray,
 
@@ -79,22 +94,76 @@ pub struct DMITable {
     pub mem_devices: MemoryDevices,
 }
 
+/// Errors that may occur while loading the DMI table
+///
+/// Constructors of this module return [`anyhow::Error`], so this
+/// type can be obtained with `err.downcast_ref::<DmiError>()`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum DmiError {
+    /// Not enough permissions to read the DMI table. The program
+    /// needs to be run as root (e.g. with `ferrix-polkit`)
+    PermissionDenied,
+
+    /// This system doesn't provide the DMI table (some virtual
+    /// machines and ARM boards)
+    NotSupported,
+
+    /// Other I/O error
+    Io(String),
+}
+
+impl Display for DmiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PermissionDenied => write!(f, "Permission denied to read the DMI table"),
+            Self::NotSupported => write!(f, "This system doesn't provide the DMI table"),
+            Self::Io(why) => write!(f, "Failed to read the DMI table: {why}"),
+        }
+    }
+}
+
+impl std::error::Error for DmiError {}
+
+impl From<std::io::Error> for DmiError {
+    fn from(value: std::io::Error) -> Self {
+        match value.kind() {
+            std::io::ErrorKind::PermissionDenied => Self::PermissionDenied,
+            std::io::ErrorKind::NotFound | std::io::ErrorKind::Unsupported => Self::NotSupported,
+            _ => Self::Io(value.to_string()),
+        }
+    }
+}
+
+/// Loads the DMI table from the device
+pub fn load_table() -> Result<SMBiosData, DmiError> {
+    Ok(smbioslib::table_load_from_device()?)
+}
+
 impl DMITable {
     /// Get information from DMI table
     ///
     /// > **NOTE:** This data DOES NOT NEED to be updated periodically!
     pub fn new() -> Result<Self> {
-        let table = smbioslib::table_load_from_device()?;
+        let table = load_table()?;
+        Self::new_from_table(&table)
+    }
+
+    pub fn new_from_table(table: &SMBiosData) -> Result<Self> {
         Ok(Self {
-            bios: Bios::new_from_table(&table)?,
-            system: System::new_from_table(&table)?,
-            baseboard: Baseboard::new_from_table(&table)?,
-            chassis: Chassis::new_from_table(&table)?,
-            processor: Processor::new_from_table(&table)?,
-            caches: Caches::new_from_table(&table)?,
-            ports: PortConnectors::new_from_table(&table)?,
-            mem_array: MemoryArray::new_from_table(&table)?,
-            mem_devices: MemoryDevices::new_from_table(&table)?,
+            bios: Bios::new_from_table(table)?,
+            system: System::new_from_table(table)?,
+            baseboard: Baseboard::new_from_table(table)?,
+            chassis: Chassis::new_from_table(table)?,
+            processor: Processor::new_from_table(table)?,
+            memory_controller: MemoryController::new_from_table(table).ok(),
+            memory_modules: MemoryModules::new_from_table(table)?,
+            caches: Caches::new_from_table(table)?,
+            ports: PortConnectors::new_from_table(table)?,
+            slots: SystemSlots::new_from_table(table)?,
+            extras: DMIExtras::new_from_table(table)?,
+            mem_array: MemoryArray::new_from_table(table)?,
+            mem_devices: MemoryDevices::new_from_table(table)?,
         })
     }
 
@@ -125,6 +194,105 @@ impl DMITable {
 
 impl ToJson for DMITable {}
 
+impl ToPlainText for DMITable {
+    fn to_plain(&self) -> String {
+        let mut s = String::from("\nInformation about hardware (DMI)\n");
+
+        let bios = &self.bios;
+        s += "\nBIOS\n";
+        s += &print_opt_val("Vendor", &bios.vendor);
+        s += &print_opt_val("Version", &bios.version);
+        s += &print_opt_val("Release date", &bios.release_date);
+
+        let system = &self.system;
+        s += "\nSystem\n";
+        s += &print_opt_val("Manufacturer", &system.manufacturer);
+        s += &print_opt_val("Product name", &system.product_name);
+        s += &print_opt_val("Version", &system.version);
+        s += &print_opt_val("SKU number", &system.sku_number);
+        s += &print_opt_val("Family", &system.family);
+
+        let board = &self.baseboard;
+        s += "\nBaseboard\n";
+        s += &print_opt_val("Manufacturer", &board.manufacturer);
+        s += &print_opt_val("Product", &board.product);
+        s += &print_opt_val("Location in chassis", &board.location_in_chassis);
+
+        let chassis = &self.chassis;
+        s += "\nChassis\n";
+        s += &print_opt_val("Manufacturer", &chassis.manufacturer);
+        s += &print_opt_val(
+            "Type",
+            &chassis.chassis_type.as_ref().map(|ct| ct.value.to_string()),
+        );
+        s += &print_opt_val("Version", &chassis.version);
+
+        let proc = &self.processor;
+        s += "\nProcessor\n";
+        s += &print_opt_val("Socket", &proc.socked_designation);
+        s += &print_opt_val("Manufacturer", &proc.processor_manufacturer);
+        s += &print_opt_val("Version", &proc.processor_version);
+        s += &print_opt_val("Max speed", &proc.max_speed);
+        s += &print_opt_val("Current speed", &proc.current_speed);
+        s += &print_opt_val("Core count", &proc.core_count);
+        s += &print_opt_val("Thread count", &proc.thread_count);
+
+        s
+    }
+}
+
+impl ToMarkdown for DMITable {
+    fn to_markdown(&self) -> String {
+        let mut s = String::from("## DMI\n");
+
+        let bios = &self.bios;
+        s += "\n### BIOS\n\n";
+        s += &md_table_header();
+        s += &md_opt_val("Vendor", &bios.vendor);
+        s += &md_opt_val("Version", &bios.version);
+        s += &md_opt_val("Release date", &bios.release_date);
+
+        let system = &self.system;
+        s += "\n### System\n\n";
+        s += &md_table_header();
+        s += &md_opt_val("Manufacturer", &system.manufacturer);
+        s += &md_opt_val("Product name", &system.product_name);
+        s += &md_opt_val("Version", &system.version);
+        s += &md_opt_val("SKU number", &system.sku_number);
+        s += &md_opt_val("Family", &system.family);
+
+        let board = &self.baseboard;
+        s += "\n### Baseboard\n\n";
+        s += &md_table_header();
+        s += &md_opt_val("Manufacturer", &board.manufacturer);
+        s += &md_opt_val("Product", &board.product);
+        s += &md_opt_val("Location in chassis", &board.location_in_chassis);
+
+        let chassis = &self.chassis;
+        s += "\n### Chassis\n\n";
+        s += &md_table_header();
+        s += &md_opt_val("Manufacturer", &chassis.manufacturer);
+        s += &md_opt_val(
+            "Type",
+            &chassis.chassis_type.as_ref().map(|ct| ct.value.to_string()),
+        );
+        s += &md_opt_val("Version", &chassis.version);
+
+        let proc = &self.processor;
+        s += "\n### Processor\n\n";
+        s += &md_table_header();
+        s += &md_opt_val("Socket", &proc.socked_designation);
+        s += &md_opt_val("Manufacturer", &proc.processor_manufacturer);
+        s += &md_opt_val("Version", &proc.processor_version);
+        s += &md_opt_val("Max speed", &proc.max_speed);
+        s += &md_opt_val("Current speed", &proc.current_speed);
+        s += &md_opt_val("Core count", &proc.core_count);
+        s += &md_opt_val("Thread count", &proc.thread_count);
+
+        s
+    }
+}
+
 /****************************** NOTE *********************************
  * Дичайший костыль для того, чтобы структура XML была корректной    *
  * Если не обернуть данные в поле "hardware" (тег <hardware> в XML), *
@@ -171,6 +339,7 @@ macro_rules! impl_from_struct {
 /// with it. Some structs will reference other structures by using
 /// this value.
 #[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Handle(pub u16);
 
 impl From<smbioslib::Handle> for Handle {
@@ -196,6 +365,7 @@ impl Display for Handle {
 
 /// BIOS ROM Size
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum RomSize {
     /// Size of this ROM in bytes
     Kilobytes(u16),
@@ -248,6 +418,7 @@ impl Display for RomSize {
 
 /// Information about BIOS/UEFI
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Bios {
     /// BIOS vendor's name
     pub vendor: Option<String>,
@@ -303,7 +474,7 @@ impl Bios {
     /// [`Self::new_from_table(table: &SMBiosData)`] in the constructor
     /// [`DMITable::new()`].
     pub fn new() -> Result<Self> {
-        let table = smbioslib::table_load_from_device()?;
+        let table = load_table()?;
         Self::new_from_table(&table)
     }
 
@@ -351,6 +522,7 @@ impl ToJson for Bios {}
 
 /// BIOS characteristics
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct BiosCharacteristics {
     /// Unknown
     pub unknown: bool,
@@ -485,6 +657,7 @@ impl ToJson for BiosCharacteristics {}
 
 /// Characteristics extension byte 0
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct BiosCharacteristicsExtension0 {
     /// ACPI is supported
     pub acpi_is_supported: bool,
@@ -529,6 +702,7 @@ impl ToJson for BiosCharacteristicsExtension0 {}
 
 /// Characteristics extension byte 0
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct BiosCharacteristicsExtension1 {
     /// BIOS Boot Specification is supported
     pub bios_boot_specification_is_supported: bool,
@@ -584,6 +758,7 @@ impl ToJson for BiosCharacteristicsExtension1 {}
 
 /// System UUID Data
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum SystemUuidData {
     IdNotPresentButSettable,
     IdNotPresent,
@@ -616,6 +791,7 @@ impl Display for SystemUuidData {
 
 /// System UUID
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SystemUuid {
     /// Raw byte array for this UUID
     pub raw: [u8; 16],
@@ -633,6 +809,7 @@ impl Display for SystemUuid {
 
 /// System wakeup data
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SystemWakeUpTypeData {
     /// Raw value
     ///
@@ -653,6 +830,7 @@ impl From<smbioslib::SystemWakeUpTypeData> for SystemWakeUpTypeData {
 
 /// System wakeup type
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum SystemWakeUpType {
     Other,
     Unknown,
@@ -703,6 +881,7 @@ impl Display for SystemWakeUpType {
 
 /// Attributes of the overall system
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct System {
     /// System manufacturer
     pub manufacturer: Option<String>,
@@ -743,7 +922,7 @@ impl System {
     /// `Self::new_from_table(table: &SMBiosData)` in the constructor
     /// [`DMITable::new()`].
     pub fn new() -> Result<Self> {
-        let table = smbioslib::table_load_from_device()?;
+        let table = load_table()?;
         Self::new_from_table(&table)
     }
 
@@ -775,6 +954,7 @@ impl ToJson for System {}
 
 /// Board type data
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct BoardTypeData {
     pub raw: u8,
     pub value: BoardType,
@@ -791,6 +971,7 @@ impl From<smbioslib::BoardTypeData> for BoardTypeData {
 
 /// Board type
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum BoardType {
     Unknown,
     Other,
@@ -856,6 +1037,7 @@ impl Display for BoardType {
 
 /// Information about baseboard/module
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Baseboard {
     /// Baseboard manufacturer
     pub manufacturer: Option<String>,
@@ -891,7 +1073,7 @@ impl Baseboard {
     /// `Self::new_from_table(table: &SMBiosData)` in the constructor
     /// [`DMITable::new()`].
     pub fn new() -> Result<Self> {
-        let table = smbioslib::table_load_from_device()?;
+        let table = load_table()?;
         Self::new_from_table(&table)
     }
 
@@ -929,6 +1111,7 @@ impl ToJson for Baseboard {}
 ///
 /// Collection of flags that identify features of this board
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct BaseboardFeatures {
     /// Set if the board is a hosting board (e.g. motherboard)
     pub hosting_board: bool,
@@ -969,6 +1152,7 @@ impl ToJson for BaseboardFeatures {}
 
 /// Chassis type data
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ChassisTypeData {
     pub raw: u8,
     pub value: ChassisType,
@@ -987,6 +1171,7 @@ impl From<smbioslib::ChassisTypeData> for ChassisTypeData {
 
 /// Chassis type
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ChassisType {
     Other,
     Unknown,
@@ -1121,6 +1306,7 @@ impl Display for ChassisType {
 
 /// Chassis lock presence
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ChassisLockPresence {
     Present,
     NotPresent,
@@ -1150,6 +1336,7 @@ impl Display for ChassisLockPresence {
 
 /// Chassis state data
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ChassisStateData {
     pub raw: u8,
     pub value: ChassisState,
@@ -1166,6 +1353,7 @@ impl From<smbioslib::ChassisStateData> for ChassisStateData {
 
 /// Chassis state
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ChassisState {
     Other,
     Unknown,
@@ -1210,6 +1398,7 @@ impl Display for ChassisState {
 
 /// Chassis security status data
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ChassisSecurityStatusData {
     pub raw: u8,
     pub value: ChassisSecurityStatus,
@@ -1226,6 +1415,7 @@ impl From<smbioslib::ChassisSecurityStatusData> for ChassisSecurityStatusData {
 
 /// Chassis security status
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ChassisSecurityStatus {
     Other,
     Unknown,
@@ -1271,6 +1461,7 @@ impl Display for ChassisSecurityStatus {
 
 /// Chassis height
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ChassisHeight {
     Unspecified,
     U(u8),
@@ -1300,6 +1491,7 @@ impl Display for ChassisHeight {
 
 /// Number of Power Cords
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum PowerCords {
     Unspecified,
     Count(u8),
@@ -1329,6 +1521,7 @@ impl Display for PowerCords {
 
 /// Information about system enclosure or chassis
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Chassis {
     /// Enclosure/chassis manufacturer
     pub manufacturer: Option<String>,
@@ -1382,7 +1575,10 @@ pub struct Chassis {
     /// this field is set to 0
     pub contained_element_record_length: Option<u8>,
 
-    // TODO: this struct doesn't included ContainedElements<'_> iter!
+    /// Elements (boards or other SMBIOS structures) contained in
+    /// this chassis
+    pub contained_elements: Vec<ContainedElement>,
+
     /// Chassis or enclosure SKU number
     pub sku_number: Option<String>,
 }
@@ -1395,7 +1591,7 @@ impl Chassis {
     /// `Self::new_from_table(table: &SMBiosData)` in the constructor
     /// [`DMITable::new()`].
     pub fn new() -> Result<Self> {
-        let table = smbioslib::table_load_from_device()?;
+        let table = load_table()?;
         Self::new_from_table(&table)
     }
 
@@ -1442,6 +1638,13 @@ impl Chassis {
             },
             contained_element_count: t.contained_element_count(),
             contained_element_record_length: t.contained_element_record_length(),
+            contained_elements: match t.contained_elements() {
+                Some(elements) => (&elements)
+                    .into_iter()
+                    .map(ContainedElement::from)
+                    .collect(),
+                None => vec![],
+            },
             sku_number: t.sku_number().ok(),
         })
     }
@@ -1449,8 +1652,77 @@ impl Chassis {
 
 impl ToJson for Chassis {}
 
+/// Type of the element contained in the chassis
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ContainedElementType {
+    /// Board type (bit 7 of the raw value is 0)
+    BaseboardType(BoardTypeData),
+
+    /// SMBIOS structure type (bit 7 of the raw value is 1)
+    SMBiosType(u8),
+}
+
+impl From<smbioslib::ElementType> for ContainedElementType {
+    fn from(value: smbioslib::ElementType) -> Self {
+        match value {
+            smbioslib::ElementType::BaseboardType(bt) => {
+                Self::BaseboardType(BoardTypeData::from(bt))
+            }
+            smbioslib::ElementType::SMBiosType(t) => Self::SMBiosType(t.0),
+        }
+    }
+}
+
+impl Display for ContainedElementType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BaseboardType(bt) => write!(f, "{}", bt.value),
+            Self::SMBiosType(t) => write!(f, "SMBIOS structure (type {t})"),
+        }
+    }
+}
+
+/// Contained element record of the chassis
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ContainedElement {
+    /// Raw value of the element type byte
+    pub raw: u8,
+
+    /// Decoded element type
+    pub element_type: ContainedElementType,
+
+    /// Minimum number of the elements of this type that can be
+    /// installed in the chassis (`None` if the value is reserved)
+    pub minimum: Option<u8>,
+
+    /// Maximum number of the elements of this type that can be
+    /// installed in the chassis (`None` if the value is reserved)
+    pub maximum: Option<u8>,
+}
+
+impl<'a> From<smbioslib::ChassisElement<'a>> for ContainedElement {
+    fn from(value: smbioslib::ChassisElement<'a>) -> Self {
+        Self {
+            raw: value.raw[0],
+            element_type: ContainedElementType::from(value.element_type()),
+            minimum: match value.element_minimum() {
+                smbioslib::ElementMinimum::Count(cnt) => Some(cnt),
+                smbioslib::ElementMinimum::Reserved => None,
+            },
+            maximum: match value.element_maximum() {
+                smbioslib::ElementMaximum::Count(cnt) => Some(cnt),
+                smbioslib::ElementMaximum::Reserved => None,
+            },
+        }
+    }
+}
+impl ToJson for ContainedElement {}
+
 /// Information about processor
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Processor {
     /// Socket reference designation
     pub socked_designation: Option<String>,
@@ -1548,7 +1820,7 @@ impl Processor {
     /// `Self::new_from_table(table: &SMBiosData)` in the constructor
     /// [`DMITable::new()`].
     pub fn new() -> Result<Self> {
-        let table = smbioslib::table_load_from_device()?;
+        let table = load_table()?;
         Self::new_from_table(&table)
     }
 
@@ -1647,6 +1919,7 @@ impl ToJson for Processor {}
 
 /// Defines which functions the processor supports
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ProcessorCharacteristics {
     /// Bit 1 unknown
     pub unknown: bool,
@@ -1693,6 +1966,7 @@ impl From<smbioslib::ProcessorCharacteristics> for ProcessorCharacteristics {
 }
 impl ToJson for ProcessorCharacteristics {}
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ProcessorTypeData {
     pub raw: u8,
     pub value: ProcessorType,
@@ -1708,6 +1982,7 @@ impl From<smbioslib::ProcessorTypeData> for ProcessorTypeData {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ProcessorType {
     Other,
     Unknown,
@@ -1751,6 +2026,7 @@ impl From<smbioslib::ProcessorType> for ProcessorType {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ProcessorFamilyData {
     pub raw: u8,
     pub value: ProcessorFamily,
@@ -1766,6 +2042,7 @@ impl From<smbioslib::ProcessorFamilyData> for ProcessorFamilyData {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ProcessorFamily {
     Other,
     Unknown,
@@ -2384,6 +2661,7 @@ impl From<smbioslib::ProcessorFamily> for ProcessorFamily {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ProcessorFamilyData2 {
     pub raw: u16,
     pub value: ProcessorFamily,
@@ -2399,6 +2677,7 @@ impl From<smbioslib::ProcessorFamilyData2> for ProcessorFamilyData2 {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ProcessorVoltage {
     CurrentVolts(f32),
     SupportedVolts(ProcessorSupportedVoltages),
@@ -2416,6 +2695,7 @@ impl From<smbioslib::ProcessorVoltage> for ProcessorVoltage {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ProcessorSupportedVoltages {
     pub volts_5_0: bool,
     pub volts_3_3: bool,
@@ -2435,6 +2715,7 @@ impl From<smbioslib::ProcessorSupportedVoltages> for ProcessorSupportedVoltages
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ProcessorExternalClock {
     Unknown,
     MHz(u16),
@@ -2459,6 +2740,7 @@ impl From<smbioslib::ProcessorExternalClock> for ProcessorExternalClock {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ProcessorSpeed {
     Unknown,
     MHz(u16),
@@ -2484,6 +2766,7 @@ impl From<smbioslib::ProcessorSpeed> for ProcessorSpeed {
 
 /// Processor Socket and CPU Status
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ProcessorStatus {
     pub raw: u8,
 
@@ -2505,6 +2788,7 @@ impl From<smbioslib::ProcessorStatus> for ProcessorStatus {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum CpuStatus {
     Unknown,
     Enabled,
@@ -2548,6 +2832,7 @@ impl From<smbioslib::CpuStatus> for CpuStatus {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ProcessorUpgradeData {
     pub raw: u8,
     pub value: ProcessorUpgrade,
@@ -2563,6 +2848,7 @@ impl From<smbioslib::ProcessorUpgradeData> for ProcessorUpgradeData {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ProcessorUpgrade {
     Other,
     Unknown,
@@ -2828,6 +3114,7 @@ impl From<smbioslib::ProcessorUpgrade> for ProcessorUpgrade {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum CoreCount {
     Unknown,
     Count(u8),
@@ -2855,6 +3142,7 @@ impl From<smbioslib::CoreCount> for CoreCount {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum CoreCount2 {
     Unknown,
     Count(u16),
@@ -2882,6 +3170,7 @@ impl From<smbioslib::CoreCount2> for CoreCount2 {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum CoresEnabled {
     Unknown,
     Count(u8),
@@ -2909,6 +3198,7 @@ impl From<smbioslib::CoresEnabled> for CoresEnabled {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum CoresEnabled2 {
     Unknown,
     Count(u16),
@@ -2936,6 +3226,7 @@ impl From<smbioslib::CoresEnabled2> for CoresEnabled2 {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ThreadCount {
     Unknown,
     Count(u8),
@@ -2963,6 +3254,7 @@ impl From<smbioslib::ThreadCount> for ThreadCount {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ThreadCount2 {
     Unknown,
     Count(u16),
@@ -2990,6 +3282,7 @@ impl From<smbioslib::ThreadCount2> for ThreadCount2 {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ThreadEnabled {
     Unknown,
     Count(u16),
@@ -3016,211 +3309,295 @@ impl From<smbioslib::ThreadEnabled> for ThreadEnabled {
     }
 }
 
-/// Attributes of each CPU cache device in the system
-#[derive(Debug, Serialize)]
-pub struct Caches {
-    pub caches: Vec<Cache>,
+/// Error detecting method data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ErrorDetectingMethodData {
+    pub raw: u8,
+    pub value: ErrorDetectingMethod,
 }
 
-impl Caches {
-    /// Creates a new instance of `Self`
-    ///
-    /// It is usually not required, since an instance of this
-    /// structure will be created using the method
-    /// `Self::new_from_table(table: &SMBiosData)` in the constructor
-    /// [`DMITable::new()`].
-    pub fn new() -> Result<Self> {
-        let table = smbioslib::table_load_from_device()?;
-        Self::new_from_table(&table)
+impl From<smbioslib::ErrorDetectingMethodData> for ErrorDetectingMethodData {
+    fn from(value: smbioslib::ErrorDetectingMethodData) -> Self {
+        Self {
+            raw: value.raw,
+            value: ErrorDetectingMethod::from(value.value),
+        }
     }
+}
 
-    pub fn new_from_table(table: &SMBiosData) -> Result<Self> {
-        let mut caches = vec![];
+/// Error detecting method
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ErrorDetectingMethod {
+    Other,
+    Unknown,
+    NoErrorDetection,
+    Parity8Bit,
+    Ecc32Bit,
+    Ecc64Bit,
+    Ecc128Bit,
+    Crc,
+    None,
+}
 
-        for cache_device in table.collect::<smbioslib::SMBiosCacheInformation>() {
-            caches.push(Cache::from(cache_device));
+impl From<smbioslib::ErrorDetectingMethod> for ErrorDetectingMethod {
+    fn from(value: smbioslib::ErrorDetectingMethod) -> Self {
+        match value {
+            smbioslib::ErrorDetectingMethod::Other => Self::Other,
+            smbioslib::ErrorDetectingMethod::Unknown => Self::Unknown,
+            smbioslib::ErrorDetectingMethod::NoErrorDetection => Self::NoErrorDetection,
+            smbioslib::ErrorDetectingMethod::Parity8Bit => Self::Parity8Bit,
+            smbioslib::ErrorDetectingMethod::Ecc32Bit => Self::Ecc32Bit,
+            smbioslib::ErrorDetectingMethod::Ecc64Bit => Self::Ecc64Bit,
+            smbioslib::ErrorDetectingMethod::Ecc128Bit => Self::Ecc128Bit,
+            smbioslib::ErrorDetectingMethod::Crc => Self::Crc,
+            smbioslib::ErrorDetectingMethod::None => Self::None,
         }
-
-        Ok(Self { caches })
     }
 }
 
-impl ToJson for Caches {}
+/// Error correcting capabilities
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ErrorCorrectingCapabilities {
+    pub raw: u8,
 
-// struct_migrate!(CacheConfiguration, smbioslib::CacheConfiguaration, {
-//     raw: u16,
-// });
+    /// Other
+    pub other: bool,
 
-#[derive(Debug, Serialize)]
-pub struct CacheConfiguaration {
-    pub raw: u16,
-}
+    /// Unknown
+    pub unknown: bool,
 
-/// This structure defines the attributes of CPU cache device in the
-/// system. One structure is specified for each such device, whether
-/// the device is internal to or external to the CPU module.
-#[derive(Debug, Serialize)]
-pub struct Cache {
-    /// String number for reference designation
-    pub socket_designation: Option<String>,
+    /// None
+    pub no_capabilities: bool,
 
-    /// Bit fields describing the cache configuration
-    pub cache_configuration: Option<CacheConfiguaration>,
+    /// Single-Bit Error Correcting
+    pub single_bit_error_correcting: bool,
 
-    /// Maximum size that can be installed
-    pub maximum_cache_size: Option<smbioslib::CacheMemorySize>,
+    /// Double-Bit Error Correcting
+    pub double_bit_error_correcting: bool,
 
-    /// Same format as Max Cache Size field; set 0 if no cache
-    /// is installed.
-    pub installed_size: Option<smbioslib::CacheMemorySize>,
+    /// Error Scrubbing
+    pub error_scrubbing: bool,
+}
 
-    /// Supported SRAM type
-    pub supported_sram_type: Option<smbioslib::SramTypes>,
+impl From<smbioslib::ErrorCorrectingCapabilities> for ErrorCorrectingCapabilities {
+    fn from(value: smbioslib::ErrorCorrectingCapabilities) -> Self {
+        Self {
+            raw: value.raw,
+            other: value.other(),
+            unknown: value.unknown(),
+            no_capabilities: value.no_capabilities(),
+            single_bit_error_correcting: value.single_bit_error_correcting(),
+            double_bit_error_correcting: value.double_bit_error_correcting(),
+            error_scrubbing: value.error_scrubbing(),
+        }
+    }
+}
 
-    /// Current SRAM type
-    pub current_sram_type: Option<smbioslib::SramTypes>,
+/// Interleave support data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct InterleaveSupportData {
+    pub raw: u8,
+    pub value: InterleaveSupport,
+}
 
-    /// Cache module speed, in nanosecs. The value is 0 if the
-    /// speed is unknown
-    pub cache_speed: Option<u8>,
+impl From<smbioslib::InterleaveSupportData> for InterleaveSupportData {
+    fn from(value: smbioslib::InterleaveSupportData) -> Self {
+        Self {
+            raw: value.raw,
+            value: InterleaveSupport::from(value.value),
+        }
+    }
+}
 
-    /// Error-correction scheme supported by this cache component
-    pub error_correction_type: Option<smbioslib::ErrorCorrectionTypeData>,
+/// Interleave support
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum InterleaveSupport {
+    Other,
+    Unknown,
+    OneWay,
+    TwoWay,
+    FourWay,
+    EightWay,
+    SixteenWay,
+    None,
+}
 
-    /// Logical type of cache
-    pub system_cache_type: Option<smbioslib::SystemCacheTypeData>,
+impl From<smbioslib::InterleaveSupport> for InterleaveSupport {
+    fn from(value: smbioslib::InterleaveSupport) -> Self {
+        match value {
+            smbioslib::InterleaveSupport::Other => Self::Other,
+            smbioslib::InterleaveSupport::Unknown => Self::Unknown,
+            smbioslib::InterleaveSupport::OneWay => Self::OneWay,
+            smbioslib::InterleaveSupport::TwoWay => Self::TwoWay,
+            smbioslib::InterleaveSupport::FourWay => Self::FourWay,
+            smbioslib::InterleaveSupport::EightWay => Self::EightWay,
+            smbioslib::InterleaveSupport::SixteenWay => Self::SixteenWay,
+            smbioslib::InterleaveSupport::None => Self::None,
+        }
+    }
+}
 
-    /// Associativity of the cache
-    pub associativity: Option<smbioslib::CacheAssociativityData>,
+/// Memory speeds supported by the memory controller
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemorySpeeds {
+    pub raw: u16,
 
-    /// Maximum cache size
-    pub maximum_cache_size_2: Option<smbioslib::CacheMemorySize>,
+    /// Other
+    pub other: bool,
 
-    /// Installed cache size
-    pub installed_cache_size_2: Option<smbioslib::CacheMemorySize>,
+    /// Unknown
+    pub unknown: bool,
+
+    /// 70ns
+    pub ns70: bool,
+
+    /// 60ns
+    pub ns60: bool,
+
+    /// 50ns
+    pub ns50: bool,
 }
 
-impl<'a> From<smbioslib::SMBiosCacheInformation<'a>> for Cache {
-    fn from(value: smbioslib::SMBiosCacheInformation) -> Self {
+impl From<smbioslib::MemorySpeeds> for MemorySpeeds {
+    fn from(value: smbioslib::MemorySpeeds) -> Self {
         Self {
-            socket_designation: value.socket_designation().ok(),
-            cache_configuration: match value.cache_configuration() {
-                Some(conf) => Some(CacheConfiguaration { raw: conf.raw }),
-                None => None,
-            },
-            maximum_cache_size: value.maximum_cache_size(),
-            installed_size: value.installed_size(),
-            supported_sram_type: value.supported_sram_type(),
-            current_sram_type: value.current_sram_type(),
-            cache_speed: value.cache_speed(),
-            error_correction_type: value.error_correction_type(),
-            system_cache_type: value.system_cache_type(),
-            associativity: value.associativity(),
-            maximum_cache_size_2: value.maximum_cache_size_2(),
-            installed_cache_size_2: value.installed_cache_size_2(),
+            raw: value.raw,
+            other: value.other(),
+            unknown: value.unknown(),
+            ns70: value.ns70(),
+            ns60: value.ns60(),
+            ns50: value.ns50(),
         }
     }
 }
-impl ToJson for Cache {}
 
-/// Attributes of a system port connectors
-#[derive(Debug, Serialize)]
-pub struct PortConnectors {
-    pub ports: Vec<Port>,
-}
+/// Memory types supported by the memory controller or the current
+/// type of the memory module
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemoryTypes {
+    pub raw: u16,
 
-impl PortConnectors {
-    /// Creates a new instance of `Self`
-    ///
-    /// It is usually not required, since an instance of this
-    /// structure will be created using the method
-    /// `Self::new_from_table(table: &SMBiosData)` in the constructor
-    /// [`DMITable::new()`].
-    pub fn new() -> Result<Self> {
-        let table = smbioslib::table_load_from_device()?;
-        Self::new_from_table(&table)
-    }
+    /// Other
+    pub other: bool,
 
-    pub fn new_from_table(table: &SMBiosData) -> Result<Self> {
-        let mut ports = vec![];
+    /// Unknown
+    pub unknown: bool,
 
-        for port in table.collect::<smbioslib::SMBiosPortConnectorInformation>() {
-            ports.push(Port::from(port));
-        }
+    /// Standard
+    pub standard: bool,
 
-        Ok(Self { ports })
-    }
-}
+    /// Fast Page Mode
+    pub fast_page_mode: bool,
 
-impl ToJson for PortConnectors {}
+    /// EDO
+    pub edo: bool,
 
-/// Attributes of a system port connector (serial, parallel,
-/// keyboard or mouse ports)
-#[derive(Debug, Serialize)]
-pub struct Port {
-    /// Internal reference designator, that is, internal to the
-    /// system enclosure
-    pub internal_reference_designator: Option<String>,
+    /// Parity
+    pub parity: bool,
 
-    /// Internal connector type
-    pub internal_connector_type: Option<smbioslib::PortInformationConnectorTypeData>,
+    /// ECC
+    pub ecc: bool,
 
-    /// External reference designation, external to the system
-    /// enclosure
-    pub external_reference_designator: Option<String>,
+    /// SIMM
+    pub simm: bool,
 
-    /// External connector type
-    pub external_connector_type: Option<smbioslib::PortInformationConnectorTypeData>,
+    /// DIMM
+    pub dimm: bool,
 
-    /// Function of the port
-    pub port_type: Option<smbioslib::PortInformationPortTypeData>,
+    /// Burst EDO
+    pub burst_edo: bool,
+
+    /// SDRAM
+    pub sdram: bool,
 }
 
-impl<'a> From<smbioslib::SMBiosPortConnectorInformation<'a>> for Port {
-    fn from(value: smbioslib::SMBiosPortConnectorInformation) -> Self {
+impl From<smbioslib::MemoryTypes> for MemoryTypes {
+    fn from(value: smbioslib::MemoryTypes) -> Self {
         Self {
-            internal_reference_designator: value.internal_reference_designator().ok(),
-            internal_connector_type: value.internal_connector_type(),
-            external_reference_designator: value.external_reference_designator().ok(),
-            external_connector_type: value.external_connector_type(),
-            port_type: value.port_type(),
+            raw: value.raw,
+            other: value.other(),
+            unknown: value.unknown(),
+            standard: value.standard(),
+            fast_page_mode: value.fast_page_mode(),
+            edo: value.edo(),
+            parity: value.parity(),
+            ecc: value.ecc(),
+            simm: value.simm(),
+            dimm: value.dimm(),
+            burst_edo: value.burst_edo(),
+            sdram: value.sdram(),
         }
     }
 }
-impl ToJson for Port {}
 
-/// Collection of memory devices that operate together to form a memory address space
-#[derive(Debug, Serialize)]
-pub struct MemoryArray {
-    /// Physical location of the Memory Array, whether on the system
-    /// board or an add-in board
-    pub location: Option<smbioslib::MemoryArrayLocationData>,
+/// Voltages required by memory modules
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModuleVoltage {
+    pub raw: u8,
 
-    /// Which the array is used
-    pub usage: Option<smbioslib::MemoryArrayUseData>,
+    /// 5V
+    pub volts_5: bool,
 
-    /// Primary hardware error correction or detection method
-    /// supported by this memory array
-    pub memory_error_correction: Option<smbioslib::MemoryArrayErrorCorrectionData>,
+    /// 3.3V
+    pub volts_3_3: bool,
 
-    /// Maximum memory capacity, in kbytes, for this array
-    pub maximum_capacity: Option<smbioslib::MaximumMemoryCapacity>,
+    /// 2.9V
+    pub volts_2_9: bool,
+}
 
-    /// Handle, or instance number, associated with any error that
-    /// was previously detected for the array
-    pub memory_error_information_handle: Option<smbioslib::Handle>,
+impl From<smbioslib::ModuleVoltage> for ModuleVoltage {
+    fn from(value: smbioslib::ModuleVoltage) -> Self {
+        Self {
+            raw: value.raw,
+            volts_5: value.volts_5(),
+            volts_3_3: value.volts_3_3(),
+            volts_2_9: value.volts_2_9(),
+        }
+    }
+}
 
-    /// Number of slots/sockets available for memory devices in
-    /// this array
-    pub number_of_memory_devices: Option<u16>,
+/// Information about memory controller (Type 5, obsolete)
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemoryController {
+    /// Error detecting method
+    pub error_detecting_method: Option<ErrorDetectingMethodData>,
 
-    /// Maximum memory capacity, in bytes, for this array. This
-    /// field is only valid when the Maximum Capacity field
-    /// contains 8000 0000h.
-    pub extended_maximum_capacity: Option<u64>,
+    /// Error correcting capabilities
+    pub error_correcting_capability: Option<ErrorCorrectingCapabilities>,
+
+    /// Supported interleave
+    pub supported_interleave: Option<InterleaveSupportData>,
+
+    /// Current interleave
+    pub current_interleave: Option<InterleaveSupportData>,
+
+    /// Size of the largest memory module supported (per slot),
+    /// specified as n, where 2**n is the maximum size in MB
+    pub maximum_memory_module_size: Option<u8>,
+
+    /// Supported speeds
+    pub supported_speeds: Option<MemorySpeeds>,
+
+    /// Supported memory types
+    pub supported_memory_types: Option<MemoryTypes>,
+
+    /// Required voltages
+    pub memory_module_voltage: Option<ModuleVoltage>,
+
+    /// Number of associated memory slots
+    pub number_of_associated_memory_slots: Option<u8>,
 }
 
-impl MemoryArray {
+impl MemoryController {
     /// Creates a new instance of `Self`
     ///
     /// It is usually not required, since an instance of this
@@ -3228,23 +3605,1552 @@ impl MemoryArray {
     /// `Self::new_from_table(table: &SMBiosData)` in the constructor
     /// [`DMITable::new()`].
     pub fn new() -> Result<Self> {
-        let table = smbioslib::table_load_from_device()?;
+        let table = load_table()?;
         Self::new_from_table(&table)
     }
 
     pub fn new_from_table(table: &SMBiosData) -> Result<Self> {
         let t = table
-            .find_map(|f: smbioslib::SMBiosPhysicalMemoryArray| Some(f))
+            .find_map(|f: smbioslib::SMBiosMemoryControllerInformation| Some(f))
+            .ok_or(anyhow!(
+                "Failed to get information about memory controller (type 5)!"
+            ))?;
+
+        Ok(Self::from(t))
+    }
+}
+
+impl<'a> From<smbioslib::SMBiosMemoryControllerInformation<'a>> for MemoryController {
+    fn from(value: smbioslib::SMBiosMemoryControllerInformation) -> Self {
+        Self {
+            error_detecting_method: value
+                .error_detecting_method()
+                .map(ErrorDetectingMethodData::from),
+            error_correcting_capability: value
+                .error_correcting_capability()
+                .map(ErrorCorrectingCapabilities::from),
+            supported_interleave: value
+                .supported_interleave()
+                .map(InterleaveSupportData::from),
+            current_interleave: value.current_interleave().map(InterleaveSupportData::from),
+            maximum_memory_module_size: value.maximum_memory_module_size(),
+            supported_speeds: value.supported_speeds().map(MemorySpeeds::from),
+            supported_memory_types: value.supported_memory_types().map(MemoryTypes::from),
+            memory_module_voltage: value.memory_module_voltage().map(ModuleVoltage::from),
+            number_of_associated_memory_slots: value.number_of_associated_memory_slots(),
+        }
+    }
+}
+impl ToJson for MemoryController {}
+
+/// Information about all memory modules (Type 6, obsolete)
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemoryModules {
+    pub modules: Vec<MemoryModule>,
+}
+
+impl MemoryModules {
+    /// Creates a new instance of `Self`
+    ///
+    /// It is usually not required, since an instance of this
+    /// structure will be created using the method
+    /// `Self::new_from_table(table: &SMBiosData)` in the constructor
+    /// [`DMITable::new()`].
+    pub fn new() -> Result<Self> {
+        let table = load_table()?;
+        Self::new_from_table(&table)
+    }
+
+    pub fn new_from_table(table: &SMBiosData) -> Result<Self> {
+        let mut modules = vec![];
+
+        for module in table.collect::<smbioslib::SMBiosMemoryModuleInformation>() {
+            modules.push(MemoryModule::from(module));
+        }
+
+        Ok(Self { modules })
+    }
+}
+
+impl ToJson for MemoryModules {}
+
+/// Information about single memory module (Type 6, obsolete)
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemoryModule {
+    /// Socket reference designation
+    pub socket_designation: Option<String>,
+
+    /// Each nibble indicates a bank (RAS#) connection; 0xF means
+    /// no connection
+    pub bank_connections: Option<u8>,
+
+    /// Speed of the memory module, in ns
+    pub current_speed: Option<u8>,
+
+    /// Physical characteristics of the memory modules that are
+    /// supported by (and currently installed in) the system
+    pub current_memory_type: Option<MemoryTypes>,
+
+    /// Installed size
+    pub installed_size: Option<u8>,
+
+    /// Enabled size
+    pub enabled_size: Option<u8>,
+
+    /// Error status
+    pub error_status: Option<u8>,
+}
+
+impl<'a> From<smbioslib::SMBiosMemoryModuleInformation<'a>> for MemoryModule {
+    fn from(value: smbioslib::SMBiosMemoryModuleInformation) -> Self {
+        Self {
+            socket_designation: value.socket_designation().ok(),
+            bank_connections: value.bank_connections(),
+            current_speed: value.current_speed(),
+            current_memory_type: value.current_memory_type().map(MemoryTypes::from),
+            installed_size: value.installed_size(),
+            enabled_size: value.enabled_size(),
+            error_status: value.error_status(),
+        }
+    }
+}
+impl ToJson for MemoryModule {}
+
+/// Attributes of each CPU cache device in the system
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Caches {
+    pub caches: Vec<Cache>,
+}
+
+impl Caches {
+    /// Creates a new instance of `Self`
+    ///
+    /// It is usually not required, since an instance of this
+    /// structure will be created using the method
+    /// `Self::new_from_table(table: &SMBiosData)` in the constructor
+    /// [`DMITable::new()`].
+    pub fn new() -> Result<Self> {
+        let table = load_table()?;
+        Self::new_from_table(&table)
+    }
+
+    pub fn new_from_table(table: &SMBiosData) -> Result<Self> {
+        let mut caches = vec![];
+
+        for cache_device in table.collect::<smbioslib::SMBiosCacheInformation>() {
+            caches.push(Cache::from(cache_device));
+        }
+
+        Ok(Self { caches })
+    }
+}
+
+impl ToJson for Caches {}
+
+// struct_migrate!(CacheConfiguration, smbioslib::CacheConfiguaration, {
+//     raw: u16,
+// });
+
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CacheConfiguaration {
+    pub raw: u16,
+}
+
+/// Cache memory size
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CacheMemorySize {
+    Kilobytes(u64),
+    SeeCacheSize2,
+}
+
+impl From<smbioslib::CacheMemorySize> for CacheMemorySize {
+    fn from(value: smbioslib::CacheMemorySize) -> Self {
+        match value {
+            smbioslib::CacheMemorySize::Kilobytes(v) => Self::Kilobytes(v),
+            smbioslib::CacheMemorySize::SeeCacheSize2 => Self::SeeCacheSize2,
+        }
+    }
+}
+
+/// SRAM types
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SramTypes {
+    pub raw: u16,
+
+    /// Other
+    pub other: bool,
+
+    /// Unknown
+    pub unknown: bool,
+
+    /// Non-Burst
+    pub non_burst: bool,
+
+    /// Burst
+    pub burst: bool,
+
+    /// Pipeline Burst
+    pub pipeline_burst: bool,
+
+    /// Synchronous
+    pub synchronous: bool,
+
+    /// Asynchronous
+    pub asynchronous: bool,
+}
+
+impl From<smbioslib::SramTypes> for SramTypes {
+    fn from(value: smbioslib::SramTypes) -> Self {
+        Self {
+            raw: value.raw,
+            other: value.other(),
+            unknown: value.unknown(),
+            non_burst: value.non_burst(),
+            burst: value.burst(),
+            pipeline_burst: value.pipeline_burst(),
+            synchronous: value.synchronous(),
+            asynchronous: value.asynchronous(),
+        }
+    }
+}
+
+/// Error-correction type data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ErrorCorrectionTypeData {
+    pub raw: u8,
+    pub value: ErrorCorrectionType,
+}
+
+impl From<smbioslib::ErrorCorrectionTypeData> for ErrorCorrectionTypeData {
+    fn from(value: smbioslib::ErrorCorrectionTypeData) -> Self {
+        Self {
+            raw: value.raw,
+            value: ErrorCorrectionType::from(value.value),
+        }
+    }
+}
+
+/// Error-correction type
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ErrorCorrectionType {
+    Other,
+    Unknown,
+    NoCorrection,
+    Parity,
+    SingleBitEcc,
+    MultiBitEcc,
+    None,
+}
+
+impl From<smbioslib::ErrorCorrectionType> for ErrorCorrectionType {
+    fn from(value: smbioslib::ErrorCorrectionType) -> Self {
+        match value {
+            smbioslib::ErrorCorrectionType::Other => Self::Other,
+            smbioslib::ErrorCorrectionType::Unknown => Self::Unknown,
+            smbioslib::ErrorCorrectionType::NoCorrection => Self::NoCorrection,
+            smbioslib::ErrorCorrectionType::Parity => Self::Parity,
+            smbioslib::ErrorCorrectionType::SingleBitEcc => Self::SingleBitEcc,
+            smbioslib::ErrorCorrectionType::MultiBitEcc => Self::MultiBitEcc,
+            smbioslib::ErrorCorrectionType::None => Self::None,
+        }
+    }
+}
+
+/// System cache type data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SystemCacheTypeData {
+    pub raw: u8,
+    pub value: SystemCacheType,
+}
+
+impl From<smbioslib::SystemCacheTypeData> for SystemCacheTypeData {
+    fn from(value: smbioslib::SystemCacheTypeData) -> Self {
+        Self {
+            raw: value.raw,
+            value: SystemCacheType::from(value.value),
+        }
+    }
+}
+
+/// System cache type
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SystemCacheType {
+    Other,
+    Unknown,
+    Instruction,
+    Data,
+    Unified,
+    None,
+}
+
+impl From<smbioslib::SystemCacheType> for SystemCacheType {
+    fn from(value: smbioslib::SystemCacheType) -> Self {
+        match value {
+            smbioslib::SystemCacheType::Other => Self::Other,
+            smbioslib::SystemCacheType::Unknown => Self::Unknown,
+            smbioslib::SystemCacheType::Instruction => Self::Instruction,
+            smbioslib::SystemCacheType::Data => Self::Data,
+            smbioslib::SystemCacheType::Unified => Self::Unified,
+            smbioslib::SystemCacheType::None => Self::None,
+        }
+    }
+}
+
+/// Cache associativity data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CacheAssociativityData {
+    pub raw: u8,
+    pub value: CacheAssociativity,
+}
+
+impl From<smbioslib::CacheAssociativityData> for CacheAssociativityData {
+    fn from(value: smbioslib::CacheAssociativityData) -> Self {
+        Self {
+            raw: value.raw,
+            value: CacheAssociativity::from(value.value),
+        }
+    }
+}
+
+/// Cache associativity
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CacheAssociativity {
+    Other,
+    Unknown,
+    DirectMapped,
+    SetAssociative2Way,
+    SetAssociative4Way,
+    FullyAssociative,
+    SetAssociative8Way,
+    SetAssociative16Way,
+    SetAssociative12Way,
+    SetAssociative24Way,
+    SetAssociative32Way,
+    SetAssociative48Way,
+    SetAssociative64Way,
+    SetAssociative20Way,
+    None,
+}
+
+impl From<smbioslib::CacheAssociativity> for CacheAssociativity {
+    fn from(value: smbioslib::CacheAssociativity) -> Self {
+        match value {
+            smbioslib::CacheAssociativity::Other => Self::Other,
+            smbioslib::CacheAssociativity::Unknown => Self::Unknown,
+            smbioslib::CacheAssociativity::DirectMapped => Self::DirectMapped,
+            smbioslib::CacheAssociativity::SetAssociative2Way => Self::SetAssociative2Way,
+            smbioslib::CacheAssociativity::SetAssociative4Way => Self::SetAssociative4Way,
+            smbioslib::CacheAssociativity::FullyAssociative => Self::FullyAssociative,
+            smbioslib::CacheAssociativity::SetAssociative8Way => Self::SetAssociative8Way,
+            smbioslib::CacheAssociativity::SetAssociative16Way => Self::SetAssociative16Way,
+            smbioslib::CacheAssociativity::SetAssociative12Way => Self::SetAssociative12Way,
+            smbioslib::CacheAssociativity::SetAssociative24Way => Self::SetAssociative24Way,
+            smbioslib::CacheAssociativity::SetAssociative32Way => Self::SetAssociative32Way,
+            smbioslib::CacheAssociativity::SetAssociative48Way => Self::SetAssociative48Way,
+            smbioslib::CacheAssociativity::SetAssociative64Way => Self::SetAssociative64Way,
+            smbioslib::CacheAssociativity::SetAssociative20Way => Self::SetAssociative20Way,
+            smbioslib::CacheAssociativity::None => Self::None,
+        }
+    }
+}
+
+/// This structure defines the attributes of CPU cache device in the
+/// system. One structure is specified for each such device, whether
+/// the device is internal to or external to the CPU module.
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Cache {
+    /// String number for reference designation
+    pub socket_designation: Option<String>,
+
+    /// Bit fields describing the cache configuration
+    pub cache_configuration: Option<CacheConfiguaration>,
+
+    /// Maximum size that can be installed
+    pub maximum_cache_size: Option<CacheMemorySize>,
+
+    /// Same format as Max Cache Size field; set 0 if no cache
+    /// is installed.
+    pub installed_size: Option<CacheMemorySize>,
+
+    /// Supported SRAM type
+    pub supported_sram_type: Option<SramTypes>,
+
+    /// Current SRAM type
+    pub current_sram_type: Option<SramTypes>,
+
+    /// Cache module speed, in nanosecs. The value is 0 if the
+    /// speed is unknown
+    pub cache_speed: Option<u8>,
+
+    /// Error-correction scheme supported by this cache component
+    pub error_correction_type: Option<ErrorCorrectionTypeData>,
+
+    /// Logical type of cache
+    pub system_cache_type: Option<SystemCacheTypeData>,
+
+    /// Associativity of the cache
+    pub associativity: Option<CacheAssociativityData>,
+
+    /// Maximum cache size
+    pub maximum_cache_size_2: Option<CacheMemorySize>,
+
+    /// Installed cache size
+    pub installed_cache_size_2: Option<CacheMemorySize>,
+}
+
+impl<'a> From<smbioslib::SMBiosCacheInformation<'a>> for Cache {
+    fn from(value: smbioslib::SMBiosCacheInformation) -> Self {
+        Self {
+            socket_designation: value.socket_designation().ok(),
+            cache_configuration: match value.cache_configuration() {
+                Some(conf) => Some(CacheConfiguaration { raw: conf.raw }),
+                None => None,
+            },
+            maximum_cache_size: value.maximum_cache_size().map(CacheMemorySize::from),
+            installed_size: value.installed_size().map(CacheMemorySize::from),
+            supported_sram_type: value.supported_sram_type().map(SramTypes::from),
+            current_sram_type: value.current_sram_type().map(SramTypes::from),
+            cache_speed: value.cache_speed(),
+            error_correction_type: value
+                .error_correction_type()
+                .map(ErrorCorrectionTypeData::from),
+            system_cache_type: value.system_cache_type().map(SystemCacheTypeData::from),
+            associativity: value.associativity().map(CacheAssociativityData::from),
+            maximum_cache_size_2: value.maximum_cache_size_2().map(CacheMemorySize::from),
+            installed_cache_size_2: value.installed_cache_size_2().map(CacheMemorySize::from),
+        }
+    }
+}
+impl ToJson for Cache {}
+
+/// Attributes of a system port connectors
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PortConnectors {
+    pub ports: Vec<Port>,
+}
+
+impl PortConnectors {
+    /// Creates a new instance of `Self`
+    ///
+    /// It is usually not required, since an instance of this
+    /// structure will be created using the method
+    /// `Self::new_from_table(table: &SMBiosData)` in the constructor
+    /// [`DMITable::new()`].
+    pub fn new() -> Result<Self> {
+        let table = load_table()?;
+        Self::new_from_table(&table)
+    }
+
+    pub fn new_from_table(table: &SMBiosData) -> Result<Self> {
+        let mut ports = vec![];
+
+        for port in table.collect::<smbioslib::SMBiosPortConnectorInformation>() {
+            ports.push(Port::from(port));
+        }
+
+        Ok(Self { ports })
+    }
+}
+
+impl ToJson for PortConnectors {}
+
+/// Port connector type data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PortInformationConnectorTypeData {
+    pub raw: u8,
+    pub value: PortInformationConnectorType,
+}
+
+impl From<smbioslib::PortInformationConnectorTypeData> for PortInformationConnectorTypeData {
+    fn from(value: smbioslib::PortInformationConnectorTypeData) -> Self {
+        Self {
+            raw: value.raw,
+            value: PortInformationConnectorType::from(value.value),
+        }
+    }
+}
+
+/// Port connector type
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum PortInformationConnectorType {
+    NoConnector,
+    Centronics,
+    MiniCentronics,
+    Proprietary,
+    DB25PinMale,
+    DB25PinFemale,
+    DB15PinMale,
+    DB15PinFemale,
+    DB9PinMale,
+    DB8PinFemale,
+    RJ11,
+    RJ45,
+    MiniScsi50Pin,
+    MiniDin,
+    MicroDin,
+    Ps2,
+    Infrared,
+    HpHil,
+    AccessBusUsb,
+    SsaScsi,
+    CircularDin8Male,
+    CircularDin8Female,
+    OnBoardIde,
+    OnBoardFloppy,
+    DualInline9Pin,
+    DualInline25Pin,
+    DualInline50Pin,
+    DualInline68Pin,
+    OnBoardSoundInputCDRom,
+    MiniCentronicsType14,
+    MiniCentronicsTyp26,
+    MiniJackHeadphones,
+    Bnc,
+    Port1394,
+    SasSataPlugReceptacle,
+    UsbTypeCReceptacle,
+    PC98,
+    PC98Hireso,
+    PCH88,
+    PC98Note,
+    PC98Full,
+    Other,
+    None,
+}
+
+impl From<smbioslib::PortInformationConnectorType> for PortInformationConnectorType {
+    fn from(value: smbioslib::PortInformationConnectorType) -> Self {
+        match value {
+            smbioslib::PortInformationConnectorType::NoConnector => Self::NoConnector,
+            smbioslib::PortInformationConnectorType::Centronics => Self::Centronics,
+            smbioslib::PortInformationConnectorType::MiniCentronics => Self::MiniCentronics,
+            smbioslib::PortInformationConnectorType::Proprietary => Self::Proprietary,
+            smbioslib::PortInformationConnectorType::DB25PinMale => Self::DB25PinMale,
+            smbioslib::PortInformationConnectorType::DB25PinFemale => Self::DB25PinFemale,
+            smbioslib::PortInformationConnectorType::DB15PinMale => Self::DB15PinMale,
+            smbioslib::PortInformationConnectorType::DB15PinFemale => Self::DB15PinFemale,
+            smbioslib::PortInformationConnectorType::DB9PinMale => Self::DB9PinMale,
+            smbioslib::PortInformationConnectorType::DB8PinFemale => Self::DB8PinFemale,
+            smbioslib::PortInformationConnectorType::RJ11 => Self::RJ11,
+            smbioslib::PortInformationConnectorType::RJ45 => Self::RJ45,
+            smbioslib::PortInformationConnectorType::MiniScsi50Pin => Self::MiniScsi50Pin,
+            smbioslib::PortInformationConnectorType::MiniDin => Self::MiniDin,
+            smbioslib::PortInformationConnectorType::MicroDin => Self::MicroDin,
+            smbioslib::PortInformationConnectorType::Ps2 => Self::Ps2,
+            smbioslib::PortInformationConnectorType::Infrared => Self::Infrared,
+            smbioslib::PortInformationConnectorType::HpHil => Self::HpHil,
+            smbioslib::PortInformationConnectorType::AccessBusUsb => Self::AccessBusUsb,
+            smbioslib::PortInformationConnectorType::SsaScsi => Self::SsaScsi,
+            smbioslib::PortInformationConnectorType::CircularDin8Male => Self::CircularDin8Male,
+            smbioslib::PortInformationConnectorType::CircularDin8Female => Self::CircularDin8Female,
+            smbioslib::PortInformationConnectorType::OnBoardIde => Self::OnBoardIde,
+            smbioslib::PortInformationConnectorType::OnBoardFloppy => Self::OnBoardFloppy,
+            smbioslib::PortInformationConnectorType::DualInline9Pin => Self::DualInline9Pin,
+            smbioslib::PortInformationConnectorType::DualInline25Pin => Self::DualInline25Pin,
+            smbioslib::PortInformationConnectorType::DualInline50Pin => Self::DualInline50Pin,
+            smbioslib::PortInformationConnectorType::DualInline68Pin => Self::DualInline68Pin,
+            smbioslib::PortInformationConnectorType::OnBoardSoundInputCDRom => {
+                Self::OnBoardSoundInputCDRom
+            }
+            smbioslib::PortInformationConnectorType::MiniCentronicsType14 => {
+                Self::MiniCentronicsType14
+            }
+            smbioslib::PortInformationConnectorType::MiniCentronicsTyp26 => {
+                Self::MiniCentronicsTyp26
+            }
+            smbioslib::PortInformationConnectorType::MiniJackHeadphones => Self::MiniJackHeadphones,
+            smbioslib::PortInformationConnectorType::Bnc => Self::Bnc,
+            smbioslib::PortInformationConnectorType::Port1394 => Self::Port1394,
+            smbioslib::PortInformationConnectorType::SasSataPlugReceptacle => {
+                Self::SasSataPlugReceptacle
+            }
+            smbioslib::PortInformationConnectorType::UsbTypeCReceptacle => Self::UsbTypeCReceptacle,
+            smbioslib::PortInformationConnectorType::PC98 => Self::PC98,
+            smbioslib::PortInformationConnectorType::PC98Hireso => Self::PC98Hireso,
+            smbioslib::PortInformationConnectorType::PCH88 => Self::PCH88,
+            smbioslib::PortInformationConnectorType::PC98Note => Self::PC98Note,
+            smbioslib::PortInformationConnectorType::PC98Full => Self::PC98Full,
+            smbioslib::PortInformationConnectorType::Other => Self::Other,
+            smbioslib::PortInformationConnectorType::None => Self::None,
+        }
+    }
+}
+
+/// Port type data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PortInformationPortTypeData {
+    pub raw: u8,
+    pub value: PortInformationPortType,
+}
+
+impl From<smbioslib::PortInformationPortTypeData> for PortInformationPortTypeData {
+    fn from(value: smbioslib::PortInformationPortTypeData) -> Self {
+        Self {
+            raw: value.raw,
+            value: PortInformationPortType::from(value.value),
+        }
+    }
+}
+
+/// Port type
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum PortInformationPortType {
+    NoPort,
+    ParallelPortXTATCompatible,
+    ParallelPortPS2,
+    ParallelPortEcp,
+    ParallelPortEpp,
+    ParallelPortEcpEpp,
+    SerialPortXTATCompatible,
+    SerialPort16450Compatible,
+    SerialPort16550Compatible,
+    SerialPort16550ACompatible,
+    ScsiPort,
+    MidiPort,
+    JoyStickPort,
+    KeyboardPort,
+    MousePort,
+    SsaScsi,
+    Usb,
+    Firewire,
+    PcmciaTypeI,
+    PcmcialTypeII,
+    PcmciaTypeIii,
+    Cardbus,
+    AccessBusPort,
+    ScsiII,
+    ScsiWide,
+    PC98,
+    PC98Hireso,
+    PCH98,
+    VideoPort,
+    AudioPort,
+    ModemPort,
+    NetworkPort,
+    Sata,
+    Sas,
+    Mfdp,
+    Thunderbolt,
+    Port8251Compatible,
+    Port8251FifoCompatible,
+    Other,
+    None,
+}
+
+impl From<smbioslib::PortInformationPortType> for PortInformationPortType {
+    fn from(value: smbioslib::PortInformationPortType) -> Self {
+        match value {
+            smbioslib::PortInformationPortType::NoPort => Self::NoPort,
+            smbioslib::PortInformationPortType::ParallelPortXTATCompatible => {
+                Self::ParallelPortXTATCompatible
+            }
+            smbioslib::PortInformationPortType::ParallelPortPS2 => Self::ParallelPortPS2,
+            smbioslib::PortInformationPortType::ParallelPortEcp => Self::ParallelPortEcp,
+            smbioslib::PortInformationPortType::ParallelPortEpp => Self::ParallelPortEpp,
+            smbioslib::PortInformationPortType::ParallelPortEcpEpp => Self::ParallelPortEcpEpp,
+            smbioslib::PortInformationPortType::SerialPortXTATCompatible => {
+                Self::SerialPortXTATCompatible
+            }
+            smbioslib::PortInformationPortType::SerialPort16450Compatible => {
+                Self::SerialPort16450Compatible
+            }
+            smbioslib::PortInformationPortType::SerialPort16550Compatible => {
+                Self::SerialPort16550Compatible
+            }
+            smbioslib::PortInformationPortType::SerialPort16550ACompatible => {
+                Self::SerialPort16550ACompatible
+            }
+            smbioslib::PortInformationPortType::ScsiPort => Self::ScsiPort,
+            smbioslib::PortInformationPortType::MidiPort => Self::MidiPort,
+            smbioslib::PortInformationPortType::JoyStickPort => Self::JoyStickPort,
+            smbioslib::PortInformationPortType::KeyboardPort => Self::KeyboardPort,
+            smbioslib::PortInformationPortType::MousePort => Self::MousePort,
+            smbioslib::PortInformationPortType::SsaScsi => Self::SsaScsi,
+            smbioslib::PortInformationPortType::Usb => Self::Usb,
+            smbioslib::PortInformationPortType::Firewire => Self::Firewire,
+            smbioslib::PortInformationPortType::PcmciaTypeI => Self::PcmciaTypeI,
+            smbioslib::PortInformationPortType::PcmcialTypeII => Self::PcmcialTypeII,
+            smbioslib::PortInformationPortType::PcmciaTypeIii => Self::PcmciaTypeIii,
+            smbioslib::PortInformationPortType::Cardbus => Self::Cardbus,
+            smbioslib::PortInformationPortType::AccessBusPort => Self::AccessBusPort,
+            smbioslib::PortInformationPortType::ScsiII => Self::ScsiII,
+            smbioslib::PortInformationPortType::ScsiWide => Self::ScsiWide,
+            smbioslib::PortInformationPortType::PC98 => Self::PC98,
+            smbioslib::PortInformationPortType::PC98Hireso => Self::PC98Hireso,
+            smbioslib::PortInformationPortType::PCH98 => Self::PCH98,
+            smbioslib::PortInformationPortType::VideoPort => Self::VideoPort,
+            smbioslib::PortInformationPortType::AudioPort => Self::AudioPort,
+            smbioslib::PortInformationPortType::ModemPort => Self::ModemPort,
+            smbioslib::PortInformationPortType::NetworkPort => Self::NetworkPort,
+            smbioslib::PortInformationPortType::Sata => Self::Sata,
+            smbioslib::PortInformationPortType::Sas => Self::Sas,
+            smbioslib::PortInformationPortType::Mfdp => Self::Mfdp,
+            smbioslib::PortInformationPortType::Thunderbolt => Self::Thunderbolt,
+            smbioslib::PortInformationPortType::Port8251Compatible => Self::Port8251Compatible,
+            smbioslib::PortInformationPortType::Port8251FifoCompatible => {
+                Self::Port8251FifoCompatible
+            }
+            smbioslib::PortInformationPortType::Other => Self::Other,
+            smbioslib::PortInformationPortType::None => Self::None,
+        }
+    }
+}
+
+/// Attributes of a system port connector (serial, parallel,
+/// keyboard or mouse ports)
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Port {
+    /// Internal reference designator, that is, internal to the
+    /// system enclosure
+    pub internal_reference_designator: Option<String>,
+
+    /// Internal connector type
+    pub internal_connector_type: Option<PortInformationConnectorTypeData>,
+
+    /// External reference designation, external to the system
+    /// enclosure
+    pub external_reference_designator: Option<String>,
+
+    /// External connector type
+    pub external_connector_type: Option<PortInformationConnectorTypeData>,
+
+    /// Function of the port
+    pub port_type: Option<PortInformationPortTypeData>,
+}
+
+impl<'a> From<smbioslib::SMBiosPortConnectorInformation<'a>> for Port {
+    fn from(value: smbioslib::SMBiosPortConnectorInformation) -> Self {
+        Self {
+            internal_reference_designator: value.internal_reference_designator().ok(),
+            internal_connector_type: value
+                .internal_connector_type()
+                .map(PortInformationConnectorTypeData::from),
+            external_reference_designator: value.external_reference_designator().ok(),
+            external_connector_type: value
+                .external_connector_type()
+                .map(PortInformationConnectorTypeData::from),
+            port_type: value.port_type().map(PortInformationPortTypeData::from),
+        }
+    }
+}
+impl ToJson for Port {}
+
+/// Attributes of all system slots (PCI, PCIe, AGP, etc.)
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SystemSlots {
+    pub slots: Vec<SystemSlot>,
+}
+
+impl SystemSlots {
+    /// Creates a new instance of `Self`
+    ///
+    /// It is usually not required, since an instance of this
+    /// structure will be created using the method
+    /// `Self::new_from_table(table: &SMBiosData)` in the constructor
+    /// [`DMITable::new()`].
+    pub fn new() -> Result<Self> {
+        let table = load_table()?;
+        Self::new_from_table(&table)
+    }
+
+    pub fn new_from_table(table: &SMBiosData) -> Result<Self> {
+        let mut slots = vec![];
+
+        for slot in table.collect::<smbioslib::SMBiosSystemSlot>() {
+            slots.push(SystemSlot::from(slot));
+        }
+
+        Ok(Self { slots })
+    }
+}
+
+impl ToJson for SystemSlots {}
+
+/// System slot type data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SystemSlotTypeData {
+    pub raw: u8,
+    pub value: SystemSlotType,
+}
+
+impl From<smbioslib::SystemSlotTypeData> for SystemSlotTypeData {
+    fn from(value: smbioslib::SystemSlotTypeData) -> Self {
+        Self {
+            raw: value.raw,
+            value: SystemSlotType::from(value.value),
+        }
+    }
+}
+
+/// System slot type
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SystemSlotType {
+    Other,
+    Unknown,
+    Isa,
+    Mca,
+    Eisa,
+    Pci,
+    Pcmcia,
+    VlVesa,
+    Proprietary,
+    ProcessorCardSlot,
+    ProprietaryMemoryCardSlot,
+    IORiserCardSlot,
+    NuBus,
+    Pci66MhzCapable,
+    Agp(AgpSlotWidth),
+    Mxm(MXMSlotType),
+    PciX,
+    M2(M2SlotType),
+    OcpNic30SmallFormFactor,
+    OcpNic30LargeFormFactor,
+    OcpNicPriorTo30,
+    CxlFlexbus1,
+    PC98C20,
+    PC98C24,
+    PC98E,
+    PC98LocalBus,
+    PC98Card,
+    PciExpress(PciExpressGeneration, PciExpressSlotWidth),
+    EnterpriseAndDataCenter1UE1,
+    EnterpriseAndDataCenter3InE3,
+    None,
+}
+
+impl From<smbioslib::SystemSlotType> for SystemSlotType {
+    fn from(value: smbioslib::SystemSlotType) -> Self {
+        match value {
+            smbioslib::SystemSlotType::Other => Self::Other,
+            smbioslib::SystemSlotType::Unknown => Self::Unknown,
+            smbioslib::SystemSlotType::Isa => Self::Isa,
+            smbioslib::SystemSlotType::Mca => Self::Mca,
+            smbioslib::SystemSlotType::Eisa => Self::Eisa,
+            smbioslib::SystemSlotType::Pci => Self::Pci,
+            smbioslib::SystemSlotType::Pcmcia => Self::Pcmcia,
+            smbioslib::SystemSlotType::VlVesa => Self::VlVesa,
+            smbioslib::SystemSlotType::Proprietary => Self::Proprietary,
+            smbioslib::SystemSlotType::ProcessorCardSlot => Self::ProcessorCardSlot,
+            smbioslib::SystemSlotType::ProprietaryMemoryCardSlot => Self::ProprietaryMemoryCardSlot,
+            smbioslib::SystemSlotType::IORiserCardSlot => Self::IORiserCardSlot,
+            smbioslib::SystemSlotType::NuBus => Self::NuBus,
+            smbioslib::SystemSlotType::Pci66MhzCapable => Self::Pci66MhzCapable,
+            smbioslib::SystemSlotType::Agp(v) => Self::Agp(AgpSlotWidth::from(v)),
+            smbioslib::SystemSlotType::Mxm(v) => Self::Mxm(MXMSlotType::from(v)),
+            smbioslib::SystemSlotType::PciX => Self::PciX,
+            smbioslib::SystemSlotType::M2(v) => Self::M2(M2SlotType::from(v)),
+            smbioslib::SystemSlotType::OcpNic30SmallFormFactor => Self::OcpNic30SmallFormFactor,
+            smbioslib::SystemSlotType::OcpNic30LargeFormFactor => Self::OcpNic30LargeFormFactor,
+            smbioslib::SystemSlotType::OcpNicPriorTo30 => Self::OcpNicPriorTo30,
+            smbioslib::SystemSlotType::CxlFlexbus1 => Self::CxlFlexbus1,
+            smbioslib::SystemSlotType::PC98C20 => Self::PC98C20,
+            smbioslib::SystemSlotType::PC98C24 => Self::PC98C24,
+            smbioslib::SystemSlotType::PC98E => Self::PC98E,
+            smbioslib::SystemSlotType::PC98LocalBus => Self::PC98LocalBus,
+            smbioslib::SystemSlotType::PC98Card => Self::PC98Card,
+            smbioslib::SystemSlotType::PciExpress(a, b) => {
+                Self::PciExpress(PciExpressGeneration::from(a), PciExpressSlotWidth::from(b))
+            }
+            smbioslib::SystemSlotType::EnterpriseAndDataCenter1UE1 => {
+                Self::EnterpriseAndDataCenter1UE1
+            }
+            smbioslib::SystemSlotType::EnterpriseAndDataCenter3InE3 => {
+                Self::EnterpriseAndDataCenter3InE3
+            }
+            smbioslib::SystemSlotType::None => Self::None,
+        }
+    }
+}
+
+/// AGP slot width
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AgpSlotWidth {
+    X1,
+    X2,
+    X4,
+    X8,
+}
+
+impl From<smbioslib::AgpSlotWidth> for AgpSlotWidth {
+    fn from(value: smbioslib::AgpSlotWidth) -> Self {
+        match value {
+            smbioslib::AgpSlotWidth::X1 => Self::X1,
+            smbioslib::AgpSlotWidth::X2 => Self::X2,
+            smbioslib::AgpSlotWidth::X4 => Self::X4,
+            smbioslib::AgpSlotWidth::X8 => Self::X8,
+        }
+    }
+}
+
+/// MXM slot type
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MXMSlotType {
+    MxmTypeI,
+    MxmTypeII,
+    MxmTypeIIIStandard,
+    MxmTypeIIIHE,
+    MxmTypeIV,
+    Mxm3TypeA,
+    Mxm3TypeB,
+}
+
+impl From<smbioslib::MXMSlotType> for MXMSlotType {
+    fn from(value: smbioslib::MXMSlotType) -> Self {
+        match value {
+            smbioslib::MXMSlotType::MxmTypeI => Self::MxmTypeI,
+            smbioslib::MXMSlotType::MxmTypeII => Self::MxmTypeII,
+            smbioslib::MXMSlotType::MxmTypeIIIStandard => Self::MxmTypeIIIStandard,
+            smbioslib::MXMSlotType::MxmTypeIIIHE => Self::MxmTypeIIIHE,
+            smbioslib::MXMSlotType::MxmTypeIV => Self::MxmTypeIV,
+            smbioslib::MXMSlotType::Mxm3TypeA => Self::Mxm3TypeA,
+            smbioslib::MXMSlotType::Mxm3TypeB => Self::Mxm3TypeB,
+        }
+    }
+}
+
+/// M.2 slot type
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum M2SlotType {
+    M2Socket1DP,
+    M2Socket1SD,
+    M2Socket2,
+    M2Socket3,
+}
+
+impl From<smbioslib::M2SlotType> for M2SlotType {
+    fn from(value: smbioslib::M2SlotType) -> Self {
+        match value {
+            smbioslib::M2SlotType::M2Socket1DP => Self::M2Socket1DP,
+            smbioslib::M2SlotType::M2Socket1SD => Self::M2Socket1SD,
+            smbioslib::M2SlotType::M2Socket2 => Self::M2Socket2,
+            smbioslib::M2SlotType::M2Socket3 => Self::M2Socket3,
+        }
+    }
+}
+
+/// PCI Express generation
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum PciExpressGeneration {
+    PCIExpressGen1,
+    PCIExpressGen2,
+    PCIExpressGen3,
+    PCIExpressGen4,
+    PCIExpressGen5,
+    PCIExpressGen6,
+    Undefined,
+}
+
+impl From<smbioslib::PciExpressGeneration> for PciExpressGeneration {
+    fn from(value: smbioslib::PciExpressGeneration) -> Self {
+        match value {
+            smbioslib::PciExpressGeneration::PCIExpressGen1 => Self::PCIExpressGen1,
+            smbioslib::PciExpressGeneration::PCIExpressGen2 => Self::PCIExpressGen2,
+            smbioslib::PciExpressGeneration::PCIExpressGen3 => Self::PCIExpressGen3,
+            smbioslib::PciExpressGeneration::PCIExpressGen4 => Self::PCIExpressGen4,
+            smbioslib::PciExpressGeneration::PCIExpressGen5 => Self::PCIExpressGen5,
+            smbioslib::PciExpressGeneration::PCIExpressGen6 => Self::PCIExpressGen6,
+            smbioslib::PciExpressGeneration::Undefined => Self::Undefined,
+        }
+    }
+}
+
+/// PCI Express slot width
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum PciExpressSlotWidth {
+    UndefinedSlotWidth,
+    X1,
+    X2,
+    X4,
+    X8,
+    X16,
+    Sff8639,
+    PciExpressMini52WithKeepouts,
+    PciExpressMini52WithoutKeepouts,
+    PciExpressMini76,
+}
+
+impl From<smbioslib::PciExpressSlotWidth> for PciExpressSlotWidth {
+    fn from(value: smbioslib::PciExpressSlotWidth) -> Self {
+        match value {
+            smbioslib::PciExpressSlotWidth::UndefinedSlotWidth => Self::UndefinedSlotWidth,
+            smbioslib::PciExpressSlotWidth::X1 => Self::X1,
+            smbioslib::PciExpressSlotWidth::X2 => Self::X2,
+            smbioslib::PciExpressSlotWidth::X4 => Self::X4,
+            smbioslib::PciExpressSlotWidth::X8 => Self::X8,
+            smbioslib::PciExpressSlotWidth::X16 => Self::X16,
+            smbioslib::PciExpressSlotWidth::Sff8639 => Self::Sff8639,
+            smbioslib::PciExpressSlotWidth::PciExpressMini52WithKeepouts => {
+                Self::PciExpressMini52WithKeepouts
+            }
+            smbioslib::PciExpressSlotWidth::PciExpressMini52WithoutKeepouts => {
+                Self::PciExpressMini52WithoutKeepouts
+            }
+            smbioslib::PciExpressSlotWidth::PciExpressMini76 => Self::PciExpressMini76,
+        }
+    }
+}
+
+/// Slot data bus width data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SlotWidthData {
+    pub raw: u8,
+    pub value: SlotWidth,
+}
+
+impl From<smbioslib::SlotWidthData> for SlotWidthData {
+    fn from(value: smbioslib::SlotWidthData) -> Self {
+        Self {
+            raw: value.raw,
+            value: SlotWidth::from(value.value),
+        }
+    }
+}
+
+/// Slot data bus width
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SlotWidth {
+    Other,
+    Unknown,
+    Bit8,
+    Bit16,
+    Bit32,
+    Bit64,
+    Bit128,
+    X1,
+    X2,
+    X4,
+    X8,
+    X12,
+    X16,
+    X32,
+    None,
+}
+
+impl From<smbioslib::SlotWidth> for SlotWidth {
+    fn from(value: smbioslib::SlotWidth) -> Self {
+        match value {
+            smbioslib::SlotWidth::Other => Self::Other,
+            smbioslib::SlotWidth::Unknown => Self::Unknown,
+            smbioslib::SlotWidth::Bit8 => Self::Bit8,
+            smbioslib::SlotWidth::Bit16 => Self::Bit16,
+            smbioslib::SlotWidth::Bit32 => Self::Bit32,
+            smbioslib::SlotWidth::Bit64 => Self::Bit64,
+            smbioslib::SlotWidth::Bit128 => Self::Bit128,
+            smbioslib::SlotWidth::X1 => Self::X1,
+            smbioslib::SlotWidth::X2 => Self::X2,
+            smbioslib::SlotWidth::X4 => Self::X4,
+            smbioslib::SlotWidth::X8 => Self::X8,
+            smbioslib::SlotWidth::X12 => Self::X12,
+            smbioslib::SlotWidth::X16 => Self::X16,
+            smbioslib::SlotWidth::X32 => Self::X32,
+            smbioslib::SlotWidth::None => Self::None,
+        }
+    }
+}
+
+/// Slot current usage data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SlotCurrentUsageData {
+    pub raw: u8,
+    pub value: SlotCurrentUsage,
+}
+
+impl From<smbioslib::SlotCurrentUsageData> for SlotCurrentUsageData {
+    fn from(value: smbioslib::SlotCurrentUsageData) -> Self {
+        Self {
+            raw: value.raw,
+            value: SlotCurrentUsage::from(value.value),
+        }
+    }
+}
+
+/// Slot current usage
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SlotCurrentUsage {
+    Other,
+    Unknown,
+    Available,
+    InUse,
+    Unavailable,
+    None,
+}
+
+impl From<smbioslib::SlotCurrentUsage> for SlotCurrentUsage {
+    fn from(value: smbioslib::SlotCurrentUsage) -> Self {
+        match value {
+            smbioslib::SlotCurrentUsage::Other => Self::Other,
+            smbioslib::SlotCurrentUsage::Unknown => Self::Unknown,
+            smbioslib::SlotCurrentUsage::Available => Self::Available,
+            smbioslib::SlotCurrentUsage::InUse => Self::InUse,
+            smbioslib::SlotCurrentUsage::Unavailable => Self::Unavailable,
+            smbioslib::SlotCurrentUsage::None => Self::None,
+        }
+    }
+}
+
+/// Slot length data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SlotLengthData {
+    pub raw: u8,
+    pub value: SlotLength,
+}
+
+impl From<smbioslib::SlotLengthData> for SlotLengthData {
+    fn from(value: smbioslib::SlotLengthData) -> Self {
+        Self {
+            raw: value.raw,
+            value: SlotLength::from(value.value),
+        }
+    }
+}
+
+/// Slot length
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SlotLength {
+    Other,
+    Unknown,
+    ShortLength,
+    LongLength,
+    DriveFormFactor25,
+    DriveFormFactor35,
+    None,
+}
+
+impl From<smbioslib::SlotLength> for SlotLength {
+    fn from(value: smbioslib::SlotLength) -> Self {
+        match value {
+            smbioslib::SlotLength::Other => Self::Other,
+            smbioslib::SlotLength::Unknown => Self::Unknown,
+            smbioslib::SlotLength::ShortLength => Self::ShortLength,
+            smbioslib::SlotLength::LongLength => Self::LongLength,
+            smbioslib::SlotLength::DriveFormFactor25 => Self::DriveFormFactor25,
+            smbioslib::SlotLength::DriveFormFactor35 => Self::DriveFormFactor35,
+            smbioslib::SlotLength::None => Self::None,
+        }
+    }
+}
+
+/// Segment group number
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SegmentGroupNumber {
+    SingleSegment,
+    Number(u16),
+    NotApplicable,
+}
+
+impl From<smbioslib::SegmentGroupNumber> for SegmentGroupNumber {
+    fn from(value: smbioslib::SegmentGroupNumber) -> Self {
+        match value {
+            smbioslib::SegmentGroupNumber::SingleSegment => Self::SingleSegment,
+            smbioslib::SegmentGroupNumber::Number(v) => Self::Number(v),
+            smbioslib::SegmentGroupNumber::NotApplicable => Self::NotApplicable,
+        }
+    }
+}
+
+/// Bus number
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum BusNumber {
+    Number(u8),
+    NotApplicable,
+}
+
+impl From<smbioslib::BusNumber> for BusNumber {
+    fn from(value: smbioslib::BusNumber) -> Self {
+        match value {
+            smbioslib::BusNumber::Number(v) => Self::Number(v),
+            smbioslib::BusNumber::NotApplicable => Self::NotApplicable,
+        }
+    }
+}
+
+/// Device/function number
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum DeviceFunctionNumber {
+    Number { device: u8, function: u8 },
+    NotApplicable,
+}
+
+impl From<smbioslib::DeviceFunctionNumber> for DeviceFunctionNumber {
+    fn from(value: smbioslib::DeviceFunctionNumber) -> Self {
+        match value {
+            smbioslib::DeviceFunctionNumber::Number { device, function } => {
+                Self::Number { device, function }
+            }
+            smbioslib::DeviceFunctionNumber::NotApplicable => Self::NotApplicable,
+        }
+    }
+}
+
+/// Attributes of a system slot. One structure is provided for each
+/// slot in the system
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SystemSlot {
+    /// Reference designation of the slot (e.g. `PCIEX16_1`)
+    pub slot_designation: Option<String>,
+
+    /// Slot type
+    pub system_slot_type: Option<SystemSlotTypeData>,
+
+    /// Slot data bus width
+    pub slot_data_bus_width: Option<SlotWidthData>,
+
+    /// Current usage of the slot (available, in use, etc.)
+    pub current_usage: Option<SlotCurrentUsageData>,
+
+    /// Slot length
+    pub slot_length: Option<SlotLengthData>,
+
+    /// Segment group number of the device installed to this slot
+    pub segment_group_number: Option<SegmentGroupNumber>,
+
+    /// Bus number of the device installed to this slot
+    pub bus_number: Option<BusNumber>,
+
+    /// Device/function number of the device installed to this slot
+    pub device_function_number: Option<DeviceFunctionNumber>,
+}
+
+impl<'a> From<smbioslib::SMBiosSystemSlot<'a>> for SystemSlot {
+    fn from(value: smbioslib::SMBiosSystemSlot) -> Self {
+        Self {
+            slot_designation: value.slot_designation().ok(),
+            system_slot_type: value.system_slot_type().map(SystemSlotTypeData::from),
+            slot_data_bus_width: value.slot_data_bus_width().map(SlotWidthData::from),
+            current_usage: value.current_usage().map(SlotCurrentUsageData::from),
+            slot_length: value.slot_length().map(SlotLengthData::from),
+            segment_group_number: value.segment_group_number().map(SegmentGroupNumber::from),
+            bus_number: value.bus_number().map(BusNumber::from),
+            device_function_number: value
+                .device_function_number()
+                .map(DeviceFunctionNumber::from),
+        }
+    }
+}
+impl ToJson for SystemSlot {}
+
+/// Free-form strings defined by the system vendor
+///
+/// Many vendors store asset or provisioning data in these strings
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DMIExtras {
+    /// OEM strings (Type 11)
+    pub oem_strings: Vec<String>,
+
+    /// System configuration options (Type 12), e.g. jumper settings
+    pub configuration_options: Vec<String>,
+}
+
+impl DMIExtras {
+    /// Creates a new instance of `Self`
+    ///
+    /// It is usually not required, since an instance of this
+    /// structure will be created using the method
+    /// `Self::new_from_table(table: &SMBiosData)` in the constructor
+    /// [`DMITable::new()`].
+    pub fn new() -> Result<Self> {
+        let table = load_table()?;
+        Self::new_from_table(&table)
+    }
+
+    /// Both structures are optional, so missing ones result in an
+    /// empty list rather than an error
+    pub fn new_from_table(table: &SMBiosData) -> Result<Self> {
+        let mut oem_strings = vec![];
+        let mut configuration_options = vec![];
+
+        for oem in table.collect::<smbioslib::SMBiosOemStrings>() {
+            for s in oem.oem_strings() {
+                oem_strings.push(s.to_string());
+            }
+        }
+        for opts in table.collect::<smbioslib::SMBiosSystemConfigurationOptions>() {
+            for s in opts.configuration_strings() {
+                configuration_options.push(s.to_string());
+            }
+        }
+
+        Ok(Self {
+            oem_strings,
+            configuration_options,
+        })
+    }
+}
+
+impl ToJson for DMIExtras {}
+
+/// Memory array location data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemoryArrayLocationData {
+    pub raw: u8,
+    pub value: MemoryArrayLocation,
+}
+
+impl From<smbioslib::MemoryArrayLocationData> for MemoryArrayLocationData {
+    fn from(value: smbioslib::MemoryArrayLocationData) -> Self {
+        Self {
+            raw: value.raw,
+            value: MemoryArrayLocation::from(value.value),
+        }
+    }
+}
+
+/// Memory array location
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MemoryArrayLocation {
+    Other,
+    Unknown,
+    SystemBoardOrMotherboard,
+    IsaAddOnCard,
+    EisaAddOnCard,
+    PciAddOnCard,
+    McaAddOnCard,
+    PcmciaAddOnCard,
+    ProprietaryAddOnCard,
+    NuBus,
+    PC98C20AddOnCard,
+    PC98C24AddOnCard,
+    PC98EAddOnCard,
+    PC98LocalBusAddOnCard,
+    CxlFlexbus10AddOnCard,
+    None,
+}
+
+impl From<smbioslib::MemoryArrayLocation> for MemoryArrayLocation {
+    fn from(value: smbioslib::MemoryArrayLocation) -> Self {
+        match value {
+            smbioslib::MemoryArrayLocation::Other => Self::Other,
+            smbioslib::MemoryArrayLocation::Unknown => Self::Unknown,
+            smbioslib::MemoryArrayLocation::SystemBoardOrMotherboard => {
+                Self::SystemBoardOrMotherboard
+            }
+            smbioslib::MemoryArrayLocation::IsaAddOnCard => Self::IsaAddOnCard,
+            smbioslib::MemoryArrayLocation::EisaAddOnCard => Self::EisaAddOnCard,
+            smbioslib::MemoryArrayLocation::PciAddOnCard => Self::PciAddOnCard,
+            smbioslib::MemoryArrayLocation::McaAddOnCard => Self::McaAddOnCard,
+            smbioslib::MemoryArrayLocation::PcmciaAddOnCard => Self::PcmciaAddOnCard,
+            smbioslib::MemoryArrayLocation::ProprietaryAddOnCard => Self::ProprietaryAddOnCard,
+            smbioslib::MemoryArrayLocation::NuBus => Self::NuBus,
+            smbioslib::MemoryArrayLocation::PC98C20AddOnCard => Self::PC98C20AddOnCard,
+            smbioslib::MemoryArrayLocation::PC98C24AddOnCard => Self::PC98C24AddOnCard,
+            smbioslib::MemoryArrayLocation::PC98EAddOnCard => Self::PC98EAddOnCard,
+            smbioslib::MemoryArrayLocation::PC98LocalBusAddOnCard => Self::PC98LocalBusAddOnCard,
+            smbioslib::MemoryArrayLocation::CxlFlexbus10AddOnCard => Self::CxlFlexbus10AddOnCard,
+            smbioslib::MemoryArrayLocation::None => Self::None,
+        }
+    }
+}
+
+/// Memory array use data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemoryArrayUseData {
+    pub raw: u8,
+    pub value: MemoryArrayUse,
+}
+
+impl From<smbioslib::MemoryArrayUseData> for MemoryArrayUseData {
+    fn from(value: smbioslib::MemoryArrayUseData) -> Self {
+        Self {
+            raw: value.raw,
+            value: MemoryArrayUse::from(value.value),
+        }
+    }
+}
+
+/// Memory array use
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MemoryArrayUse {
+    Other,
+    Unknown,
+    SystemMemory,
+    VideoMemory,
+    FlashMemory,
+    NonVolatileRam,
+    CacheMemory,
+    None,
+}
+
+impl From<smbioslib::MemoryArrayUse> for MemoryArrayUse {
+    fn from(value: smbioslib::MemoryArrayUse) -> Self {
+        match value {
+            smbioslib::MemoryArrayUse::Other => Self::Other,
+            smbioslib::MemoryArrayUse::Unknown => Self::Unknown,
+            smbioslib::MemoryArrayUse::SystemMemory => Self::SystemMemory,
+            smbioslib::MemoryArrayUse::VideoMemory => Self::VideoMemory,
+            smbioslib::MemoryArrayUse::FlashMemory => Self::FlashMemory,
+            smbioslib::MemoryArrayUse::NonVolatileRam => Self::NonVolatileRam,
+            smbioslib::MemoryArrayUse::CacheMemory => Self::CacheMemory,
+            smbioslib::MemoryArrayUse::None => Self::None,
+        }
+    }
+}
+
+/// Memory array error correction data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemoryArrayErrorCorrectionData {
+    pub raw: u8,
+    pub value: MemoryArrayErrorCorrection,
+}
+
+impl From<smbioslib::MemoryArrayErrorCorrectionData> for MemoryArrayErrorCorrectionData {
+    fn from(value: smbioslib::MemoryArrayErrorCorrectionData) -> Self {
+        Self {
+            raw: value.raw,
+            value: MemoryArrayErrorCorrection::from(value.value),
+        }
+    }
+}
+
+/// Memory array error correction
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MemoryArrayErrorCorrection {
+    Other,
+    Unknown,
+    NoCorrection,
+    Parity,
+    SingleBitEcc,
+    MultiBitEcc,
+    Crc,
+    None,
+}
+
+impl From<smbioslib::MemoryArrayErrorCorrection> for MemoryArrayErrorCorrection {
+    fn from(value: smbioslib::MemoryArrayErrorCorrection) -> Self {
+        match value {
+            smbioslib::MemoryArrayErrorCorrection::Other => Self::Other,
+            smbioslib::MemoryArrayErrorCorrection::Unknown => Self::Unknown,
+            smbioslib::MemoryArrayErrorCorrection::NoCorrection => Self::NoCorrection,
+            smbioslib::MemoryArrayErrorCorrection::Parity => Self::Parity,
+            smbioslib::MemoryArrayErrorCorrection::SingleBitEcc => Self::SingleBitEcc,
+            smbioslib::MemoryArrayErrorCorrection::MultiBitEcc => Self::MultiBitEcc,
+            smbioslib::MemoryArrayErrorCorrection::Crc => Self::Crc,
+            smbioslib::MemoryArrayErrorCorrection::None => Self::None,
+        }
+    }
+}
+
+/// Maximum memory capacity
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MaximumMemoryCapacity {
+    Kilobytes(u32),
+    SeeExtendedMaximumCapacity,
+}
+
+impl From<smbioslib::MaximumMemoryCapacity> for MaximumMemoryCapacity {
+    fn from(value: smbioslib::MaximumMemoryCapacity) -> Self {
+        match value {
+            smbioslib::MaximumMemoryCapacity::Kilobytes(v) => Self::Kilobytes(v),
+            smbioslib::MaximumMemoryCapacity::SeeExtendedMaximumCapacity => {
+                Self::SeeExtendedMaximumCapacity
+            }
+        }
+    }
+}
+
+/// Collection of memory devices that operate together to form a memory address space
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemoryArray {
+    /// Physical location of the Memory Array, whether on the system
+    /// board or an add-in board
+    pub location: Option<MemoryArrayLocationData>,
+
+    /// Which the array is used
+    pub usage: Option<MemoryArrayUseData>,
+
+    /// Primary hardware error correction or detection method
+    /// supported by this memory array
+    pub memory_error_correction: Option<MemoryArrayErrorCorrectionData>,
+
+    /// Maximum memory capacity, in kbytes, for this array
+    pub maximum_capacity: Option<MaximumMemoryCapacity>,
+
+    /// Handle, or instance number, associated with any error that
+    /// was previously detected for the array
+    pub memory_error_information_handle: Option<Handle>,
+
+    /// Number of slots/sockets available for memory devices in
+    /// this array
+    pub number_of_memory_devices: Option<u16>,
+
+    /// Maximum memory capacity, in bytes, for this array. This
+    /// field is only valid when the Maximum Capacity field
+    /// contains 8000 0000h.
+    pub extended_maximum_capacity: Option<u64>,
+}
+
+impl MemoryArray {
+    /// Creates a new instance of `Self`
+    ///
+    /// It is usually not required, since an instance of this
+    /// structure will be created using the method
+    /// `Self::new_from_table(table: &SMBiosData)` in the constructor
+    /// [`DMITable::new()`].
+    pub fn new() -> Result<Self> {
+        let table = load_table()?;
+        Self::new_from_table(&table)
+    }
+
+    pub fn new_from_table(table: &SMBiosData) -> Result<Self> {
+        let t = table
+            .find_map(|f: smbioslib::SMBiosPhysicalMemoryArray| Some(f))
             .ok_or(anyhow!(
                 "Failed to get information about memory array (type 16)!"
             ))?;
 
         Ok(Self {
-            location: t.location(),
-            usage: t.usage(),
-            memory_error_correction: t.memory_error_correction(),
-            maximum_capacity: t.maximum_capacity(),
-            memory_error_information_handle: t.memory_error_information_handle(),
+            location: t.location().map(MemoryArrayLocationData::from),
+            usage: t.usage().map(MemoryArrayUseData::from),
+            memory_error_correction: t
+                .memory_error_correction()
+                .map(MemoryArrayErrorCorrectionData::from),
+            maximum_capacity: t.maximum_capacity().map(MaximumMemoryCapacity::from),
+            memory_error_information_handle: t.memory_error_information_handle().map(Handle::from),
             number_of_memory_devices: t.number_of_memory_devices(),
             extended_maximum_capacity: t.extended_maximum_capacity(),
         })
@@ -3254,7 +5160,8 @@ impl MemoryArray {
 impl ToJson for MemoryArray {}
 
 /// Information about all installed memory devices
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MemoryDevices {
     pub memory: Vec<MemoryDevice>,
 }
@@ -3267,7 +5174,7 @@ impl MemoryDevices {
     /// `Self::new_from_table(table: &SMBiosData)` in the constructor
     /// [`DMITable::new()`].
     pub fn new() -> Result<Self> {
-        let table = smbioslib::table_load_from_device()?;
+        let table = load_table()?;
         Self::new_from_table(&table)
     }
 
@@ -3280,22 +5187,447 @@ impl MemoryDevices {
 
         Ok(Self { memory })
     }
+
+    /// Returns the number of slots with the installed memory module
+    pub fn populated_count(&self) -> usize {
+        self.memory.iter().filter(|m| m.is_populated()).count()
+    }
 }
 
 impl ToJson for MemoryDevices {}
 
+/// Returns the total size (in bytes) of memory installed across all
+/// populated slots
+pub fn total_installed(devices: &MemoryDevices) -> u64 {
+    devices.memory.iter().filter_map(|m| m.size_bytes()).sum()
+}
+
+/// Memory device size
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MemorySize {
+    NotInstalled,
+    Unknown,
+    SeeExtendedSize,
+    Kilobytes(u16),
+    Megabytes(u16),
+}
+
+impl From<smbioslib::MemorySize> for MemorySize {
+    fn from(value: smbioslib::MemorySize) -> Self {
+        match value {
+            smbioslib::MemorySize::NotInstalled => Self::NotInstalled,
+            smbioslib::MemorySize::Unknown => Self::Unknown,
+            smbioslib::MemorySize::SeeExtendedSize => Self::SeeExtendedSize,
+            smbioslib::MemorySize::Kilobytes(v) => Self::Kilobytes(v),
+            smbioslib::MemorySize::Megabytes(v) => Self::Megabytes(v),
+        }
+    }
+}
+
+/// Extended memory device size
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MemorySizeExtended {
+    Megabytes(u32),
+    SeeSize,
+}
+
+impl From<smbioslib::MemorySizeExtended> for MemorySizeExtended {
+    fn from(value: smbioslib::MemorySizeExtended) -> Self {
+        match value {
+            smbioslib::MemorySizeExtended::Megabytes(v) => Self::Megabytes(v),
+            smbioslib::MemorySizeExtended::SeeSize => Self::SeeSize,
+        }
+    }
+}
+
+/// Memory region size
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MemoryIndicatedSize {
+    Unknown,
+    Bytes(u64),
+}
+
+impl From<smbioslib::MemoryIndicatedSize> for MemoryIndicatedSize {
+    fn from(value: smbioslib::MemoryIndicatedSize) -> Self {
+        match value {
+            smbioslib::MemoryIndicatedSize::Unknown => Self::Unknown,
+            smbioslib::MemoryIndicatedSize::Bytes(v) => Self::Bytes(v),
+        }
+    }
+}
+
+/// Memory device form factor data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemoryFormFactorData {
+    pub raw: u8,
+    pub value: MemoryFormFactor,
+}
+
+impl From<smbioslib::MemoryFormFactorData> for MemoryFormFactorData {
+    fn from(value: smbioslib::MemoryFormFactorData) -> Self {
+        Self {
+            raw: value.raw,
+            value: MemoryFormFactor::from(value.value),
+        }
+    }
+}
+
+/// Memory device form factor
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MemoryFormFactor {
+    Other,
+    Unknown,
+    Simm,
+    Sip,
+    Chip,
+    Dip,
+    Zip,
+    ProprietaryCard,
+    Dimm,
+    Tsop,
+    RowOfChips,
+    Rimm,
+    Sodimm,
+    Srimm,
+    Fbdimm,
+    Die,
+    None,
+}
+
+impl From<smbioslib::MemoryFormFactor> for MemoryFormFactor {
+    fn from(value: smbioslib::MemoryFormFactor) -> Self {
+        match value {
+            smbioslib::MemoryFormFactor::Other => Self::Other,
+            smbioslib::MemoryFormFactor::Unknown => Self::Unknown,
+            smbioslib::MemoryFormFactor::Simm => Self::Simm,
+            smbioslib::MemoryFormFactor::Sip => Self::Sip,
+            smbioslib::MemoryFormFactor::Chip => Self::Chip,
+            smbioslib::MemoryFormFactor::Dip => Self::Dip,
+            smbioslib::MemoryFormFactor::Zip => Self::Zip,
+            smbioslib::MemoryFormFactor::ProprietaryCard => Self::ProprietaryCard,
+            smbioslib::MemoryFormFactor::Dimm => Self::Dimm,
+            smbioslib::MemoryFormFactor::Tsop => Self::Tsop,
+            smbioslib::MemoryFormFactor::RowOfChips => Self::RowOfChips,
+            smbioslib::MemoryFormFactor::Rimm => Self::Rimm,
+            smbioslib::MemoryFormFactor::Sodimm => Self::Sodimm,
+            smbioslib::MemoryFormFactor::Srimm => Self::Srimm,
+            smbioslib::MemoryFormFactor::Fbdimm => Self::Fbdimm,
+            smbioslib::MemoryFormFactor::Die => Self::Die,
+            smbioslib::MemoryFormFactor::None => Self::None,
+        }
+    }
+}
+
+/// Memory device type data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemoryDeviceTypeData {
+    pub raw: u8,
+    pub value: MemoryDeviceType,
+}
+
+impl From<smbioslib::MemoryDeviceTypeData> for MemoryDeviceTypeData {
+    fn from(value: smbioslib::MemoryDeviceTypeData) -> Self {
+        Self {
+            raw: value.raw,
+            value: MemoryDeviceType::from(value.value),
+        }
+    }
+}
+
+/// Memory device type
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MemoryDeviceType {
+    Other,
+    Unknown,
+    Dram,
+    Edram,
+    Vram,
+    Sram,
+    Ram,
+    Rom,
+    Flash,
+    Eeprom,
+    Feprom,
+    Eprom,
+    Cdram,
+    ThreeDram,
+    Sdram,
+    Sgram,
+    Rdram,
+    Ddr,
+    Ddr2,
+    Ddr2Fbdimm,
+    Ddr3,
+    Fbd2,
+    Ddr4,
+    Lpddr,
+    Lpddr2,
+    Lpddr3,
+    Lpddr4,
+    LogicalNonVolatileDevice,
+    Hbm,
+    Hbm2,
+    Ddr5,
+    Lpddr5,
+    Hbm3,
+    None,
+}
+
+impl From<smbioslib::MemoryDeviceType> for MemoryDeviceType {
+    fn from(value: smbioslib::MemoryDeviceType) -> Self {
+        match value {
+            smbioslib::MemoryDeviceType::Other => Self::Other,
+            smbioslib::MemoryDeviceType::Unknown => Self::Unknown,
+            smbioslib::MemoryDeviceType::Dram => Self::Dram,
+            smbioslib::MemoryDeviceType::Edram => Self::Edram,
+            smbioslib::MemoryDeviceType::Vram => Self::Vram,
+            smbioslib::MemoryDeviceType::Sram => Self::Sram,
+            smbioslib::MemoryDeviceType::Ram => Self::Ram,
+            smbioslib::MemoryDeviceType::Rom => Self::Rom,
+            smbioslib::MemoryDeviceType::Flash => Self::Flash,
+            smbioslib::MemoryDeviceType::Eeprom => Self::Eeprom,
+            smbioslib::MemoryDeviceType::Feprom => Self::Feprom,
+            smbioslib::MemoryDeviceType::Eprom => Self::Eprom,
+            smbioslib::MemoryDeviceType::Cdram => Self::Cdram,
+            smbioslib::MemoryDeviceType::ThreeDram => Self::ThreeDram,
+            smbioslib::MemoryDeviceType::Sdram => Self::Sdram,
+            smbioslib::MemoryDeviceType::Sgram => Self::Sgram,
+            smbioslib::MemoryDeviceType::Rdram => Self::Rdram,
+            smbioslib::MemoryDeviceType::Ddr => Self::Ddr,
+            smbioslib::MemoryDeviceType::Ddr2 => Self::Ddr2,
+            smbioslib::MemoryDeviceType::Ddr2Fbdimm => Self::Ddr2Fbdimm,
+            smbioslib::MemoryDeviceType::Ddr3 => Self::Ddr3,
+            smbioslib::MemoryDeviceType::Fbd2 => Self::Fbd2,
+            smbioslib::MemoryDeviceType::Ddr4 => Self::Ddr4,
+            smbioslib::MemoryDeviceType::Lpddr => Self::Lpddr,
+            smbioslib::MemoryDeviceType::Lpddr2 => Self::Lpddr2,
+            smbioslib::MemoryDeviceType::Lpddr3 => Self::Lpddr3,
+            smbioslib::MemoryDeviceType::Lpddr4 => Self::Lpddr4,
+            smbioslib::MemoryDeviceType::LogicalNonVolatileDevice => Self::LogicalNonVolatileDevice,
+            smbioslib::MemoryDeviceType::Hbm => Self::Hbm,
+            smbioslib::MemoryDeviceType::Hbm2 => Self::Hbm2,
+            smbioslib::MemoryDeviceType::Ddr5 => Self::Ddr5,
+            smbioslib::MemoryDeviceType::Lpddr5 => Self::Lpddr5,
+            smbioslib::MemoryDeviceType::Hbm3 => Self::Hbm3,
+            smbioslib::MemoryDeviceType::None => Self::None,
+        }
+    }
+}
+
+/// Memory device type details
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemoryTypeDetails {
+    pub raw: u16,
+
+    /// Other
+    pub other: bool,
+
+    /// Unknown
+    pub unknown: bool,
+
+    /// Fast-paged
+    pub fast_paged: bool,
+
+    /// Static column
+    pub static_column: bool,
+
+    /// Pseudo-static
+    pub pseudo_static: bool,
+
+    /// RAMBUS
+    pub ram_bus: bool,
+
+    /// Synchronous
+    pub synchronous: bool,
+
+    /// CMOS
+    pub cmos: bool,
+
+    /// EDO
+    pub edo: bool,
+
+    /// Window DRAM
+    pub window_dram: bool,
+
+    /// Cache DRAM
+    pub cache_dram: bool,
+
+    /// Non-volatile
+    pub non_volatile: bool,
+
+    /// Registered (Buffered)
+    pub registered: bool,
+
+    /// Unbuffered (Unregistered)
+    pub unbuffered: bool,
+
+    /// LRDIMM
+    pub lrdimm: bool,
+}
+
+impl From<smbioslib::MemoryTypeDetails> for MemoryTypeDetails {
+    fn from(value: smbioslib::MemoryTypeDetails) -> Self {
+        Self {
+            raw: value.raw,
+            other: value.other(),
+            unknown: value.unknown(),
+            fast_paged: value.fast_paged(),
+            static_column: value.static_column(),
+            pseudo_static: value.pseudo_static(),
+            ram_bus: value.ram_bus(),
+            synchronous: value.synchronous(),
+            cmos: value.cmos(),
+            edo: value.edo(),
+            window_dram: value.window_dram(),
+            cache_dram: value.cache_dram(),
+            non_volatile: value.non_volatile(),
+            registered: value.registered(),
+            unbuffered: value.unbuffered(),
+            lrdimm: value.lrdimm(),
+        }
+    }
+}
+
+/// Memory device speed
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MemorySpeed {
+    Unknown,
+    SeeExtendedSpeed,
+    MTs(u16),
+}
+
+impl From<smbioslib::MemorySpeed> for MemorySpeed {
+    fn from(value: smbioslib::MemorySpeed) -> Self {
+        match value {
+            smbioslib::MemorySpeed::Unknown => Self::Unknown,
+            smbioslib::MemorySpeed::SeeExtendedSpeed => Self::SeeExtendedSpeed,
+            smbioslib::MemorySpeed::MTs(v) => Self::MTs(v),
+        }
+    }
+}
+
+/// Extended memory device speed
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MemorySpeedExtended {
+    MTs(u32),
+    SeeSpeed,
+}
+
+impl From<smbioslib::MemorySpeedExtended> for MemorySpeedExtended {
+    fn from(value: smbioslib::MemorySpeedExtended) -> Self {
+        match value {
+            smbioslib::MemorySpeedExtended::MTs(v) => Self::MTs(v),
+            smbioslib::MemorySpeedExtended::SeeSpeed => Self::SeeSpeed,
+        }
+    }
+}
+
+/// Memory device technology data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemoryDeviceTechnologyData {
+    pub raw: u8,
+    pub value: MemoryDeviceTechnology,
+}
+
+impl From<smbioslib::MemoryDeviceTechnologyData> for MemoryDeviceTechnologyData {
+    fn from(value: smbioslib::MemoryDeviceTechnologyData) -> Self {
+        Self {
+            raw: value.raw,
+            value: MemoryDeviceTechnology::from(value.value),
+        }
+    }
+}
+
+/// Memory device technology
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MemoryDeviceTechnology {
+    Other,
+    Unknown,
+    Dram,
+    NvdimmN,
+    NvdimmF,
+    NvdimmP,
+    IntelOptaneDcPersistentMemory,
+    None,
+}
+
+impl From<smbioslib::MemoryDeviceTechnology> for MemoryDeviceTechnology {
+    fn from(value: smbioslib::MemoryDeviceTechnology) -> Self {
+        match value {
+            smbioslib::MemoryDeviceTechnology::Other => Self::Other,
+            smbioslib::MemoryDeviceTechnology::Unknown => Self::Unknown,
+            smbioslib::MemoryDeviceTechnology::Dram => Self::Dram,
+            smbioslib::MemoryDeviceTechnology::NvdimmN => Self::NvdimmN,
+            smbioslib::MemoryDeviceTechnology::NvdimmF => Self::NvdimmF,
+            smbioslib::MemoryDeviceTechnology::NvdimmP => Self::NvdimmP,
+            smbioslib::MemoryDeviceTechnology::IntelOptaneDcPersistentMemory => {
+                Self::IntelOptaneDcPersistentMemory
+            }
+            smbioslib::MemoryDeviceTechnology::None => Self::None,
+        }
+    }
+}
+
+/// Memory operating modes supported by the memory device
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemoryOperatingModeCapabilities {
+    pub raw: u16,
+
+    /// Other
+    pub other: bool,
+
+    /// Unknown
+    pub unknown: bool,
+
+    /// Volatile memory
+    pub volatile_memory: bool,
+
+    /// Byte-accessible persistent memory
+    pub byte_accessible_persistent_memory: bool,
+
+    /// Block-accessible persistent memory
+    pub block_accessible_persistent_memory: bool,
+}
+
+impl From<smbioslib::MemoryOperatingModeCapabilities> for MemoryOperatingModeCapabilities {
+    fn from(value: smbioslib::MemoryOperatingModeCapabilities) -> Self {
+        Self {
+            raw: value.raw,
+            other: value.other(),
+            unknown: value.unknown(),
+            volatile_memory: value.volatile_memory(),
+            byte_accessible_persistent_memory: value.byte_accessible_persistent_memory(),
+            block_accessible_persistent_memory: value.block_accessible_persistent_memory(),
+        }
+    }
+}
+
 /// Information about single memory device
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MemoryDevice {
     /// Handle or instance number, associated with the physical
     /// memory array to which this device belongs
-    pub physical_memory_array_handle: Option<smbioslib::Handle>,
+    pub physical_memory_array_handle: Option<Handle>,
 
     /// Handle or instance number, associated with any error that
     /// was previously detected for the device. If the system does
     /// not provide the error information structure, the field
     /// containes FFFEH
-    pub memory_error_information_handle: Option<smbioslib::Handle>,
+    pub memory_error_information_handle: Option<Handle>,
 
     /// Total width, in bits, of this memory device, including any
     /// check or error-correction bits
@@ -3305,10 +5637,10 @@ pub struct MemoryDevice {
     pub data_width: Option<u16>,
 
     /// Size of memory device
-    pub size: Option<smbioslib::MemorySize>,
+    pub size: Option<MemorySize>,
 
     /// Form factor for this memory device
-    pub form_factor: Option<smbioslib::MemoryFormFactorData>,
+    pub form_factor: Option<MemoryFormFactorData>,
 
     /// Identifies when the Memory Device is one of a set of
     /// Memory Devices that must be populated with all devices
@@ -3326,13 +5658,13 @@ pub struct MemoryDevice {
     pub bank_locator: Option<String>,
 
     /// Type of memory used in this device
-    pub memory_type: Option<smbioslib::MemoryDeviceTypeData>,
+    pub memory_type: Option<MemoryDeviceTypeData>,
 
     /// Additional detail on the memory device type
-    pub type_detail: Option<smbioslib::MemoryTypeDetails>,
+    pub type_detail: Option<MemoryTypeDetails>,
 
     /// The maximum capable speed of the device (MT/s)
-    pub speed: Option<smbioslib::MemorySpeed>,
+    pub speed: Option<MemorySpeed>,
 
     /// Manufacturer of this memory device
     pub manufacturer: Option<String>,
@@ -3350,10 +5682,10 @@ pub struct MemoryDevice {
     pub attributes: Option<u8>,
 
     /// Extended suze of the memory device in MB
-    pub extended_size: Option<smbioslib::MemorySizeExtended>,
+    pub extended_size: Option<MemorySizeExtended>,
 
     /// Configured speed of the memory device, in megatransfers per second (MT/s)
-    pub configured_memory_speed: Option<smbioslib::MemorySpeed>,
+    pub configured_memory_speed: Option<MemorySpeed>,
 
     /// Minimum operating voltage for this device, in millivolts
     pub minimum_voltage: Option<u16>,
@@ -3365,10 +5697,10 @@ pub struct MemoryDevice {
     pub configured_voltage: Option<u16>,
 
     /// Memory technology type for this memory device
-    pub memory_technology: Option<smbioslib::MemoryDeviceTechnologyData>,
+    pub memory_technology: Option<MemoryDeviceTechnologyData>,
 
     /// The operating modes supported by this memory device
-    pub memory_operating_mode_capability: Option<smbioslib::MemoryOperatingModeCapabilities>,
+    pub memory_operating_mode_capability: Option<MemoryOperatingModeCapabilities>,
 
     /// Firmware version of this memory device
     pub firmware_version: Option<String>,
@@ -3391,29 +5723,29 @@ pub struct MemoryDevice {
 
     /// Size of the Non-volatile portion of the memory device in
     /// Bytes, if any
-    pub non_volatile_size: Option<smbioslib::MemoryIndicatedSize>,
+    pub non_volatile_size: Option<MemoryIndicatedSize>,
 
     /// Size of the Volatile portion of the memory device in
     /// Bytes, if any
-    pub volatile_size: Option<smbioslib::MemoryIndicatedSize>,
+    pub volatile_size: Option<MemoryIndicatedSize>,
 
     /// Size of the Cache portion of the memory device in Bytes,
     /// if any
-    pub cache_size: Option<smbioslib::MemoryIndicatedSize>,
+    pub cache_size: Option<MemoryIndicatedSize>,
 
     /// Size of the Logical memory device in Bytes
-    pub logical_size: Option<smbioslib::MemoryIndicatedSize>,
+    pub logical_size: Option<MemoryIndicatedSize>,
 
     /// Extended speed of the memory device (complements the
     /// Speed field at offset 15h). Identifies the maximum capable
     /// speed of the device, in MT/s
-    pub extended_speed: Option<smbioslib::MemorySpeedExtended>,
+    pub extended_speed: Option<MemorySpeedExtended>,
 
     /// Extended configured memory speed of the memory device
     /// (complements the `configure_memory_speed` field at offset
     /// 20h). Identifies the configured speed of the memory device,
     /// in MT/s
-    pub extended_configured_speed: Option<smbioslib::MemorySpeedExtended>,
+    pub extended_configured_speed: Option<MemorySpeedExtended>,
 
     /// Two-byte PMIC0 manufacturer ID found in the SPD of this
     /// memory device; LSB first
@@ -3435,42 +5767,50 @@ pub struct MemoryDevice {
 impl<'a> From<smbioslib::SMBiosMemoryDevice<'a>> for MemoryDevice {
     fn from(value: smbioslib::SMBiosMemoryDevice) -> Self {
         Self {
-            physical_memory_array_handle: value.physical_memory_array_handle(),
-            memory_error_information_handle: value.memory_error_information_handle(),
+            physical_memory_array_handle: value.physical_memory_array_handle().map(Handle::from),
+            memory_error_information_handle: value
+                .memory_error_information_handle()
+                .map(Handle::from),
             total_width: value.total_width(),
             data_width: value.data_width(),
-            size: value.size(),
-            form_factor: value.form_factor(),
+            size: value.size().map(MemorySize::from),
+            form_factor: value.form_factor().map(MemoryFormFactorData::from),
             device_set: value.device_set(),
             device_locator: value.device_locator().ok(),
             bank_locator: value.bank_locator().ok(),
-            memory_type: value.memory_type(),
-            type_detail: value.type_detail(),
-            speed: value.speed(),
+            memory_type: value.memory_type().map(MemoryDeviceTypeData::from),
+            type_detail: value.type_detail().map(MemoryTypeDetails::from),
+            speed: value.speed().map(MemorySpeed::from),
             manufacturer: value.manufacturer().ok(),
             serial_number: value.serial_number().ok(),
             asset_tag: value.asset_tag().ok(),
             part_number: value.part_number().ok(),
             attributes: value.attributes(),
-            extended_size: value.extended_size(),
-            configured_memory_speed: value.configured_memory_speed(),
+            extended_size: value.extended_size().map(MemorySizeExtended::from),
+            configured_memory_speed: value.configured_memory_speed().map(MemorySpeed::from),
             minimum_voltage: value.minimum_voltage(),
             maximum_voltage: value.maximum_voltage(),
             configured_voltage: value.configured_voltage(),
-            memory_technology: value.memory_technology(),
-            memory_operating_mode_capability: value.memory_operating_mode_capability(),
+            memory_technology: value
+                .memory_technology()
+                .map(MemoryDeviceTechnologyData::from),
+            memory_operating_mode_capability: value
+                .memory_operating_mode_capability()
+                .map(MemoryOperatingModeCapabilities::from),
             firmware_version: value.firmware_version().ok(),
             module_manufacturer_id: value.module_manufacturer_id(),
             module_product_id: value.module_product_id(),
             memory_subsystem_controller_manufacturer_id: value
                 .memory_subsystem_controller_manufacturer_id(),
             memory_subsystem_controller_product_id: value.memory_subsystem_controller_product_id(),
-            non_volatile_size: value.non_volatile_size(),
-            volatile_size: value.volatile_size(),
-            cache_size: value.cache_size(),
-            logical_size: value.logical_size(),
-            extended_speed: value.extended_speed(),
-            extended_configured_speed: value.extended_speed(),
+            non_volatile_size: value.non_volatile_size().map(MemoryIndicatedSize::from),
+            volatile_size: value.volatile_size().map(MemoryIndicatedSize::from),
+            cache_size: value.cache_size().map(MemoryIndicatedSize::from),
+            logical_size: value.logical_size().map(MemoryIndicatedSize::from),
+            extended_speed: value.extended_speed().map(MemorySpeedExtended::from),
+            extended_configured_speed: value
+                .extended_configured_memory_speed()
+                .map(MemorySpeedExtended::from),
             pmic0_manufacturer_id: value.pmic0_manufacturer_id(),
             pmic0_revision_number: value.pmic0_revision_number(),
             rcd_manufacturer_id: value.rcd_manufacturer_id(),
@@ -3479,3 +5819,148 @@ impl<'a> From<smbioslib::SMBiosMemoryDevice<'a>> for MemoryDevice {
     }
 }
 impl ToJson for MemoryDevice {}
+
+impl MemoryDevice {
+    /// Returns the size of the installed memory module in bytes or
+    /// `None` if the slot is empty or its size is unknown
+    pub fn size_bytes(&self) -> Option<u64> {
+        memory_size_bytes(self.size.as_ref()?, self.extended_size.as_ref())
+    }
+
+    /// Checks whether a memory module is installed in this slot
+    pub fn is_populated(&self) -> bool {
+        self.size_bytes().is_some()
+    }
+}
+
+fn memory_size_bytes(size: &MemorySize, extended_size: Option<&MemorySizeExtended>) -> Option<u64> {
+    let bytes = match size {
+        MemorySize::Kilobytes(kb) => *kb as u64 * 1024,
+        MemorySize::Megabytes(mb) => *mb as u64 * 1024 * 1024,
+        MemorySize::SeeExtendedSize => match extended_size? {
+            MemorySizeExtended::Megabytes(mb) => *mb as u64 * 1024 * 1024,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    if bytes == 0 { None } else { Some(bytes) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_size_bytes_test() {
+        assert_eq!(
+            memory_size_bytes(&MemorySize::Megabytes(8192), None),
+            Some(8 * 1024 * 1024 * 1024)
+        );
+        assert_eq!(
+            memory_size_bytes(&MemorySize::Kilobytes(512), None),
+            Some(512 * 1024)
+        );
+        assert_eq!(
+            memory_size_bytes(
+                &MemorySize::SeeExtendedSize,
+                Some(&MemorySizeExtended::Megabytes(65536))
+            ),
+            Some(64 * 1024 * 1024 * 1024)
+        );
+        assert_eq!(memory_size_bytes(&MemorySize::SeeExtendedSize, None), None);
+        assert_eq!(memory_size_bytes(&MemorySize::Megabytes(0), None), None);
+        assert_eq!(memory_size_bytes(&MemorySize::NotInstalled, None), None);
+        assert_eq!(memory_size_bytes(&MemorySize::Unknown, None), None);
+    }
+
+    fn structure(kind: u8, handle: u16, body: &[u8], strings: &[&str]) -> Vec<u8> {
+        let mut data = vec![kind, 4 + body.len() as u8];
+        data.extend(handle.to_le_bytes());
+        data.extend(body);
+        for s in strings {
+            data.extend(s.as_bytes());
+            data.push(0);
+        }
+        if strings.is_empty() {
+            data.push(0);
+        }
+        data.push(0);
+        data
+    }
+
+    fn memory_device(handle: u16, size_mb: u16) -> Vec<u8> {
+        let mut body = vec![];
+        body.extend(0x1000u16.to_le_bytes()); // physical memory array
+        body.extend(0xFFFEu16.to_le_bytes()); // no error information
+        body.extend(72u16.to_le_bytes()); // total width
+        body.extend(64u16.to_le_bytes()); // data width
+        body.extend(size_mb.to_le_bytes());
+        body.extend([0x09, 0, 1, 2, 0x1A]); // DIMM, locators, DDR4
+        body.extend(0x0080u16.to_le_bytes()); // synchronous
+        body.extend(3200u16.to_le_bytes()); // speed
+        body.extend([0, 0, 0, 0, 2]); // no strings, rank 2
+        body.extend(0u32.to_le_bytes()); // see size
+        body.extend(3200u16.to_le_bytes()); // configured speed
+        structure(17, handle, &body, &["DIMM 0", "BANK 0"])
+    }
+
+    fn populated_table() -> SMBiosData {
+        let mut data = vec![];
+        for kind in 0..=4 {
+            data.extend(structure(kind, kind as u16, &[], &[]));
+        }
+        data.extend(structure(
+            7,
+            0x0700,
+            &[
+                1, 0x80, 0x01, 0x00, 0x02, 0x00, 0x02, 0x02, 0x00, 0x02, 0x00, 0, 5, 5, 8,
+            ],
+            &["L2 Cache"],
+        ));
+        data.extend(structure(
+            8,
+            0x0800,
+            &[1, 0, 2, 0x12, 0x10],
+            &["J1", "USB1"],
+        ));
+        data.extend(structure(
+            9,
+            0x0900,
+            &[1, 0xB6, 0x0D, 0x04, 0x04, 1, 0, 0x04, 0x01, 0, 0, 1, 0x08],
+            &["PCIE1"],
+        ));
+        data.extend(structure(
+            16,
+            0x1000,
+            &[3, 3, 3, 0x00, 0x00, 0x00, 0x02, 0xFE, 0xFF, 2, 0],
+            &[],
+        ));
+        data.extend(memory_device(0x1100, 8192));
+        data.extend(memory_device(0x1101, 0));
+        data.extend(structure(127, 0xFFFF, &[], &[]));
+        SMBiosData::from_vec_and_version(data, None)
+    }
+
+    #[test]
+    fn json_round_trip_test() {
+        let table = DMITable::new_from_table(&populated_table()).unwrap();
+        let json = table.to_json().unwrap();
+        let imported: DMITable = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&table).unwrap(),
+            serde_json::to_value(&imported).unwrap()
+        );
+
+        let devices = &imported.mem_devices.memory;
+        assert!(devices[0].is_populated());
+        assert!(!devices[1].is_populated());
+        assert_eq!(
+            total_installed(&imported.mem_devices),
+            8 * 1024 * 1024 * 1024
+        );
+        assert!(imported.caches.caches[0].installed_size.is_some());
+        assert!(imported.slots.slots[0].system_slot_type.is_some());
+    }
+}
@@ -73,31 +73,39 @@
 //! | 13 | Vertical image size, mm, ...                           |
 //! | ... | ...                                                   |
 
-use crate::traits::ToJson;
+use crate::traits::{ToJson, ToPlainText, print_opt_val, print_val};
+use crate::utils::Size;
 use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::Display,
-    fs::{read, read_dir, read_to_string},
+    fs::{read, read_dir, read_link, read_to_string},
     path::Path,
 };
 
 /// Information about video devices
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Video {
     pub devices: Vec<DRM>,
+
+    /// Information about video cards
+    pub cards: Vec<GPU>,
 }
 
 impl Video {
     pub fn new() -> Result<Self> {
         let prefix = Path::new("/sys/class/drm/");
         let mut devices = vec![];
+        let mut cards = vec![];
 
         for i in 0..=u8::MAX {
             let path = prefix.join(format!("card{i}"));
             if !path.is_dir() {
                 continue;
             }
+            cards.push(GPU::new(&path));
+
             let dir_contents = read_dir(path)?.filter(|dir| match &dir {
                 Ok(dir) => dir.path().is_dir(),
                 Err(_) => false,
@@ -115,14 +123,98 @@ impl Video {
                 }
             }
         }
-        Ok(Self { devices })
+        Ok(Self { devices, cards })
     }
 }
 
 impl ToJson for Video {}
 
+impl ToPlainText for Video {
+    fn to_plain(&self) -> String {
+        let mut s = String::from("\nInformation about video");
+        for card in &self.cards {
+            s += &card.to_plain();
+        }
+        for (i, dev) in self.devices.iter().enumerate() {
+            s += &format!("\nScreen #{i}\n");
+            s += &dev.to_plain();
+        }
+        s
+    }
+}
+
+/// Information about video card
+///
+/// All fields are optional since different drivers (`amdgpu`,
+/// `i915`, `nouveau`, `virtio`...) expose different files
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GPU {
+    /// Card name (e.g. `card0`)
+    pub name: String,
+
+    /// Kernel driver
+    pub driver: Option<String>,
+
+    /// PCI ID in `vendor:device` format
+    pub pci_id: Option<String>,
+
+    /// Total amount of video memory in bytes
+    pub vram_total: Option<u64>,
+}
+
+impl GPU {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref();
+        let device = path.join("device");
+
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let driver = read_link(device.join("driver"))
+            .ok()
+            .and_then(|drv| drv.file_name().map(|n| n.to_string_lossy().to_string()));
+        let read_id = |f: &str| {
+            read_to_string(device.join(f))
+                .ok()
+                .map(|id| id.trim().trim_start_matches("0x").to_string())
+        };
+        let pci_id = match (read_id("vendor"), read_id("device")) {
+            (Some(vendor), Some(device)) => Some(format!("{vendor}:{device}")),
+            _ => None,
+        };
+        let vram_total = read_to_string(device.join("mem_info_vram_total"))
+            .ok()
+            .and_then(|v| v.trim().parse().ok());
+
+        Self {
+            name,
+            driver,
+            pci_id,
+            vram_total,
+        }
+    }
+}
+
+impl ToJson for GPU {}
+
+impl ToPlainText for GPU {
+    fn to_plain(&self) -> String {
+        let mut s = format!("\nVideo card \"{}\"\n", &self.name);
+        s += &print_opt_val("Driver", &self.driver);
+        s += &print_opt_val("PCI ID", &self.pci_id);
+        s += &print_opt_val(
+            "VRAM",
+            &self.vram_total.and_then(|vram| Size::B(vram).round(2)),
+        );
+        s
+    }
+}
+
 /// Information about selected display
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DRM {
     /// Is enabled
     pub enabled: bool,
@@ -132,6 +224,35 @@ pub struct DRM {
 
     /// Supported modes of this screen (in HxV format)
     pub modes: Vec<String>,
+
+    /// Current (preferred) mode of this screen. `None` if the
+    /// connector has no modelist
+    ///
+    /// > **NOTE:** sysfs doesn't provide the mode which is set by the
+    /// > compositor, so the preferred mode (the first one in the
+    /// > `modes` file) is used
+    pub current_mode: Option<Mode>,
+
+    /// Parsed list of supported modes without duplicates
+    pub available_modes: Vec<Mode>,
+}
+
+impl ToPlainText for DRM {
+    fn to_plain(&self) -> String {
+        let mut s = print_val("Enabled", &self.enabled);
+        s += &print_opt_val("Current mode", &self.current_mode);
+        if let Some(edid) = &self.edid {
+            s += &print_val("Manufacturer", &edid.manufacturer);
+            s += &print_opt_val("Model", &edid.product_name);
+            s += &print_opt_val("Serial number", &edid.serial_string);
+            s += &print_val("Year of manufacture", &edid.year);
+            s += &print_opt_val(
+                "Diagonal (inches)",
+                &edid.diagonal_inches().map(|d| format!("{d:.1}")),
+            );
+        }
+        s
+    }
 }
 
 impl DRM {
@@ -153,6 +274,22 @@ impl DRM {
             .collect::<Vec<_>>();
         let edid = EDID::new(path);
 
+        let mut available_modes: Vec<Mode> = vec![];
+        for mode in modes.iter().filter_map(|m| Mode::parse(m)) {
+            if !available_modes.contains(&mode) {
+                available_modes.push(mode);
+            }
+        }
+        let current_mode = available_modes.first().map(|mode| {
+            let refresh = edid
+                .as_ref()
+                .ok()
+                .and_then(|edid| edid.preferred_mode)
+                .filter(|pm| pm.width == mode.width && pm.height == mode.height)
+                .and_then(|pm| pm.refresh);
+            Mode { refresh, ..*mode }
+        });
+
         Ok(Self {
             enabled,
             edid: match edid {
@@ -167,14 +304,55 @@ impl DRM {
                 }
             },
             modes,
+            current_mode,
+            available_modes,
         })
     }
 }
 
+/// Display mode
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Mode {
+    /// Horizontal resolution, pixels
+    pub width: u32,
+
+    /// Vertical resolution, pixels
+    pub height: u32,
+
+    /// Refresh rate, Hz
+    pub refresh: Option<f32>,
+}
+
+impl Mode {
+    /// Parses the mode from `modes` file (e.g. `1920x1080` or
+    /// `1920x1080i`)
+    pub fn parse(s: &str) -> Option<Self> {
+        let (w, h) = s.trim().split_once('x')?;
+        let h = h.trim_end_matches(|c: char| !c.is_ascii_digit());
+
+        Some(Self {
+            width: w.parse().ok()?,
+            height: h.parse().ok()?,
+            refresh: None,
+        })
+    }
+}
+
+impl Display for Mode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.refresh {
+            Some(refresh) => write!(f, "{}x{} @ {refresh:.2} Hz", self.width, self.height),
+            None => write!(f, "{}x{}", self.width, self.height),
+        }
+    }
+}
+
 /// Information from `edid` file (EDID v1.4 only supported yet)
 ///
 /// Read [Wikipedia](https://en.wikipedia.org/wiki/Extended_Display_Identification_Data) for details.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct EDID {
     //  NAME          TYPE       BYTES
     /// Manufacturer ID. This is a legacy Plug and Play ID assigned
@@ -216,6 +394,24 @@ pub struct EDID {
 
     /// Display gamma, factory default
     pub display_gamma: u8, // 23
+
+    /// Physical width of the image in millimetres (from the first
+    /// detailed timing descriptor)
+    pub width_mm: Option<u16>, // 54-71
+
+    /// Physical height of the image in millimetres (from the first
+    /// detailed timing descriptor)
+    pub height_mm: Option<u16>, // 54-71
+
+    /// Display product name (descriptor `FC`)
+    pub product_name: Option<String>, // 54-125
+
+    /// Display serial number (descriptor `FF`)
+    pub serial_string: Option<String>, // 54-125
+
+    /// Preferred display mode (from the first detailed timing
+    /// descriptor)
+    pub preferred_mode: Option<Mode>, // 54-71
 }
 
 impl EDID {
@@ -248,6 +444,29 @@ impl EDID {
         let vscreen_size = data[22];
         let display_gamma = data[23];
 
+        let mut width_mm = None;
+        let mut height_mm = None;
+        let mut product_name = None;
+        let mut serial_string = None;
+        let mut preferred_mode = None;
+        // Only the base block is parsed; extension blocks are skipped
+        for desc in data[54..126].chunks_exact(18) {
+            if desc[0] != 0 || desc[1] != 0 {
+                // Detailed timing descriptor
+                if width_mm.is_none() {
+                    width_mm = Some(desc[12] as u16 | ((desc[14] as u16 & 0xF0) << 4));
+                    height_mm = Some(desc[13] as u16 | ((desc[14] as u16 & 0x0F) << 8));
+                    preferred_mode = timing_mode(desc);
+                }
+                continue;
+            }
+            match desc[3] {
+                0xFC => product_name = descriptor_text(&desc[5..18]),
+                0xFF => serial_string = descriptor_text(&desc[5..18]),
+                _ => {}
+            }
+        }
+
         Ok(Self {
             manufacturer,
             product_code,
@@ -260,12 +479,69 @@ impl EDID {
             hscreen_size,
             vscreen_size,
             display_gamma,
+            width_mm: width_mm.filter(|w| *w > 0),
+            height_mm: height_mm.filter(|h| *h > 0),
+            product_name,
+            serial_string,
+            preferred_mode,
         })
     }
+
+    /// Returns the screen diagonal in inches
+    pub fn diagonal_inches(&self) -> Option<f32> {
+        let (w, h) = match (self.width_mm, self.height_mm) {
+            (Some(w), Some(h)) => (w as f32, h as f32),
+            _ => (
+                self.hscreen_size as f32 * 10.,
+                self.vscreen_size as f32 * 10.,
+            ),
+        };
+        if w == 0. || h == 0. {
+            return None;
+        }
+        Some((w * w + h * h).sqrt() / 25.4)
+    }
+}
+
+/// Reads the mode from detailed timing descriptor
+fn timing_mode(desc: &[u8]) -> Option<Mode> {
+    let pixel_clock = u16::from_le_bytes([desc[0], desc[1]]) as f32 * 10_000.;
+    let hactive = desc[2] as u32 | ((desc[4] as u32 & 0xF0) << 4);
+    let hblank = desc[3] as u32 | ((desc[4] as u32 & 0x0F) << 8);
+    let vactive = desc[5] as u32 | ((desc[7] as u32 & 0xF0) << 4);
+    let vblank = desc[6] as u32 | ((desc[7] as u32 & 0x0F) << 8);
+
+    let total = (hactive + hblank) * (vactive + vblank);
+    if hactive == 0 || vactive == 0 || total == 0 {
+        return None;
+    }
+
+    Some(Mode {
+        width: hactive,
+        height: vactive,
+        refresh: Some(pixel_clock / total as f32),
+    })
+}
+
+/// Reads text from the display descriptor. Text is terminated with
+/// `0x0A` and padded with spaces
+fn descriptor_text(data: &[u8]) -> Option<String> {
+    let text = data
+        .iter()
+        .take_while(|c| **c != 0x0A)
+        .map(|c| *c as char)
+        .collect::<String>();
+    let text = text.trim();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text.to_string())
+    }
 }
 
 /// Video input parameters bitmap
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum VideoInputParams {
     Digital(VideoInputParamsDigital),
     Analog(VideoInputParamsAnalog),
@@ -287,6 +563,7 @@ impl VideoInputParams {
 
 /// Digital input
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct VideoInputParamsDigital {
     /// Bit depth
     pub bit_depth: BitDepth,
@@ -310,6 +587,7 @@ impl VideoInputParamsDigital {
 
 /// Bit depth
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum BitDepth {
     Undefined,
 
@@ -376,6 +654,7 @@ impl Display for BitDepth {
 
 /// Video interface (EDID data may be incorrect)
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum VideoInterface {
     Undefined,
     DVI,
@@ -419,6 +698,7 @@ impl Display for VideoInterface {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct VideoInputParamsAnalog {
     /// Video white and sync levels, relative to blank:
     ///
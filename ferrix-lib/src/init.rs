@@ -20,17 +20,23 @@
 
 //! Get information about `systemd` services
 
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display, future::Future, pin::pin, thread, time::Duration};
 
 use anyhow::Result;
-use serde::Serialize;
+use futures::{
+    StreamExt,
+    channel::oneshot,
+    future::{Either, select},
+};
+use serde::{Deserialize, Serialize};
 pub use zbus::{Connection, zvariant::OwnedObjectPath};
-use zbus_systemd::systemd1::ManagerProxy;
+use zbus_systemd::systemd1::{ManagerProxy, UnitProxy};
 
 use crate::traits::*;
 
 /// A structure containing information about `systemd` services
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SystemdServices {
     pub units: Vec<ServiceInfo>,
 }
@@ -38,18 +44,335 @@ pub struct SystemdServices {
 impl SystemdServices {
     pub async fn new_from_connection(conn: &Connection) -> Result<Self> {
         let mgr = ManagerProxy::new(conn).await?;
+        let unit_files = unit_files(&mgr).await;
         let mut units = vec![];
 
         for unit in mgr.list_units().await? {
-            units.push(ServiceInfo::from(unit));
+            let mut unit = ServiceInfo::from(unit);
+            if let Some((path, state)) = unit_file(&unit_files, &unit.name) {
+                unit.fragment_path = Some(path.clone());
+                unit.unit_file_state = Some(state.clone());
+            }
+            units.push(unit);
         }
 
         Ok(Self { units })
     }
+
+    /// Returns the number of units in each active state (e.g. `active`,
+    /// `failed`, etc.)
+    pub fn count_by_active_state(&self) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        for unit in &self.units {
+            *counts
+                .entry(unit.active_state.as_str().to_string())
+                .or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Returns a list of failed units
+    pub fn failed(&self) -> Vec<&ServiceInfo> {
+        self.units
+            .iter()
+            .filter(|unit| matches!(unit.active_state, ActiveState::Failed))
+            .collect()
+    }
+
+    /// Returns units whose name or description contains `substring` (case
+    /// insensitive) and whose active or work state equals `state`
+    pub fn filter(&self, substring: &str, state: Option<&str>) -> Vec<&ServiceInfo> {
+        let substring = substring.to_lowercase();
+        self.units
+            .iter()
+            .filter(|unit| {
+                substring.is_empty()
+                    || unit.name.to_lowercase().contains(&substring)
+                    || unit.description.to_lowercase().contains(&substring)
+            })
+            .filter(|unit| match state {
+                Some(state) => {
+                    unit.active_state.as_str() == state || unit.work_state.as_str() == state
+                }
+                None => true,
+            })
+            .collect()
+    }
+
+    /// Starts the selected unit and waits until its job is finished
+    pub async fn start_unit(conn: &Connection, name: &str) -> Result<(), UnitActionError> {
+        unit_action(conn, name, UnitAction::Start).await
+    }
+
+    /// Stops the selected unit and waits until its job is finished
+    pub async fn stop_unit(conn: &Connection, name: &str) -> Result<(), UnitActionError> {
+        unit_action(conn, name, UnitAction::Stop).await
+    }
+
+    /// Restarts the selected unit and waits until its job is finished
+    pub async fn restart_unit(conn: &Connection, name: &str) -> Result<(), UnitActionError> {
+        unit_action(conn, name, UnitAction::Restart).await
+    }
+}
+
+/// Action performed on the `systemd` unit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitAction {
+    Start,
+    Stop,
+    Restart,
+}
+
+impl Display for UnitAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Start => "start",
+                Self::Stop => "stop",
+                Self::Restart => "restart",
+            }
+        )
+    }
+}
+
+/// Errors that may occur while changing the state of the unit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnitActionError {
+    /// The request was denied by PolicyKit
+    PermissionDenied,
+    /// The selected unit doesn't exist
+    NoSuchUnit(String),
+    /// The job was finished with result other than `done`
+    JobFailed { unit: String, result: String },
+    /// Other D-Bus error
+    DBus(String),
+}
+
+impl Display for UnitActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PermissionDenied => write!(f, "Permission denied"),
+            Self::NoSuchUnit(unit) => write!(f, "No such unit: {unit}"),
+            Self::JobFailed { unit, result } => write!(f, "Job for {unit} failed: {result}"),
+            Self::DBus(why) => write!(f, "D-Bus error: {why}"),
+        }
+    }
+}
+
+impl std::error::Error for UnitActionError {}
+
+impl From<zbus::Error> for UnitActionError {
+    fn from(value: zbus::Error) -> Self {
+        match &value {
+            zbus::Error::MethodError(name, msg, _) => match name.as_str() {
+                "org.freedesktop.DBus.Error.AccessDenied"
+                | "org.freedesktop.DBus.Error.InteractiveAuthorizationRequired" => {
+                    Self::PermissionDenied
+                }
+                "org.freedesktop.systemd1.NoSuchUnit" => {
+                    Self::NoSuchUnit(msg.clone().unwrap_or_default())
+                }
+                _ => Self::DBus(value.to_string()),
+            },
+            zbus::Error::FDO(err) => match err.as_ref() {
+                zbus::fdo::Error::AccessDenied(_)
+                | zbus::fdo::Error::InteractiveAuthorizationRequired(_) => Self::PermissionDenied,
+                _ => Self::DBus(value.to_string()),
+            },
+            _ => Self::DBus(value.to_string()),
+        }
+    }
+}
+
+async fn unit_action(
+    conn: &Connection,
+    name: &str,
+    action: UnitAction,
+) -> Result<(), UnitActionError> {
+    let mgr = ManagerProxy::new(conn).await?;
+
+    // systemd sends `JobRemoved` signals only to subscribed clients. The
+    // call fails if we have already been subscribed, so ignore its result.
+    let _ = mgr.subscribe().await;
+    let mut removed = mgr.receive_job_removed().await?;
+
+    let unit = name.to_string();
+    let mode = "replace".to_string();
+    let job = match action {
+        UnitAction::Start => mgr.start_unit(unit.clone(), mode).await?,
+        UnitAction::Stop => mgr.stop_unit(unit.clone(), mode).await?,
+        UnitAction::Restart => mgr.restart_unit(unit.clone(), mode).await?,
+    };
+
+    while let Some(signal) = removed.next().await {
+        let args = signal.args()?;
+        if args.job() != &job {
+            continue;
+        }
+
+        return match args.result().as_str() {
+            "done" | "skipped" => Ok(()),
+            result => Err(UnitActionError::JobFailed {
+                unit,
+                result: result.to_string(),
+            }),
+        };
+    }
+
+    Err(UnitActionError::DBus(format!(
+        "JobRemoved stream was closed before the {action} job for {unit} finished"
+    )))
 }
 
 impl ToJson for SystemdServices {}
 
+/// Number of attempts made by [`connect_with_timeout()`]
+pub const CONNECT_ATTEMPTS: u32 = 3;
+
+/// D-Bus message bus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bus {
+    /// Per-user bus (user services)
+    Session,
+    /// System-wide bus (system services)
+    System,
+}
+
+/// The D-Bus daemon (and so `systemd`) isn't reachable. Usually it
+/// happens on early boot or in containers without D-Bus
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusUnavailable(pub String);
+
+impl Display for BusUnavailable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "systemd bus not reachable: {}", self.0)
+    }
+}
+
+impl std::error::Error for BusUnavailable {}
+
+/// Connects to the `bus`. Every attempt is cancelled after `timeout`
+/// and failed attempts are retried up to [`CONNECT_ATTEMPTS`] times
+pub async fn connect_with_timeout(
+    bus: Bus,
+    timeout: Duration,
+) -> Result<Connection, BusUnavailable> {
+    let mut why = String::new();
+    for attempt in 1..=CONNECT_ATTEMPTS {
+        let conn = match bus {
+            Bus::Session => with_timeout(Connection::session(), timeout).await,
+            Bus::System => with_timeout(Connection::system(), timeout).await,
+        };
+        match conn {
+            Some(Ok(conn)) => return Ok(conn),
+            Some(Err(err)) => why = err.to_string(),
+            None => why = format!("connection timed out after {timeout:?}"),
+        }
+        if attempt < CONNECT_ATTEMPTS {
+            sleep(timeout / 4 * attempt).await;
+        }
+    }
+    Err(BusUnavailable(why))
+}
+
+/// Returns `None` if the future isn't finished in `timeout`
+async fn with_timeout<F: Future>(fut: F, timeout: Duration) -> Option<F::Output> {
+    match select(pin!(fut), pin!(sleep(timeout))).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
+
+/// Runtime-agnostic timer: the library doesn't depend on any async
+/// runtime, so the waiting is done in a separate thread
+async fn sleep(dur: Duration) {
+    let (tx, rx) = oneshot::channel();
+    thread::spawn(move || {
+        thread::sleep(dur);
+        let _ = tx.send(());
+    });
+    let _ = rx.await;
+}
+
+/// Boot time breakdown (like `systemd-analyze time`)
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BootTimes {
+    /// Time spent in the firmware. `None` if it isn't reported (e.g.
+    /// on BIOS systems or in virtual machines)
+    pub firmware: Option<Duration>,
+
+    /// Time spent in the boot loader. `None` if it isn't reported
+    pub loader: Option<Duration>,
+
+    /// Time from the kernel start to the initrd or to the `systemd`
+    /// start if initrd isn't used
+    pub kernel: Duration,
+
+    /// Time spent in the initrd. `None` if initrd isn't used
+    pub initrd: Option<Duration>,
+
+    /// Time from the `systemd` start to the end of the boot. `None`
+    /// if the system is still booting
+    pub userspace: Option<Duration>,
+}
+
+impl BootTimes {
+    pub async fn new(conn: &Connection) -> Result<Self> {
+        let mgr = ManagerProxy::new(conn).await?;
+        Ok(Self::from_monotonic(
+            mgr.firmware_timestamp_monotonic().await?,
+            mgr.loader_timestamp_monotonic().await?,
+            mgr.kernel_timestamp_monotonic().await?,
+            mgr.init_rd_timestamp_monotonic().await?,
+            mgr.userspace_timestamp_monotonic().await?,
+            mgr.finish_timestamp_monotonic().await?,
+        ))
+    }
+
+    /// Calculates durations from the `*TimestampMonotonic` properties
+    /// of the `systemd` manager (in microseconds). Firmware and loader
+    /// timestamps are counted back from the kernel start; `0` means
+    /// that the stage isn't reported
+    pub fn from_monotonic(
+        firmware: u64,
+        loader: u64,
+        kernel: u64,
+        initrd: u64,
+        userspace: u64,
+        finish: u64,
+    ) -> Self {
+        let us = Duration::from_micros;
+        let reported = |ts: u64| (ts > 0).then_some(ts);
+
+        // The kernel stage ends when the initrd (if any) is started
+        let kernel_end = reported(initrd).unwrap_or(userspace);
+        Self {
+            firmware: reported(firmware).map(|fw| us(fw.saturating_sub(loader))),
+            loader: reported(loader).map(us),
+            kernel: us(kernel_end.saturating_sub(kernel)),
+            initrd: reported(initrd).map(|initrd| us(userspace.saturating_sub(initrd))),
+            userspace: reported(finish).map(|finish| us(finish.saturating_sub(userspace))),
+        }
+    }
+
+    /// Total boot time. `None` if the system is still booting
+    pub fn total(&self) -> Option<Duration> {
+        let userspace = self.userspace?;
+        Some(
+            self.firmware.unwrap_or_default()
+                + self.loader.unwrap_or_default()
+                + self.kernel
+                + self.initrd.unwrap_or_default()
+                + userspace,
+        )
+    }
+}
+
+impl ToJson for BootTimes {}
+
 impl ToPlainText for SystemdServices {
     fn to_plain(&self) -> String {
         let mut s = format!("\nSystemd services list:");
@@ -61,6 +384,30 @@ impl ToPlainText for SystemdServices {
     }
 }
 
+type UnitFiles = HashMap<String, (String, String)>;
+
+/// Reads paths and states of all unit files with a single D-Bus call
+/// instead of querying `FragmentPath` and `UnitFileState` for each unit
+async fn unit_files(mgr: &ManagerProxy<'_>) -> UnitFiles {
+    let mut files = HashMap::new();
+    for (path, state) in mgr.list_unit_files().await.unwrap_or_default() {
+        if let Some(name) = path.rsplit('/').next() {
+            files.insert(name.to_string(), (path.clone(), state));
+        }
+    }
+    files
+}
+
+fn unit_file<'a>(files: &'a UnitFiles, name: &str) -> Option<&'a (String, String)> {
+    files.get(name).or_else(|| {
+        // Instantiated units (`getty@tty1.service`) are defined by the
+        // template unit file (`getty@.service`)
+        let (prefix, _) = name.split_once('@')?;
+        let (_, suffix) = name.rsplit_once('.')?;
+        files.get(&format!("{prefix}@.{suffix}"))
+    })
+}
+
 fn unescape(s: &str) -> String {
     s.replace("\\x20", " ")
         .replace("\\x5c", "\\")
@@ -81,7 +428,8 @@ type ServiceTuple = (
     OwnedObjectPath,
 );
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ServiceInfo {
     /// Unit name (e.g. `hibernate.target`)
     pub name: String,
@@ -106,6 +454,31 @@ pub struct ServiceInfo {
 
     /// Unit type
     pub unit_type: UnitType,
+
+    /// Path to the unit file (e.g. `/usr/lib/systemd/system/sshd.service`)
+    pub fragment_path: Option<String>,
+
+    /// Unit file state (e.g. `enabled`, `disabled`, `masked`)
+    pub unit_file_state: Option<String>,
+}
+
+impl ServiceInfo {
+    /// Reads `FragmentPath` and `UnitFileState` properties of the unit
+    /// from `systemd` itself. Unlike the list of unit files used by
+    /// [`SystemdServices::new_from_connection`], this also covers units
+    /// whose file name differs from the unit name (aliases, drop-ins),
+    /// but costs several D-Bus calls, so it's meant for a single unit
+    pub async fn read_unit_file(&mut self, conn: &Connection) -> Result<()> {
+        let mgr = ManagerProxy::new(conn).await?;
+        let proxy = UnitProxy::new(conn, mgr.get_unit(self.name.clone()).await?).await?;
+
+        // Units without a unit file (e.g. devices and scopes) report
+        // empty values
+        let non_empty = |val: String| (!val.is_empty()).then_some(val);
+        self.fragment_path = non_empty(proxy.fragment_path().await?);
+        self.unit_file_state = non_empty(proxy.unit_file_state().await?);
+        Ok(())
+    }
 }
 
 impl ToPlainText for ServiceInfo {
@@ -118,6 +491,8 @@ impl ToPlainText for ServiceInfo {
         s += &print_val("Daemon path", &self.daemon_path);
         s += &print_val("Job ID", &self.job_id);
         s += &print_val("Unit type", &self.unit_type);
+        s += &print_opt_val("Unit file", &self.fragment_path);
+        s += &print_opt_val("Unit file state", &self.unit_file_state);
 
         s
     }
@@ -136,11 +511,14 @@ impl From<ServiceTuple> for ServiceInfo {
             daemon_path: unescape(&value.5),
             job_id: value.7,
             unit_type: UnitType::from(&value.8),
+            fragment_path: None,
+            unit_file_state: None,
         }
     }
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum LoadState {
     Loaded,
     Stub,
@@ -177,7 +555,8 @@ impl From<&String> for LoadState {
     }
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ActiveState {
     Active,
     Inactive,
@@ -187,6 +566,20 @@ pub enum ActiveState {
     Unknown(String),
 }
 
+impl ActiveState {
+    /// Returns the state name as reported by `systemd`
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Active => "active",
+            Self::Inactive => "inactive",
+            Self::Activating => "activating",
+            Self::Deactivating => "deactivating",
+            Self::Failed => "failed",
+            Self::Unknown(state) => state,
+        }
+    }
+}
+
 impl Display for ActiveState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -217,7 +610,8 @@ impl From<&String> for ActiveState {
     }
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum WorkState {
     Active,
     Running,
@@ -232,6 +626,25 @@ pub enum WorkState {
     Unknown(String),
 }
 
+impl WorkState {
+    /// Returns the state name as reported by `systemd`
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Active => "active",
+            Self::Running => "running",
+            Self::Exited => "exited",
+            Self::Dead => "dead",
+            Self::Mounted => "mounted",
+            Self::Mounting => "mounting",
+            Self::Plugged => "plugged",
+            Self::Listening => "listening",
+            Self::Waiting => "waiting",
+            Self::Failed => "failed",
+            Self::Unknown(state) => state,
+        }
+    }
+}
+
 impl Display for WorkState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -272,7 +685,8 @@ impl From<&String> for WorkState {
     }
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum UnitType {
     Target,
     Service,
@@ -311,3 +725,40 @@ impl From<&String> for UnitType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boot_times_test() {
+        let times =
+            BootTimes::from_monotonic(8_000_000, 3_000_000, 0, 1_500_000, 4_000_000, 10_000_000);
+        assert_eq!(times.firmware, Some(Duration::from_secs(5)));
+        assert_eq!(times.loader, Some(Duration::from_secs(3)));
+        assert_eq!(times.kernel, Duration::from_millis(1500));
+        assert_eq!(times.initrd, Some(Duration::from_millis(2500)));
+        assert_eq!(times.userspace, Some(Duration::from_secs(6)));
+        assert_eq!(times.total(), Some(Duration::from_secs(18)));
+
+        // No firmware/loader timestamps, no initrd and still booting
+        let times = BootTimes::from_monotonic(0, 0, 0, 0, 2_000_000, 0);
+        assert_eq!(times.firmware, None);
+        assert_eq!(times.kernel, Duration::from_secs(2));
+        assert_eq!(times.initrd, None);
+        assert_eq!(times.total(), None);
+    }
+
+    #[test]
+    fn with_timeout_test() {
+        use futures::executor::block_on;
+
+        let ready = block_on(with_timeout(async { 42 }, Duration::from_secs(1)));
+        assert_eq!(ready, Some(42));
+        let pending = block_on(with_timeout(
+            futures::future::pending::<()>(),
+            Duration::from_millis(10),
+        ));
+        assert_eq!(pending, None);
+    }
+}
@@ -32,6 +32,7 @@
 //! let json_str = data.to_json()?; // get machine-readable JSON from this data
 //! let pjson_str = data.to_json_pretty()?; // get human-readable JSON
 //! let xml_str = data.to_xml()?; // get XML
+//! let yaml_str = data.to_yaml()?; // get YAML
 //! ```
 //!
 //! Get information about CPU:
@@ -42,16 +43,60 @@
 //! let json_str = data.to_json()?;
 //! let pjson_str = data.to_json_pretty()?;
 //! ```
+//!
+//! Watch for changes of CPU and RAM every second:
+//! ```no-test
+//! use ferrix_lib::{UpdateScope, watch};
+//! use futures::StreamExt;
+//! use std::time::Duration;
+//!
+//! let mut snapshots = watch(UpdateScope::CPU | UpdateScope::RAM, Duration::from_secs(1));
+//! while let Some(data) = snapshots.next().await {
+//!     match data {
+//!         Ok(data) => println!("{:?}", data.ram.used_ram(1)),
+//!         Err(why) => eprintln!("{why}"),
+//!     }
+//! }
+//! ```
+//!
+//! ## Cargo features
+//! All features are enabled by default:
+//!
+//! - `dmi` — SMBIOS/DMI tables (the [`dmi`] module; pulls `smbios-lib`);
+//! - `drm` — video cards and screens (the [`drm`] module);
+//! - `systemd` — `systemd` services over D-Bus (the [`init`] module;
+//!   pulls `zbus`).
+//!
+//! The `dbus` feature (the [`dbus`] module: a session bus service
+//! serving all data), the `metrics-http` feature (HTTP server for
+//! the [`metrics`] module) and the `schema` feature (the [`schema()`]
+//! function; pulls `schemars`) are disabled by default.
+//!
+//! The `cpu`, `ram`, `sys`, `vmstat` and other `/proc`- and
+//! `/sys`-based modules are always available. Use
+//! `default-features = false` to get only them.
 
 pub mod battery;
 pub mod cpu;
 pub mod cpu_freq;
+#[cfg(feature = "dbus")]
+pub mod dbus;
+pub mod devtools;
+pub mod diff;
+#[cfg(feature = "dmi")]
 pub mod dmi;
+#[cfg(feature = "drm")]
 pub mod drm;
+#[cfg(feature = "systemd")]
 pub mod init;
+pub mod metrics;
+pub mod net;
 pub mod parts;
+pub mod proc;
 pub mod ram;
+pub mod sensors;
 pub mod soft;
+pub mod summary;
 pub mod sys;
 pub mod vmstat;
 pub mod vulnerabilities;
@@ -59,57 +104,196 @@ pub mod vulnerabilities;
 pub mod traits;
 pub mod utils;
 
-use crate::traits::ToPlainText;
-use anyhow::Result;
-use serde::Serialize;
+use crate::traits::{ToMarkdown, ToPlainText};
+use anyhow::{Result, anyhow};
+use futures::{
+    Stream,
+    channel::mpsc::{self, UnboundedSender},
+    executor::block_on,
+};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "dmi")]
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
 pub const FX_LIB_VERSION: &str = env!("CARGO_PKG_VERSION");
 
-#[derive(Debug, Serialize)]
+/// Version of the serialized data format. It's bumped whenever fields
+/// of the serialized structures are added, removed or changed
+pub const FX_SCHEMA_VERSION: u32 = 2;
+
+/// Version marker of the serialized data (the `_ferrix` field)
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FormatVersion {
+    /// Version of `ferrix-lib` ([`FX_LIB_VERSION`])
+    pub version: String,
+
+    /// Version of the data format ([`FX_SCHEMA_VERSION`])
+    pub schema: u32,
+}
+
+impl Default for FormatVersion {
+    fn default() -> Self {
+        Self {
+            version: FX_LIB_VERSION.to_string(),
+            schema: FX_SCHEMA_VERSION,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Ferrix {
+    #[serde(rename = "_ferrix")]
+    pub format: FormatVersion,
     pub cpu: cpu::Processors,
+    /// Usage of every CPU core in percents (see
+    /// [`cpu::Stat::sample_usage()`])
+    #[serde(default)]
+    pub cpu_usage: Vec<f32>,
     pub ram: ram::RAM,
     pub swaps: ram::Swaps,
-    pub dmi: dmi::DMITable,
+    /// DMI table. It's collected on a best-effort basis because
+    /// reading it requires root privileges
+    #[cfg(feature = "dmi")]
+    #[serde(default)]
+    pub dmi: Option<Arc<dmi::DMITable>>,
+    #[cfg(feature = "drm")]
     pub drm: drm::Video,
     pub sys: sys::Sys,
+    #[cfg(feature = "systemd")]
     pub init: init::SystemdServices,
 }
 
 impl Ferrix {
     pub async fn new() -> Result<Self> {
-        let conn = zbus::Connection::system().await?;
+        // Blocking collectors are running in separate threads while
+        // we're waiting for the D-Bus data, so gathering everything
+        // is bounded by the slowest source rather than their sum
+        let cpu = collect(cpu::Processors::new);
+        let cpu_usage = collect(cpu::Stat::sample_usage);
+        let ram = collect(ram::RAM::new);
+        let swaps = collect(ram::Swaps::new);
+        #[cfg(feature = "dmi")]
+        let dmi = collect(dmi::DMITable::new);
+        #[cfg(feature = "drm")]
+        let drm = collect(drm::Video::new);
+        let sys = collect(sys::Sys::new);
+
+        #[cfg(feature = "systemd")]
+        let init = {
+            let conn = zbus::Connection::system().await?;
+            init::SystemdServices::new_from_connection(&conn).await
+        };
+
+        // Joining in the same order as before keeps the "first error
+        // wins" semantics
         Ok(Self {
-            cpu: cpu::Processors::new()?,
-            ram: ram::RAM::new()?,
-            swaps: ram::Swaps::new()?,
-            dmi: dmi::DMITable::new()?,
-            drm: drm::Video::new()?,
-            sys: sys::Sys::new()?,
-            init: init::SystemdServices::new_from_connection(&conn).await?,
+            format: FormatVersion::default(),
+            cpu: join(cpu)?,
+            cpu_usage: join(cpu_usage)?,
+            ram: join(ram)?,
+            swaps: join(swaps)?,
+            #[cfg(feature = "dmi")]
+            dmi: join(dmi).ok().map(Arc::new),
+            #[cfg(feature = "drm")]
+            drm: join(drm)?,
+            sys: join(sys)?,
+            #[cfg(feature = "systemd")]
+            init: init?,
         })
     }
 
-    fn _update(&mut self) -> Result<()> {
-        self.cpu = cpu::Processors::new()?;
-        self.ram = ram::RAM::new()?;
-        self.swaps = ram::Swaps::new()?;
-        self.sys.update()?;
+    /// Refreshes the selected sections except `systemd` services
+    /// (which require a D-Bus connection)
+    ///
+    /// Refreshing [`UpdateScope::CPU`] blocks for
+    /// [`cpu::USAGE_SAMPLE_INTERVAL`] to measure the CPU usage
+    pub fn refresh(&mut self, scope: UpdateScope) -> Result<()> {
+        if scope.contains(UpdateScope::CPU) {
+            self.cpu = cpu::Processors::new()?;
+            self.cpu_usage = cpu::Stat::sample_usage()?;
+        }
+        if scope.contains(UpdateScope::RAM) {
+            self.ram = ram::RAM::new()?;
+        }
+        if scope.contains(UpdateScope::SWAPS) {
+            self.swaps = ram::Swaps::new()?;
+        }
+        #[cfg(feature = "dmi")]
+        if scope.contains(UpdateScope::DMI) {
+            self.dmi = dmi::DMITable::new().ok().map(Arc::new);
+        }
+        #[cfg(feature = "drm")]
+        if scope.contains(UpdateScope::DRM) {
+            self.drm = drm::Video::new()?;
+        }
+        if scope.contains(UpdateScope::SYS) {
+            self.sys.update()?;
+        }
+
+        Ok(())
+    }
 
+    /// Refreshes only the selected sections
+    ///
+    /// ## Example
+    /// ```no-test
+    /// use ferrix_lib::{Ferrix, UpdateScope};
+    ///
+    /// let mut data = Ferrix::new().await?;
+    /// data.update_scope(UpdateScope::CPU | UpdateScope::RAM, &conn).await?;
+    /// ```
+    #[cfg(feature = "systemd")]
+    pub async fn update_scope(
+        &mut self,
+        scope: UpdateScope,
+        conn: &zbus::Connection,
+    ) -> Result<()> {
+        self.refresh(scope)?;
+        if scope.contains(UpdateScope::INIT) {
+            self.init = init::SystemdServices::new_from_connection(conn).await?;
+        }
         Ok(())
     }
 
+    /// Refreshes volatile sections (CPU, RAM, swaps, system and
+    /// `systemd` services)
+    #[cfg(feature = "systemd")]
     pub async fn update(&mut self, conn: &zbus::Connection) -> Result<()> {
-        self._update()?;
-        self.init = init::SystemdServices::new_from_connection(&conn).await?;
-        Ok(())
+        self.update_scope(UpdateScope::VOLATILE, conn).await
     }
 
+    /// Refreshes all sections
+    #[cfg(feature = "systemd")]
     pub async fn update1(&mut self) -> Result<()> {
-        self._update()?;
         let conn = zbus::Connection::system().await?;
-        self.init = init::SystemdServices::new_from_connection(&conn).await?;
-        Ok(())
+        self.update_scope(UpdateScope::ALL, &conn).await
+    }
+
+    /// Refreshes all sections
+    #[cfg(not(feature = "systemd"))]
+    pub async fn update1(&mut self) -> Result<()> {
+        self.refresh(UpdateScope::ALL)
+    }
+
+    /// Deserializes the data captured with [`Self::to_json()`] (e.g.
+    /// on another machine)
+    pub fn from_json(json: &str) -> Result<Self> {
+        let value = serde_json::from_str::<serde_json::Value>(json)?;
+        // Documents of other versions may still happen to parse (e.g.
+        // when the changed fields are optional) and silently lose data
+        match value.pointer("/_ferrix/schema").and_then(|s| s.as_u64()) {
+            Some(schema) if schema == FX_SCHEMA_VERSION as u64 => {
+                Ok(serde_json::from_value(value)?)
+            }
+            Some(schema) => Err(anyhow!(
+                "unsupported data format version {schema} (expected {FX_SCHEMA_VERSION})"
+            )),
+            None => Err(anyhow!("data format version isn't specified")),
+        }
     }
 
     /// Performs serialization of structure data in JSON.
@@ -136,13 +320,179 @@ impl Ferrix {
         let data = XMLFerrixData::from(&xml);
         data.to_xml()
     }
+
+    /// Performs data serialization in YAML format
+    pub fn to_yaml(&self) -> Result<String> {
+        Ok(serde_yaml::to_string(&self)?)
+    }
+}
+
+/// Returns the JSON Schema of the [`Ferrix::to_json()`] output
+///
+/// It can be used to validate the captured JSON or to generate
+/// bindings for other languages.
+#[cfg(feature = "schema")]
+pub fn schema() -> String {
+    let schema = schemars::schema_for!(Ferrix);
+    serde_json::to_string_pretty(&schema).unwrap_or_default()
+}
+
+/// Set of [`Ferrix`] sections to refresh
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UpdateScope(u8);
+
+impl UpdateScope {
+    pub const CPU: Self = Self(1 << 0);
+    pub const RAM: Self = Self(1 << 1);
+    pub const SWAPS: Self = Self(1 << 2);
+    pub const DMI: Self = Self(1 << 3);
+    pub const DRM: Self = Self(1 << 4);
+    pub const SYS: Self = Self(1 << 5);
+    pub const INIT: Self = Self(1 << 6);
+
+    /// Sections which data changes at runtime
+    pub const VOLATILE: Self =
+        Self(Self::CPU.0 | Self::RAM.0 | Self::SWAPS.0 | Self::SYS.0 | Self::INIT.0);
+
+    /// All sections
+    pub const ALL: Self = Self(Self::VOLATILE.0 | Self::DMI.0 | Self::DRM.0);
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for UpdateScope {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for UpdateScope {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Periodically refreshes the selected sections and emits snapshots
+///
+/// The first item is a full snapshot collected by [`Ferrix::new()`];
+/// next ones are emitted every `interval` with the `scope` sections
+/// refreshed. An error on one tick is emitted as an `Err` item and
+/// doesn't terminate the stream. Data is collected in a separate
+/// thread which stops when the stream is dropped.
+pub fn watch(scope: UpdateScope, interval: Duration) -> impl Stream<Item = Result<Ferrix>> {
+    let (tx, rx) = mpsc::unbounded();
+    thread::spawn(move || block_on(Watcher::new(scope).run(interval, tx)));
+    rx
+}
+
+struct Watcher {
+    scope: UpdateScope,
+    data: Option<Ferrix>,
+    #[cfg(feature = "systemd")]
+    conn: Option<zbus::Connection>,
+}
+
+impl Watcher {
+    fn new(scope: UpdateScope) -> Self {
+        Self {
+            scope,
+            data: None,
+            #[cfg(feature = "systemd")]
+            conn: None,
+        }
+    }
+
+    async fn run(mut self, interval: Duration, tx: UnboundedSender<Result<Ferrix>>) {
+        loop {
+            let snapshot = self.tick().await;
+            if tx.unbounded_send(snapshot).is_err() {
+                // The stream was dropped
+                break;
+            }
+            thread::sleep(interval);
+        }
+    }
+
+    async fn tick(&mut self) -> Result<Ferrix> {
+        let Some(data) = &mut self.data else {
+            let data = Ferrix::new().await?;
+            self.data = Some(data.clone());
+            return Ok(data);
+        };
+
+        #[cfg(feature = "systemd")]
+        {
+            let conn = match &self.conn {
+                Some(conn) => conn.clone(),
+                None => {
+                    let conn = zbus::Connection::system().await?;
+                    self.conn = Some(conn.clone());
+                    conn
+                }
+            };
+            data.update_scope(self.scope, &conn).await?;
+        }
+        #[cfg(not(feature = "systemd"))]
+        data.refresh(self.scope)?;
+
+        Ok(data.clone())
+    }
+}
+
+fn collect<T: Send + 'static>(f: fn() -> Result<T>) -> JoinHandle<Result<T>> {
+    thread::spawn(f)
+}
+
+fn join<T>(handle: JoinHandle<Result<T>>) -> Result<T> {
+    handle
+        .join()
+        .map_err(|_| anyhow!("Data collector thread panicked"))?
+}
+
+impl ToMarkdown for Ferrix {
+    fn to_markdown(&self) -> String {
+        let mut s = String::from("# Ferrix report\n\n");
+        s += &self.cpu.to_markdown();
+        s += "\n";
+        s += &self.ram.to_markdown();
+        #[cfg(feature = "dmi")]
+        if let Some(dmi) = &self.dmi {
+            s += "\n";
+            s += &dmi.to_markdown();
+        }
+
+        s
+    }
 }
 
 impl ToPlainText for Ferrix {
     fn to_plain(&self) -> String {
         let mut s = format!("");
         s += &self.cpu.to_plain();
-        s += &self.init.to_plain();
+        s += "\n";
+        s += &self.ram.to_plain();
+        #[cfg(feature = "dmi")]
+        if let Some(dmi) = &self.dmi {
+            s += &dmi.to_plain();
+        }
+        #[cfg(feature = "drm")]
+        {
+            s += &self.drm.to_plain();
+        }
+        s += "\n";
+        s += &self.sys.to_plain();
+        #[cfg(feature = "systemd")]
+        {
+            s += &self.init.to_plain();
+        }
 
         s
     }
@@ -155,20 +505,27 @@ struct XMLFerrixData<'a> {
 
 #[derive(Serialize)]
 struct XMLData<'a> {
+    #[serde(rename = "_ferrix")]
+    format: &'a FormatVersion,
     cpu: &'a cpu::Processors,
     ram: &'a ram::RAM,
-    dmi: dmi::DMITableXml<'a>,
+    #[cfg(feature = "dmi")]
+    dmi: Option<dmi::DMITableXml<'a>>,
     sys: &'a sys::Sys,
+    #[cfg(feature = "systemd")]
     init: &'a init::SystemdServices,
 }
 
 impl<'a> From<&'a Ferrix> for XMLData<'a> {
     fn from(value: &'a Ferrix) -> Self {
         Self {
+            format: &value.format,
             cpu: &value.cpu,
             ram: &value.ram,
-            dmi: dmi::DMITableXml::from(&value.dmi),
+            #[cfg(feature = "dmi")]
+            dmi: value.dmi.as_deref().map(dmi::DMITableXml::from),
             sys: &value.sys,
+            #[cfg(feature = "systemd")]
             init: &value.init,
         }
     }
@@ -185,3 +542,23 @@ impl<'a> From<&'a XMLData<'a>> for XMLFerrixData<'a> {
         Self { data: value }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn old_schema_test() {
+        // `swap_type` was renamed to `kind` in the second version of
+        // the format
+        let json = r#"{
+            "_ferrix": { "version": "0.4.0", "schema": 1 },
+            "swaps": { "swaps": [{ "filename": "/dev/sda2", "swap_type": "partition" }] }
+        }"#;
+        let err = Ferrix::from_json(json).unwrap_err().to_string();
+        assert_eq!(err, "unsupported data format version 1 (expected 2)");
+
+        let err = Ferrix::from_json("{}").unwrap_err().to_string();
+        assert_eq!(err, "data format version isn't specified");
+    }
+}
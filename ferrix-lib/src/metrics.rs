@@ -0,0 +1,374 @@
+/* metrics.rs
+ *
+ * Copyright 2026 Michail Krasnov <mskrasnov07@ya.ru>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Export of the collected data as Prometheus metrics (text
+//! exposition format)
+//!
+//! ## Example
+//! ```no-test
+//! use ferrix_lib::metrics::Metrics;
+//!
+//! let ram = RAM::new()?;
+//! let mounts = Mounts::new_physical()?;
+//! let text = Metrics::default()
+//!     .with_ram(&ram)
+//!     .with_mounts(&mounts)
+//!     .render();
+//! ```
+
+use anyhow::Result;
+
+use crate::{
+    Ferrix,
+    battery::BatInfo,
+    cpu::{Processors, Stat},
+    parts::Mounts,
+    ram::{RAM, Swaps},
+};
+
+/// Sources of the metrics. Only gauges of the provided sources are
+/// rendered
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Metrics<'a> {
+    cpu_usage: Option<&'a [f32]>,
+    processors: Option<&'a Processors>,
+    ram: Option<&'a RAM>,
+    swaps: Option<&'a Swaps>,
+    battery: Option<&'a BatInfo>,
+    mounts: Option<&'a Mounts>,
+}
+
+impl<'a> Metrics<'a> {
+    /// Usage of every CPU core in percents
+    pub fn with_cpu_usage(mut self, usage: &'a [f32]) -> Self {
+        self.cpu_usage = Some(usage);
+        self
+    }
+
+    pub fn with_processors(mut self, processors: &'a Processors) -> Self {
+        self.processors = Some(processors);
+        self
+    }
+
+    pub fn with_ram(mut self, ram: &'a RAM) -> Self {
+        self.ram = Some(ram);
+        self
+    }
+
+    pub fn with_swaps(mut self, swaps: &'a Swaps) -> Self {
+        self.swaps = Some(swaps);
+        self
+    }
+
+    pub fn with_battery(mut self, battery: &'a BatInfo) -> Self {
+        self.battery = Some(battery);
+        self
+    }
+
+    pub fn with_mounts(mut self, mounts: &'a Mounts) -> Self {
+        self.mounts = Some(mounts);
+        self
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        if let Some(usage) = self.cpu_usage {
+            let samples = usage
+                .iter()
+                .enumerate()
+                .map(|(core, usage)| (labels(&[("core", &core.to_string())]), *usage as f64));
+            write_gauge(
+                &mut out,
+                "ferrix_cpu_usage_percent",
+                "CPU core usage in percents",
+                samples,
+            );
+        }
+
+        if let Some(processors) = self.processors {
+            let samples = processors.entries.iter().filter_map(|cpu| {
+                let core = cpu.processor?.to_string();
+                Some((labels(&[("core", &core)]), cpu.cpu_mhz? as f64))
+            });
+            write_gauge(
+                &mut out,
+                "ferrix_cpu_frequency_mhz",
+                "Current CPU core frequency",
+                samples,
+            );
+        }
+
+        if let Some(ram) = self.ram {
+            let total = ram.total.get_bytes2().unwrap_or(0);
+            let available = ram.available.get_bytes2().unwrap_or(0);
+            write_gauge(
+                &mut out,
+                "ferrix_memory_total_bytes",
+                "Total usable RAM",
+                [(String::new(), total as f64)],
+            );
+            write_gauge(
+                &mut out,
+                "ferrix_memory_available_bytes",
+                "RAM available for new applications",
+                [(String::new(), available as f64)],
+            );
+            write_gauge(
+                &mut out,
+                "ferrix_memory_used_bytes",
+                "Used RAM",
+                [(String::new(), total.saturating_sub(available) as f64)],
+            );
+        }
+
+        if let Some(swaps) = self.swaps {
+            let samples = swaps.swaps.iter().map(|swap| {
+                (
+                    labels(&[("filename", &swap.filename)]),
+                    swap.used.get_bytes2().unwrap_or(0) as f64,
+                )
+            });
+            write_gauge(
+                &mut out,
+                "ferrix_swap_used_bytes",
+                "Used swap space",
+                samples,
+            );
+        }
+
+        if let Some(percent) = self.battery.and_then(|bat| bat.total_percentage()) {
+            write_gauge(
+                &mut out,
+                "ferrix_battery_percent",
+                "Total charge of all batteries in percents",
+                [(String::new(), percent as f64)],
+            );
+        }
+
+        if let Some(mounts) = self.mounts {
+            let with_stats = || {
+                mounts
+                    .mounts
+                    .iter()
+                    .filter_map(|m| m.fstats.map(|stats| (m, stats)))
+            };
+            write_gauge(
+                &mut out,
+                "ferrix_fs_size_bytes",
+                "Filesystem size",
+                with_stats().map(|(m, stats)| {
+                    (
+                        labels(&[("mount", &m.mount_point), ("device", &m.device)]),
+                        stats.total_bytes() as f64,
+                    )
+                }),
+            );
+            write_gauge(
+                &mut out,
+                "ferrix_fs_used_bytes",
+                "Used space of the filesystem",
+                with_stats().map(|(m, stats)| {
+                    (
+                        labels(&[("mount", &m.mount_point), ("device", &m.device)]),
+                        stats.used_bytes() as f64,
+                    )
+                }),
+            );
+        }
+
+        out
+    }
+}
+
+/// Sources of the sections stored in [`Ferrix`]
+impl<'a> From<&'a Ferrix> for Metrics<'a> {
+    fn from(data: &'a Ferrix) -> Self {
+        Self::default()
+            .with_cpu_usage(&data.cpu_usage)
+            .with_processors(&data.cpu)
+            .with_ram(&data.ram)
+            .with_swaps(&data.swaps)
+    }
+}
+
+/// Renders gauges of the already collected data without reading
+/// anything. Batteries and filesystems aren't stored in [`Ferrix`],
+/// so their gauges are omitted
+pub fn prometheus(data: &Ferrix) -> String {
+    Metrics::from(data).render()
+}
+
+/// Collects fresh data from all supported sources and renders it
+///
+/// Blocks the current thread for
+/// [`USAGE_SAMPLE_INTERVAL`](crate::cpu::USAGE_SAMPLE_INTERVAL) to
+/// take two samples of `/proc/stat`.
+pub fn collect() -> Result<String> {
+    let cpu_usage = Stat::sample_usage()?;
+    let processors = Processors::new()?;
+    let ram = RAM::new()?;
+    let swaps = Swaps::new()?;
+    let mounts = Mounts::new_physical()?;
+    // There is no battery on desktops and servers
+    let battery = BatInfo::new().ok();
+
+    let mut metrics = Metrics::default()
+        .with_cpu_usage(&cpu_usage)
+        .with_processors(&processors)
+        .with_ram(&ram)
+        .with_swaps(&swaps)
+        .with_mounts(&mounts);
+    if let Some(battery) = &battery {
+        metrics = metrics.with_battery(battery);
+    }
+    Ok(metrics.render())
+}
+
+/// Maximum time of waiting for the request of a client
+#[cfg(feature = "metrics-http")]
+pub const CLIENT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Serves [`collect()`] output on `GET /metrics`. Returns only if
+/// the address can't be bound
+///
+/// ## Example
+/// ```no-test
+/// ferrix_lib::metrics::serve_http("127.0.0.1:9123")?;
+/// ```
+#[cfg(feature = "metrics-http")]
+pub fn serve_http<A: std::net::ToSocketAddrs>(addr: A) -> Result<()> {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+    };
+
+    let listener = TcpListener::bind(addr)?;
+    for stream in listener.incoming() {
+        // Errors of a single client shouldn't stop the server
+        let Ok(mut stream) = stream else {
+            continue;
+        };
+        if stream.set_read_timeout(Some(CLIENT_TIMEOUT)).is_err() {
+            continue;
+        }
+        let mut request = String::new();
+        if BufReader::new(&stream).read_line(&mut request).is_err() {
+            continue;
+        }
+
+        let (status, body) = match request.split_whitespace().nth(1) {
+            Some("/metrics") => match collect() {
+                Ok(body) => ("200 OK", body),
+                Err(why) => ("500 Internal Server Error", format!("{why}\n")),
+            },
+            _ => ("404 Not Found", "Not found\n".to_string()),
+        };
+        let _ = write!(
+            stream,
+            "HTTP/1.1 {status}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+    }
+    Ok(())
+}
+
+fn write_gauge<I>(out: &mut String, name: &str, help: &str, samples: I)
+where
+    I: IntoIterator<Item = (String, f64)>,
+{
+    out.push_str(&format!("# HELP {name} {help}\n# TYPE {name} gauge\n"));
+    for (labels, value) in samples {
+        out.push_str(&format!("{name}{labels} {value}\n"));
+    }
+}
+
+/// Renders labels as `{key="value",...}`
+fn labels(labels: &[(&str, &str)]) -> String {
+    let labels = labels
+        .iter()
+        .map(|(key, val)| format!("{key}=\"{}\"", escape(val)))
+        .collect::<Vec<_>>();
+    format!("{{{}}}", labels.join(","))
+}
+
+fn escape(val: &str) -> String {
+    val.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_test() {
+        assert_eq!(labels(&[("core", "0")]), "{core=\"0\"}");
+        assert_eq!(
+            labels(&[("mount", "/mnt/a \"b\""), ("device", "C:\\")]),
+            "{mount=\"/mnt/a \\\"b\\\"\",device=\"C:\\\\\"}"
+        );
+    }
+
+    #[test]
+    fn cpu_frequency_test() {
+        let processors = Processors {
+            entries: vec![
+                crate::cpu::CPU {
+                    processor: Some(0),
+                    cpu_mhz: Some(2400.5),
+                    ..Default::default()
+                },
+                // Cores without the frequency are skipped
+                crate::cpu::CPU {
+                    processor: Some(1),
+                    ..Default::default()
+                },
+            ],
+            packages: vec![],
+        };
+        let out = Metrics::default().with_processors(&processors).render();
+        assert!(out.contains("ferrix_cpu_frequency_mhz{core=\"0\"} 2400.5\n"));
+        assert!(!out.contains("core=\"1\""));
+    }
+
+    #[test]
+    fn cpu_usage_test() {
+        let out = Metrics::default().with_cpu_usage(&[12.5, 100.]).render();
+        assert!(out.contains("ferrix_cpu_usage_percent{core=\"0\"} 12.5\n"));
+        assert!(out.contains("ferrix_cpu_usage_percent{core=\"1\"} 100\n"));
+    }
+
+    #[test]
+    fn gauge_test() {
+        let mut out = String::new();
+        write_gauge(
+            &mut out,
+            "ferrix_test",
+            "Test gauge",
+            [(labels(&[("core", "1")]), 42.5)],
+        );
+        assert_eq!(
+            out,
+            "# HELP ferrix_test Test gauge\n# TYPE ferrix_test gauge\nferrix_test{core=\"1\"} 42.5\n"
+        );
+    }
+}
@@ -0,0 +1,370 @@
+/* net.rs
+ *
+ * Copyright 2026 Michail Krasnov <mskrasnov07@ya.ru>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Get information about network interfaces and their traffic from
+//! `/sys/class/net/`
+//!
+//! ## Example
+//! ```no-test
+//! use ferrix_lib::net::Interfaces;
+//! use ferrix_lib::traits::ToJson;
+//!
+//! let ifaces = Interfaces::new().unwrap();
+//! let json = ifaces.to_json().unwrap();
+//! dbg!(json);
+//! ```
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    ffi::CStr,
+    fmt::Display,
+    fs::{read_dir, read_to_string},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    path::Path,
+};
+
+use crate::traits::ToJson;
+
+/// Information about all network interfaces
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Interfaces {
+    pub ifaces: Vec<Interface>,
+}
+
+impl Interfaces {
+    pub fn new() -> Result<Self> {
+        let mut ifaces = Vec::new();
+        let base_path = Path::new("/sys/class/net/");
+        if !base_path.is_dir() {
+            return Ok(Self { ifaces });
+        }
+
+        // Addresses aren't provided by `sysfs`
+        let mut addresses = get_addresses()?;
+        for dir in read_dir(base_path)? {
+            let dir = dir?.path();
+            let mut iface = Interface::new(&dir);
+            iface.addresses = addresses.remove(&iface.name).unwrap_or_default();
+            ifaces.push(iface);
+        }
+        ifaces.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(Self { ifaces })
+    }
+
+    /// Computes the difference between traffic counters of `self`
+    /// (current snapshot) and `prev` (previous snapshot). Interfaces
+    /// missing in `prev` are skipped.
+    pub fn delta(&self, prev: &Self) -> Self {
+        let ifaces = self
+            .ifaces
+            .iter()
+            .filter_map(|cur| {
+                let prev = prev.ifaces.iter().find(|p| p.name == cur.name)?;
+                Some(Interface {
+                    stats: cur
+                        .stats
+                        .zip(prev.stats)
+                        .map(|(cur, prev)| cur.delta(&prev)),
+                    ..cur.clone()
+                })
+            })
+            .collect();
+        Self { ifaces }
+    }
+
+    /// Returns only interfaces which are up
+    pub fn up(&self) -> Vec<&Interface> {
+        self.ifaces
+            .iter()
+            .filter(|iface| iface.state == OperState::Up)
+            .collect()
+    }
+}
+
+impl ToJson for Interfaces {}
+
+/// Network interface (`/sys/class/net/*`)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Interface {
+    /// Interface name (e.g. `eth0`, `wlp3s0`)
+    pub name: String,
+
+    /// Hardware (MAC) address. `None` for interfaces without it
+    /// (e.g. `tun` devices)
+    pub mac: Option<String>,
+
+    /// Maximum transmission unit in bytes
+    pub mtu: Option<u32>,
+
+    /// Operational state (RFC 2863)
+    pub state: OperState,
+
+    /// Is the interface created by software (e.g. `lo`, bridges,
+    /// VPN tunnels) rather than backed by the device
+    pub is_virtual: bool,
+
+    /// IPv4 and IPv6 addresses
+    pub addresses: Vec<Address>,
+
+    /// Traffic counters. `None` if the driver doesn't provide them
+    pub stats: Option<NetStats>,
+}
+
+impl Interface {
+    fn new(dir: &Path) -> Self {
+        let name = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        Self {
+            name,
+            mac: read(dir, "address").filter(|mac| !mac.is_empty()),
+            mtu: read(dir, "mtu").and_then(|mtu| mtu.parse().ok()),
+            state: read(dir, "operstate")
+                .map(|state| OperState::from(state.as_str()))
+                .unwrap_or_default(),
+            is_virtual: !dir.join("device").exists(),
+            addresses: Vec::new(),
+            stats: NetStats::new(&dir.join("statistics")),
+        }
+    }
+
+    pub fn is_loopback(&self) -> bool {
+        self.name == "lo"
+    }
+}
+
+/// Traffic counters of the interface (`/sys/class/net/*/statistics/`)
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct NetStats {
+    /// Bytes received
+    pub rx_bytes: u64,
+
+    /// Bytes transmitted
+    pub tx_bytes: u64,
+
+    /// Packets received
+    pub rx_packets: u64,
+
+    /// Packets transmitted
+    pub tx_packets: u64,
+}
+
+impl NetStats {
+    fn new(dir: &Path) -> Option<Self> {
+        let get = |file: &str| read(dir, file).and_then(|val| val.parse().ok());
+        Some(Self {
+            rx_bytes: get("rx_bytes")?,
+            tx_bytes: get("tx_bytes")?,
+            rx_packets: get("rx_packets")?,
+            tx_packets: get("tx_packets")?,
+        })
+    }
+
+    pub fn delta(&self, prev: &Self) -> Self {
+        Self {
+            rx_bytes: self.rx_bytes.saturating_sub(prev.rx_bytes),
+            tx_bytes: self.tx_bytes.saturating_sub(prev.tx_bytes),
+            rx_packets: self.rx_packets.saturating_sub(prev.rx_packets),
+            tx_packets: self.tx_packets.saturating_sub(prev.tx_packets),
+        }
+    }
+}
+
+/// Operational state of the interface
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum OperState {
+    Up,
+    Down,
+
+    /// Waiting for an external event (e.g. 802.1X authentication)
+    Dormant,
+
+    /// Down because of the lower layer interface (e.g. the bond slave)
+    LowerLayerDown,
+    NotPresent,
+    Testing,
+
+    /// Not reported by the driver (e.g. `lo` and some tunnels)
+    #[default]
+    Unknown,
+}
+
+impl From<&str> for OperState {
+    fn from(value: &str) -> Self {
+        match value.trim() {
+            "up" => Self::Up,
+            "down" => Self::Down,
+            "dormant" => Self::Dormant,
+            "lowerlayerdown" => Self::LowerLayerDown,
+            "notpresent" => Self::NotPresent,
+            "testing" => Self::Testing,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for OperState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Up => "up",
+                Self::Down => "down",
+                Self::Dormant => "dormant",
+                Self::LowerLayerDown => "lower layer down",
+                Self::NotPresent => "not present",
+                Self::Testing => "testing",
+                Self::Unknown => "unknown",
+            }
+        )
+    }
+}
+
+/// IP address of the interface
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Address {
+    pub address: IpAddr,
+
+    /// Length of the network prefix (e.g. `24` for `255.255.255.0`)
+    pub prefix: Option<u8>,
+}
+
+impl Display for Address {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.prefix {
+            Some(prefix) => write!(f, "{}/{prefix}", self.address),
+            None => write!(f, "{}", self.address),
+        }
+    }
+}
+
+fn read(dir: &Path, file: &str) -> Option<String> {
+    read_to_string(dir.join(file))
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
+/// Returns IP addresses of all interfaces using `getifaddrs(3)`
+fn get_addresses() -> Result<HashMap<String, Vec<Address>>> {
+    let mut ifap: *mut libc::ifaddrs = std::ptr::null_mut();
+    if unsafe { libc::getifaddrs(&mut ifap) } != 0 {
+        return Err(std::io::Error::last_os_error().into());
+    }
+
+    let mut addresses: HashMap<String, Vec<Address>> = HashMap::new();
+    let mut cur = ifap;
+    while !cur.is_null() {
+        let ifa = unsafe { &*cur };
+        cur = ifa.ifa_next;
+
+        let Some(address) = (unsafe { to_ip_addr(ifa.ifa_addr) }) else {
+            // `AF_PACKET` entries and interfaces without addresses
+            continue;
+        };
+        let prefix = unsafe { to_ip_addr(ifa.ifa_netmask) }.map(prefix_len);
+        let name = unsafe { CStr::from_ptr(ifa.ifa_name) }
+            .to_string_lossy()
+            .to_string();
+        addresses
+            .entry(name)
+            .or_default()
+            .push(Address { address, prefix });
+    }
+    unsafe { libc::freeifaddrs(ifap) };
+
+    Ok(addresses)
+}
+
+/// Converts `AF_INET` and `AF_INET6` socket addresses
+unsafe fn to_ip_addr(addr: *const libc::sockaddr) -> Option<IpAddr> {
+    if addr.is_null() {
+        return None;
+    }
+    match unsafe { (*addr).sa_family } as libc::c_int {
+        libc::AF_INET => {
+            let addr = unsafe { &*(addr as *const libc::sockaddr_in) };
+            Some(IpAddr::V4(Ipv4Addr::from(u32::from_be(
+                addr.sin_addr.s_addr,
+            ))))
+        }
+        libc::AF_INET6 => {
+            let addr = unsafe { &*(addr as *const libc::sockaddr_in6) };
+            Some(IpAddr::V6(Ipv6Addr::from(addr.sin6_addr.s6_addr)))
+        }
+        _ => None,
+    }
+}
+
+fn prefix_len(netmask: IpAddr) -> u8 {
+    match netmask {
+        IpAddr::V4(mask) => mask.to_bits().count_ones() as u8,
+        IpAddr::V6(mask) => mask.to_bits().count_ones() as u8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oper_state_test() {
+        assert_eq!(OperState::from("up\n"), OperState::Up);
+        assert_eq!(OperState::from("lowerlayerdown"), OperState::LowerLayerDown);
+        assert_eq!(OperState::from("something"), OperState::Unknown);
+    }
+
+    #[test]
+    fn net_stats_delta_test() {
+        let prev = NetStats {
+            rx_bytes: 1000,
+            tx_bytes: 500,
+            rx_packets: 10,
+            tx_packets: 5,
+        };
+        let cur = NetStats {
+            rx_bytes: 3000,
+            tx_bytes: 400,
+            rx_packets: 30,
+            tx_packets: 7,
+        };
+        let delta = cur.delta(&prev);
+        assert_eq!(delta.rx_bytes, 2000);
+        // Counters are reset when the driver is reloaded
+        assert_eq!(delta.tx_bytes, 0);
+        assert_eq!(delta.rx_packets, 20);
+        assert_eq!(delta.tx_packets, 2);
+    }
+
+    #[test]
+    fn prefix_len_test() {
+        assert_eq!(prefix_len("255.255.255.0".parse().unwrap()), 24);
+        assert_eq!(prefix_len("ffff:ffff:ffff:ffff::".parse().unwrap()), 64);
+    }
+}
@@ -23,9 +23,12 @@
 use anyhow::{Result, anyhow};
 use libc::statvfs;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::ffi::{CString, c_char};
-use std::fs::{read_dir, read_to_string};
+use std::fs::{File, read_dir, read_to_string};
+use std::os::fd::AsRawFd;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 
 use crate::traits::ToJson;
 use crate::utils::Size;
@@ -33,6 +36,7 @@ use crate::utils::Size;
 // NOTE: Is this structure really necessary, since there are `Mounts`?
 /// List of partitions from `/proc/partitions` file
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Partitions {
     pub parts: Vec<Partition>,
 }
@@ -63,6 +67,7 @@ impl Partitions {
 impl ToJson for Partitions {}
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Partition {
     pub major: usize,
     pub minor: usize,
@@ -111,6 +116,7 @@ impl TryFrom<&str> for Partition {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DeviceInfo {
     pub model: Option<String>,
     pub vendor: Option<String>,
@@ -158,6 +164,7 @@ impl DeviceInfo {
 
 /// Physical disk drives from `/sys/block/` directory
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Storages {
     pub storages: Vec<Storage>,
 }
@@ -184,6 +191,7 @@ impl Storages {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Storage {
     /// `/sys/block/` subdirectory name (e.g. `sda`, `mmcblk0`, `nvme0n1`, etc.)
     pub devname: String,
@@ -278,25 +286,83 @@ impl Storage {
 
 /// Mounted filesystems list from `/proc/mounts` file
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Mounts {
     pub mounts: Vec<MountEntry>,
 }
 
+/// Pseudo (virtual) filesystems which don't store data on the disk
+pub const PSEUDO_FS: &[&str] = &[
+    "proc",
+    "sysfs",
+    "devtmpfs",
+    "devpts",
+    "tmpfs",
+    "cgroup",
+    "cgroup2",
+    "securityfs",
+    "debugfs",
+    "tracefs",
+    "configfs",
+    "fusectl",
+    "pstore",
+    "bpf",
+    "mqueue",
+    "hugetlbfs",
+    "autofs",
+    "binfmt_misc",
+    "efivarfs",
+    "ramfs",
+    "overlay",
+    "squashfs",
+    "nsfs",
+];
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MountEntry {
     pub device: String,
     pub mount_point: String,
+
+    /// Filesystem type
     pub filesystem: String,
-    pub options: String,
+
+    /// Mount options (e.g. `rw`, `noatime`, `nodev`)
+    pub options: Vec<String>,
     pub dump: u8,
     pub pass: u8,
     pub fstats: Option<FileSystemStats>,
+
+    /// Total number of inodes. `None` for pseudo filesystems and
+    /// filesystems with dynamic inode allocation (e.g. btrfs)
+    pub inodes_total: Option<u64>,
+
+    /// Number of free inodes. `None` for pseudo filesystems and
+    /// filesystems with dynamic inode allocation (e.g. btrfs)
+    pub inodes_free: Option<u64>,
 }
 
-impl TryFrom<&str> for MountEntry {
-    type Error = anyhow::Error;
+impl MountEntry {
+    /// Checks whether this filesystem is pseudo (virtual)
+    pub fn is_pseudo(&self) -> bool {
+        PSEUDO_FS.contains(&self.filesystem.as_str())
+    }
 
-    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+    /// Checks whether this filesystem is mounted read-only. The
+    /// kernel may remount the filesystem read-only after I/O errors
+    pub fn is_read_only(&self) -> bool {
+        self.options.iter().any(|opt| opt == "ro")
+    }
+
+    /// Returns the inode usage in percents
+    pub fn inodes_usage_percent(&self) -> Option<f64> {
+        let total = self.inodes_total?;
+        let free = self.inodes_free?;
+        Some((total - free.min(total)) as f64 / total as f64 * 100.)
+    }
+
+    /// Parses a line of `/proc/mounts` without filesystem statistics
+    fn parse(value: &str) -> Result<Self> {
         let values = value.split_whitespace().collect::<Vec<_>>();
         if values.len() != 6 {
             return Err(anyhow!(
@@ -305,37 +371,107 @@ impl TryFrom<&str> for MountEntry {
         }
 
         Ok(Self {
-            device: values[0].to_string(),
-            mount_point: values[1].to_string(),
+            device: unescape_octal(values[0]),
+            mount_point: unescape_octal(values[1]),
             filesystem: values[2].to_string(),
-            options: values[3].to_string(),
+            options: values[3].split(',').map(|opt| opt.to_string()).collect(),
             dump: values[4].parse()?,
             pass: values[5].parse()?,
-            fstats: FileSystemStats::from_path(values[1]).ok(),
+            fstats: None,
+            inodes_total: None,
+            inodes_free: None,
         })
     }
+
+    fn read_stats(&mut self) {
+        self.fstats = FileSystemStats::from_path(&self.mount_point).ok();
+        if let Some(fstats) = self.fstats
+            && fstats.total_inodes > 0
+            && !self.is_pseudo()
+        {
+            self.inodes_total = Some(fstats.total_inodes);
+            self.inodes_free = Some(fstats.free_inodes);
+        }
+    }
+}
+
+impl TryFrom<&str> for MountEntry {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        let mut entry = Self::parse(value)?;
+        entry.read_stats();
+        Ok(entry)
+    }
+}
+
+/// Decodes spaces, tabs, newlines and backslashes which are escaped
+/// in `/proc/mounts` as octal sequences (e.g. `\040`)
+fn unescape_octal(s: &str) -> String {
+    let raw = s.as_bytes();
+    let mut bytes = Vec::with_capacity(raw.len());
+    let mut i = 0;
+    while i < raw.len() {
+        let digits = raw.get(i + 1..i + 4).unwrap_or_default();
+        if raw[i] == b'\\' && digits.len() == 3 && digits.iter().all(|d| (b'0'..=b'7').contains(d))
+        {
+            bytes.push(
+                digits
+                    .iter()
+                    .fold(0u8, |acc, d| acc.wrapping_mul(8) + (d - b'0')),
+            );
+            i += 4;
+        } else {
+            bytes.push(raw[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&bytes).to_string()
 }
 
 impl Mounts {
+    /// Returns all mounted filesystems including pseudo ones
     pub fn new() -> Result<Self> {
+        Self::read(|_| true)
+    }
+
+    /// Returns only filesystems which store data on the disk
+    ///
+    /// Pseudo filesystems ([`PSEUDO_FS`]) are excluded except
+    /// `tmpfs` mounted on `/tmp` and `overlay` inside containers
+    pub fn new_physical() -> Result<Self> {
+        let in_container =
+            Path::new("/.dockerenv").exists() || Path::new("/run/.containerenv").exists();
+
+        Self::read(|m| match m.filesystem.as_str() {
+            "tmpfs" => m.mount_point == "/tmp",
+            "overlay" => in_container,
+            _ => !m.is_pseudo(),
+        })
+    }
+
+    /// Parses `/proc/mounts` and gets filesystem statistics only for
+    /// the entries accepted by `filter` (`statvfs()` may hang on
+    /// unreachable network filesystems)
+    fn read<F: Fn(&MountEntry) -> bool>(filter: F) -> Result<Self> {
         let contents = read_to_string("/proc/mounts")?;
-        let lines = contents.lines();
         let mut mounts = vec![];
 
-        for line in lines {
-            if line.starts_with("/")
-                || line.starts_with("udev")
-                || line.starts_with("sysfs")
-                || line.starts_with("tmpfs")
-            {
-                mounts.push(MountEntry::try_from(line)?);
+        for line in contents.lines() {
+            let mut entry = MountEntry::parse(line)?;
+            if filter(&entry) {
+                entry.read_stats();
+                mounts.push(entry);
             }
         }
         Ok(Self { mounts })
     }
 }
 
+impl ToJson for Mounts {}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct FileSystemStats {
     pub block_size: u64,
     pub fragment_size: u64,
@@ -425,6 +561,337 @@ impl FileSystemStats {
     }
 }
 
+/// Disk I/O statistics from `/proc/diskstats` file
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DiskStats {
+    pub disks: Vec<DiskStat>,
+}
+
+impl DiskStats {
+    pub fn new() -> Result<Self> {
+        let contents = read_to_string("/proc/diskstats")?;
+        Self::from_str(&contents)
+    }
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut disks = Vec::new();
+        for line in s.lines().filter(|s| !s.trim().is_empty()) {
+            disks.push(DiskStat::try_from(line)?);
+        }
+        Ok(Self { disks })
+    }
+
+    /// Computes the difference between `self` (current snapshot) and
+    /// `prev` (previous snapshot). Devices missing in `prev` are
+    /// skipped.
+    pub fn delta(&self, prev: &Self) -> Self {
+        let disks = self
+            .disks
+            .iter()
+            .filter_map(|cur| {
+                prev.disks
+                    .iter()
+                    .find(|p| p.name == cur.name)
+                    .map(|p| cur.delta(p))
+            })
+            .collect();
+        Self { disks }
+    }
+}
+
+impl ToJson for DiskStats {}
+
+/// I/O statistics of the single block device
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DiskStat {
+    pub major: usize,
+    pub minor: usize,
+    pub name: String,
+
+    /// Reads completed successfully
+    pub reads_completed: u64,
+
+    /// Sectors read (1 sector = 512 bytes)
+    pub sectors_read: u64,
+
+    /// Writes completed successfully
+    pub writes_completed: u64,
+
+    /// Sectors written (1 sector = 512 bytes)
+    pub sectors_written: u64,
+
+    /// I/Os currently in progress
+    pub io_in_progress: u64,
+
+    /// Time spent doing I/Os, ms
+    pub io_time_ms: u64,
+}
+
+impl DiskStat {
+    /// Size of the sector in `/proc/diskstats`. It is always 512
+    /// bytes regardless of the real sector size of the device
+    pub const SECTOR_SIZE: u64 = 512;
+
+    pub fn read_bytes(&self) -> u64 {
+        self.sectors_read * Self::SECTOR_SIZE
+    }
+
+    pub fn written_bytes(&self) -> u64 {
+        self.sectors_written * Self::SECTOR_SIZE
+    }
+
+    /// Is this device a whole disk (not a partition or a loop
+    /// device)
+    pub fn is_disk(&self) -> bool {
+        !self.name.starts_with("loop") && Path::new("/sys/block").join(&self.name).exists()
+    }
+
+    pub fn delta(&self, prev: &Self) -> Self {
+        Self {
+            major: self.major,
+            minor: self.minor,
+            name: self.name.clone(),
+            reads_completed: self.reads_completed.saturating_sub(prev.reads_completed),
+            sectors_read: self.sectors_read.saturating_sub(prev.sectors_read),
+            writes_completed: self.writes_completed.saturating_sub(prev.writes_completed),
+            sectors_written: self.sectors_written.saturating_sub(prev.sectors_written),
+            io_in_progress: self.io_in_progress,
+            io_time_ms: self.io_time_ms.saturating_sub(prev.io_time_ms),
+        }
+    }
+}
+
+impl TryFrom<&str> for DiskStat {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        let values = value.split_whitespace().collect::<Vec<_>>();
+        if values.len() < 14 {
+            return Err(anyhow!(
+                "Format of diskstats string is incorrect\n(string: \"{value}\")",
+            ));
+        }
+
+        Ok(Self {
+            major: values[0].parse()?,
+            minor: values[1].parse()?,
+            name: values[2].to_string(),
+            reads_completed: values[3].parse()?,
+            sectors_read: values[5].parse()?,
+            writes_completed: values[7].parse()?,
+            sectors_written: values[9].parse()?,
+            io_in_progress: values[11].parse()?,
+            io_time_ms: values[12].parse()?,
+        })
+    }
+}
+
+/// SMART health summary of the disk drive
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Smart {
+    /// `/sys/block/` subdirectory name (e.g. `sda`, `nvme0n1`)
+    pub devname: String,
+
+    /// Overall health self-assessment. `None` if it isn't available
+    /// (e.g. `smartctl` isn't installed)
+    pub health: Option<SmartHealth>,
+
+    /// Drive temperature, °C
+    pub temperature: Option<i64>,
+
+    pub power_on_hours: Option<u64>,
+
+    /// Reallocated sectors count (ATA) or media errors count (NVMe)
+    pub reallocated_sectors: Option<u64>,
+
+    /// Used endurance in percents (NVMe only). May exceed 100%
+    pub percentage_used: Option<u8>,
+}
+
+impl Smart {
+    /// Reads SMART data of the `dev` drive (e.g. `sda`) with
+    /// `smartctl --json`. If `smartctl` isn't installed or fails, only
+    /// the values exposed in `/sys/block/<dev>/device/` and (for NVMe
+    /// drives) in the health log of the controller are returned
+    pub fn for_device(dev: &str) -> Result<Self> {
+        match Self::from_smartctl(dev) {
+            Ok(smart) => Ok(smart),
+            Err(_) => Self::from_sysfs(dev),
+        }
+    }
+
+    /// Returns `true` if the drive reports the failure or has
+    /// reallocated sectors
+    pub fn is_failing(&self) -> bool {
+        self.health == Some(SmartHealth::Failed) || self.reallocated_sectors.is_some_and(|s| s > 0)
+    }
+
+    fn from_smartctl(dev: &str) -> Result<Self> {
+        let output = Command::new("smartctl")
+            .args(["--json", "-H", "-A", &format!("/dev/{dev}")])
+            .output()?;
+        // `smartctl` returns the bit mask of found problems as an exit
+        // status, so the JSON is parsed regardless of it
+        Self::from_smartctl_json(dev, &String::from_utf8_lossy(&output.stdout))
+    }
+
+    fn from_smartctl_json(dev: &str, json: &str) -> Result<Self> {
+        let json: Value = serde_json::from_str(json)?;
+        let passed = json["smart_status"]["passed"]
+            .as_bool()
+            .ok_or_else(|| anyhow!("smartctl didn't report the health of {dev}"))?;
+
+        let nvme = &json["nvme_smart_health_information_log"];
+        let reallocated_sectors = json["ata_smart_attributes"]["table"]
+            .as_array()
+            .and_then(|attrs| attrs.iter().find(|attr| attr["id"].as_u64() == Some(5)))
+            .and_then(|attr| attr["raw"]["value"].as_u64())
+            .or_else(|| nvme["media_errors"].as_u64());
+
+        Ok(Self {
+            devname: dev.to_string(),
+            health: Some(if passed {
+                SmartHealth::Passed
+            } else {
+                SmartHealth::Failed
+            }),
+            temperature: json["temperature"]["current"].as_i64(),
+            power_on_hours: json["power_on_time"]["hours"].as_u64(),
+            reallocated_sectors,
+            percentage_used: nvme["percentage_used"]
+                .as_u64()
+                .map(|p| p.min(u8::MAX as u64) as u8),
+        })
+    }
+
+    fn from_sysfs(dev: &str) -> Result<Self> {
+        let device = Path::new("/sys/block/").join(dev).join("device");
+        if !device.is_dir() {
+            return Err(anyhow!("Device {dev} not found"));
+        }
+
+        // NVMe controllers have `hwmonN` directly in the device
+        // directory, `drivetemp` sensors of SATA drives are placed in
+        // the `hwmon/` subdirectory
+        let temperature = [device.clone(), device.join("hwmon")]
+            .iter()
+            .filter_map(|dir| read_dir(dir).ok())
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_name().to_string_lossy().starts_with("hwmon"))
+            .find_map(|entry| read_to_string(entry.path().join("temp1_input")).ok())
+            .and_then(|temp| temp.trim().parse::<i64>().ok())
+            .map(|temp| temp / 1000);
+
+        let mut smart = Self {
+            devname: dev.to_string(),
+            temperature,
+            ..Default::default()
+        };
+        // The NVMe wear isn't exposed in sysfs. Reading the health log
+        // requires access to `/dev/<dev>`, so it usually works only
+        // for root
+        if dev.starts_with("nvme")
+            && let Ok(log) = read_nvme_health_log(dev)
+        {
+            smart.apply_nvme_health_log(&log);
+        }
+        Ok(smart)
+    }
+
+    fn apply_nvme_health_log(&mut self, log: &[u8; NVME_HEALTH_LOG_SIZE]) {
+        let le_u64 = |offset: usize| {
+            // 128-bit counters; values above `u64::MAX` aren't realistic
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&log[offset..offset + 8]);
+            u64::from_le_bytes(bytes)
+        };
+
+        // Bit 1 of the critical warning reports the temperature
+        // threshold, which isn't a failure of the drive
+        self.health = Some(if log[0] & !0x02 == 0 {
+            SmartHealth::Passed
+        } else {
+            SmartHealth::Failed
+        });
+        if self.temperature.is_none() {
+            let kelvin = u16::from_le_bytes([log[1], log[2]]);
+            self.temperature = (kelvin > 0).then(|| kelvin as i64 - 273);
+        }
+        self.percentage_used = Some(log[5]);
+        self.power_on_hours = Some(le_u64(128));
+        self.reallocated_sectors = Some(le_u64(160));
+    }
+}
+
+/// `NVME_IOCTL_ADMIN_CMD` (`_IOWR('N', 0x41, struct nvme_admin_cmd)`)
+const NVME_IOCTL_ADMIN_CMD: u32 = 0xC048_4E41;
+
+/// Size of the "SMART / Health Information" log page of NVMe drives
+const NVME_HEALTH_LOG_SIZE: usize = 512;
+
+/// `struct nvme_admin_cmd` from `<linux/nvme_ioctl.h>`
+#[repr(C)]
+#[derive(Default)]
+struct NvmeAdminCmd {
+    opcode: u8,
+    flags: u8,
+    rsvd1: u16,
+    nsid: u32,
+    cdw2: u32,
+    cdw3: u32,
+    metadata: u64,
+    addr: u64,
+    metadata_len: u32,
+    data_len: u32,
+    cdw10: u32,
+    cdw11: u32,
+    cdw12: u32,
+    cdw13: u32,
+    cdw14: u32,
+    cdw15: u32,
+    timeout_ms: u32,
+    result: u32,
+}
+
+/// Reads the "SMART / Health Information" log page with the "Get Log
+/// Page" admin command
+fn read_nvme_health_log(dev: &str) -> Result<[u8; NVME_HEALTH_LOG_SIZE]> {
+    let file = File::open(Path::new("/dev/").join(dev))?;
+    let mut log = [0u8; NVME_HEALTH_LOG_SIZE];
+    let mut cmd = NvmeAdminCmd {
+        opcode: 0x02,
+        nsid: 0xFFFF_FFFF,
+        addr: log.as_mut_ptr() as u64,
+        data_len: NVME_HEALTH_LOG_SIZE as u32,
+        // Number of dwords to read (0-based) and the log page ID
+        cdw10: ((NVME_HEALTH_LOG_SIZE as u32 / 4 - 1) << 16) | 0x02,
+        ..Default::default()
+    };
+
+    let res = unsafe { libc::ioctl(file.as_raw_fd(), NVME_IOCTL_ADMIN_CMD as _, &mut cmd) };
+    if res != 0 {
+        return Err(anyhow!(
+            "Failed to read the health log of {dev}: {}",
+            std::io::Error::last_os_error()
+        ));
+    }
+    Ok(log)
+}
+
+impl ToJson for Smart {}
+
+/// SMART overall health self-assessment
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SmartHealth {
+    Passed,
+    Failed,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -455,6 +922,22 @@ mod tests {
         let _ = std::fs::write("./test-filesystems.json", parts.to_json_pretty().unwrap());
     }
 
+    const DISKSTATS: &str = " 259       0 nvme0n1 194806 49795 14212254 36353 471592 387428 22536288 406174 0 217608 463826 0 0 0 0 32473 21297
+ 259       1 nvme0n1p1 206 8 13530 46 2 0 2 1 0 60 47 0 0 0 0 0 0";
+
+    #[test]
+    fn diskstats_from_str_test() {
+        let stats = DiskStats::from_str(DISKSTATS).unwrap();
+        assert_eq!(stats.disks.len(), 2);
+        assert_eq!(&stats.disks[0].name, "nvme0n1");
+        assert_eq!(stats.disks[0].sectors_read, 14212254);
+        assert_eq!(stats.disks[0].sectors_written, 22536288);
+        assert_eq!(stats.disks[0].io_time_ms, 217608);
+
+        let delta = stats.delta(&stats);
+        assert_eq!(delta.disks[0].sectors_read, 0);
+    }
+
     #[test]
     fn partition_invalid_str_test() {
         let s = "256 0 nvme";
@@ -468,4 +951,105 @@ mod tests {
         let part = Partition::try_from(s);
         assert!(part.is_ok());
     }
+
+    const SMARTCTL_ATA: &str = r#"{
+  "smart_status": { "passed": true },
+  "ata_smart_attributes": {
+    "table": [
+      { "id": 5, "name": "Reallocated_Sector_Ct", "raw": { "value": 8 } },
+      { "id": 9, "name": "Power_On_Hours", "raw": { "value": 12034 } }
+    ]
+  },
+  "power_on_time": { "hours": 12034 },
+  "temperature": { "current": 35 }
+}"#;
+
+    const SMARTCTL_NVME: &str = r#"{
+  "smart_status": { "passed": false },
+  "nvme_smart_health_information_log": {
+    "percentage_used": 104,
+    "media_errors": 0
+  },
+  "power_on_time": { "hours": 731 },
+  "temperature": { "current": 41 }
+}"#;
+
+    #[test]
+    fn smartctl_ata_test() {
+        let smart = Smart::from_smartctl_json("sda", SMARTCTL_ATA).unwrap();
+        assert_eq!(smart.health, Some(SmartHealth::Passed));
+        assert_eq!(smart.temperature, Some(35));
+        assert_eq!(smart.power_on_hours, Some(12034));
+        assert_eq!(smart.reallocated_sectors, Some(8));
+        assert_eq!(smart.percentage_used, None);
+        assert!(smart.is_failing());
+    }
+
+    #[test]
+    fn smartctl_nvme_test() {
+        let smart = Smart::from_smartctl_json("nvme0n1", SMARTCTL_NVME).unwrap();
+        assert_eq!(smart.health, Some(SmartHealth::Failed));
+        assert_eq!(smart.reallocated_sectors, Some(0));
+        assert_eq!(smart.percentage_used, Some(104));
+        assert!(smart.is_failing());
+    }
+
+    #[test]
+    fn nvme_health_log_test() {
+        let mut log = [0u8; NVME_HEALTH_LOG_SIZE];
+        log[1..3].copy_from_slice(&310u16.to_le_bytes());
+        log[5] = 12;
+        log[128..136].copy_from_slice(&4321u64.to_le_bytes());
+        log[160..168].copy_from_slice(&3u64.to_le_bytes());
+
+        let mut smart = Smart::default();
+        smart.apply_nvme_health_log(&log);
+        assert_eq!(smart.health, Some(SmartHealth::Passed));
+        assert_eq!(smart.temperature, Some(37));
+        assert_eq!(smart.percentage_used, Some(12));
+        assert_eq!(smart.power_on_hours, Some(4321));
+        assert_eq!(smart.reallocated_sectors, Some(3));
+
+        // Temperature warning only
+        log[0] = 0x02;
+        smart.apply_nvme_health_log(&log);
+        assert_eq!(smart.health, Some(SmartHealth::Passed));
+
+        // Available spare below the threshold
+        log[0] = 0x01;
+        smart.apply_nvme_health_log(&log);
+        assert_eq!(smart.health, Some(SmartHealth::Failed));
+    }
+
+    #[test]
+    fn smartctl_no_permissions_test() {
+        let json = r#"{ "smartctl": { "exit_status": 2 } }"#;
+        assert!(Smart::from_smartctl_json("sda", json).is_err());
+    }
+
+    #[test]
+    fn mount_entry_test() {
+        let entry =
+            MountEntry::try_from(r"/dev/sdb1 /media/user/My\040Disk ext4 ro,nosuid,nodev 0 0")
+                .unwrap();
+        assert_eq!(&entry.mount_point, "/media/user/My Disk");
+        assert_eq!(entry.options, ["ro", "nosuid", "nodev"]);
+        assert!(entry.is_read_only());
+    }
+
+    #[test]
+    fn mount_entry_escaped_test() {
+        let entry =
+            MountEntry::try_from(r"//nas/Shared\040Files /mnt/tab\011new\012line\134x cifs rw 0 0")
+                .unwrap();
+        assert_eq!(&entry.device, "//nas/Shared Files");
+        assert_eq!(&entry.mount_point, "/mnt/tab\tnew\nline\\x");
+        assert!(!entry.is_read_only());
+    }
+
+    #[test]
+    fn unescape_octal_test() {
+        assert_eq!(unescape_octal(r"a\011b\134c"), "a\tb\\c");
+        assert_eq!(unescape_octal(r"trailing\04"), r"trailing\04");
+    }
 }
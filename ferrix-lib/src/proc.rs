@@ -0,0 +1,238 @@
+/* proc.rs
+ *
+ * Copyright 2026 Michail Krasnov <mskrasnov07@ya.ru>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Get information about running processes from `/proc/<pid>/`
+//!
+//! ## Example
+//! ```no-test
+//! use ferrix_lib::proc::Processes;
+//!
+//! let procs = Processes::new().unwrap();
+//! for proc in procs.top_by_mem(5) {
+//!     println!("{} {} {}", proc.pid, proc.comm, proc.rss);
+//! }
+//! ```
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::{read_dir, read_to_string},
+    path::Path,
+    time::Duration,
+};
+
+use crate::{
+    sys::Users,
+    traits::ToJson,
+    utils::{Size, clock_ticks, page_size},
+};
+
+/// List of running processes
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Processes {
+    pub list: Vec<Process>,
+}
+
+/// Login names of the users by their IDs
+pub type UserNames = HashMap<u32, String>;
+
+/// Reads login names of the users from `/etc/passwd`
+///
+/// User names are optional, so missing `/etc/passwd` isn't an error.
+/// The result may be reused between [`Processes::new_with_users()`]
+/// calls instead of parsing the file every time
+pub fn user_names() -> UserNames {
+    Users::new()
+        .map(|users| {
+            users
+                .users
+                .into_iter()
+                .map(|user| (user.uid, user.name))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl Processes {
+    pub fn new() -> Result<Self> {
+        Self::new_with_users(&user_names())
+    }
+
+    /// Reads the processes list, taking owner names from `users`
+    pub fn new_with_users(users: &UserNames) -> Result<Self> {
+        let ticks = clock_ticks();
+        let page_size = page_size();
+
+        let mut list = Vec::new();
+        for dir in read_dir("/proc")? {
+            let dir = dir?;
+            let Some(pid) = dir
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<u32>().ok())
+            else {
+                continue;
+            };
+            // The process may exit while its files are read
+            let Ok(mut proc) = Process::new(pid, &dir.path(), ticks, page_size) else {
+                continue;
+            };
+            proc.user = proc.uid.and_then(|uid| users.get(&uid).cloned());
+            list.push(proc);
+        }
+        list.sort_by_key(|proc| proc.pid);
+
+        Ok(Self { list })
+    }
+
+    /// Returns `n` processes with the biggest cumulative CPU time
+    /// (spent in the user and kernel mode since the process started)
+    pub fn top_by_cpu_time(&self, n: usize) -> Vec<&Process> {
+        let mut list = self.list.iter().collect::<Vec<_>>();
+        list.sort_by_key(|p| std::cmp::Reverse(p.cpu_time));
+        list.truncate(n);
+        list
+    }
+
+    /// Returns `n` processes with the biggest resident set size
+    pub fn top_by_mem(&self, n: usize) -> Vec<&Process> {
+        let mut list = self.list.iter().collect::<Vec<_>>();
+        list.sort_by_key(|proc| std::cmp::Reverse(proc.rss.get_bytes2().unwrap_or(0)));
+        list.truncate(n);
+        list
+    }
+}
+
+impl ToJson for Processes {}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Process {
+    /// Process ID
+    pub pid: u32,
+
+    /// Executable name (truncated by the kernel to 15 characters)
+    pub comm: String,
+
+    /// Real user ID of the owner
+    pub uid: Option<u32>,
+
+    /// Login name of the owner
+    pub user: Option<String>,
+
+    /// Time spent in the user and kernel mode
+    pub cpu_time: Duration,
+
+    /// Resident set size (physical memory used by the process)
+    pub rss: Size,
+}
+
+impl Process {
+    fn new(pid: u32, dir: &Path, ticks: u64, page_size: u64) -> Result<Self> {
+        let stat = Stat::try_from(read_to_string(dir.join("stat"))?.as_str())?;
+        let uid = read_to_string(dir.join("status"))
+            .ok()
+            .and_then(|status| parse_uid(&status));
+
+        Ok(Self {
+            pid,
+            comm: stat.comm,
+            uid,
+            user: None,
+            cpu_time: Duration::from_secs_f64((stat.utime + stat.stime) as f64 / ticks as f64),
+            rss: Size::B(stat.rss * page_size),
+        })
+    }
+}
+
+/// Used fields of `/proc/<pid>/stat`
+struct Stat {
+    comm: String,
+
+    /// Time spent in user mode (clock ticks)
+    utime: u64,
+
+    /// Time spent in kernel mode (clock ticks)
+    stime: u64,
+
+    /// Resident set size (pages)
+    rss: u64,
+}
+
+impl TryFrom<&str> for Stat {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        // `comm` may contain spaces and parentheses, so it's taken
+        // between the first `(` and the last `)`
+        let (start, end) = value
+            .find('(')
+            .zip(value.rfind(')'))
+            .ok_or(anyhow!("Format of stat string is incorrect"))?;
+        let comm = value[start + 1..end].to_string();
+
+        // Fields after `comm` starting from `state` (3rd field)
+        let fields = value[end + 1..].split_whitespace().collect::<Vec<_>>();
+        if fields.len() < 22 {
+            return Err(anyhow!("Format of stat string is incorrect"));
+        }
+        Ok(Self {
+            comm,
+            utime: fields[11].parse()?,
+            stime: fields[12].parse()?,
+            rss: fields[21].parse()?,
+        })
+    }
+}
+
+/// Returns the real UID from `/proc/<pid>/status`
+fn parse_uid(status: &str) -> Option<u32> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("Uid:"))?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stat_test() {
+        let line = "1234 (Web Content (1)) S 1 1234 1234 0 -1 4194560 5000 0 0 0 \
+            250 50 0 0 20 0 30 0 12345 2000000000 40000 18446744073709551615";
+        let stat = Stat::try_from(line).unwrap();
+        assert_eq!(stat.comm, "Web Content (1)");
+        assert_eq!(stat.utime, 250);
+        assert_eq!(stat.stime, 50);
+        assert_eq!(stat.rss, 40000);
+    }
+
+    #[test]
+    fn uid_test() {
+        let status = "Name:\tbash\nUmask:\t0022\nUid:\t1000\t1000\t1000\t1000\n";
+        assert_eq!(parse_uid(status), Some(1000));
+    }
+}
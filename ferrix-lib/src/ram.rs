@@ -33,15 +33,17 @@
 //! ```
 
 use anyhow::{Result, anyhow};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 use std::fs::read_to_string;
 use std::path::Path;
 
-use crate::traits::ToJson;
-use crate::utils::Size;
+use crate::traits::{ToJson, ToMarkdown, ToPlainText, md_table_header, md_val, print_val};
+use crate::utils::{Size, page_size, read_optional};
 
 /// A structure containing data from the `/proc/meminfo` file
-#[derive(Debug, Serialize, Default, Clone)]
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RAM {
     /// Total usable physical RAM (excludes reserved/firmware memory)
     pub total: Size,
@@ -205,11 +207,26 @@ pub struct RAM {
     pub direct_map_4k: Size,
     pub direct_map_2m: Size,
     pub direct_map_1g: Size,
+
+    /// Transparent huge pages mode (from
+    /// `/sys/kernel/mm/transparent_hugepage/enabled`)
+    pub thp_mode: Option<ThpMode>,
 }
 
 impl RAM {
+    /// Fields missing in `/proc/meminfo` (or the whole file) are
+    /// left as [`Size::None`]
     pub fn new() -> Result<Self> {
-        let chunks = read_to_string("/proc/meminfo")?;
+        let mut ram = read_optional("/proc/meminfo")
+            .map(|chunks| Self::from_str(&chunks))
+            .unwrap_or_default();
+        ram.thp_mode = ThpMode::new();
+        Ok(ram)
+    }
+
+    fn from_str(chunks: &str) -> Self {
+        // Malformed values are skipped
+        let size = |val: &str| Size::try_from(val).unwrap_or_default();
         let chunks = chunks
             .lines()
             .map(|item| {
@@ -221,69 +238,69 @@ impl RAM {
         for chunk in chunks {
             match chunk {
                 (Some(key), Some(val)) => match key {
-                    "MemTotal" => ram.total = Size::try_from(val)?,
-                    "MemFree" => ram.free = Size::try_from(val)?,
-                    "MemAvailable" => ram.available = Size::try_from(val)?,
-                    "Buffers" => ram.buffers = Size::try_from(val)?,
-                    "Cached" => ram.cached = Size::try_from(val)?,
-                    "SwapCached" => ram.swap_cached = Size::try_from(val)?,
-                    "Active" => ram.active = Size::try_from(val)?,
-                    "Inactive" => ram.inactive = Size::try_from(val)?,
-                    "Active(anon)" => ram.active_anon = Size::try_from(val)?,
-                    "Inactive(anon)" => ram.inactive_anon = Size::try_from(val)?,
-                    "Active(file)" => ram.active_file = Size::try_from(val)?,
-                    "Inactive(file)" => ram.inactive_file = Size::try_from(val)?,
-                    "Unevictable" => ram.unevictable = Size::try_from(val)?,
-                    "Mlocked" => ram.mlocked = Size::try_from(val)?,
-                    "SwapTotal" => ram.swap_total = Size::try_from(val)?,
-                    "SwapFree" => ram.swap_free = Size::try_from(val)?,
-                    "Zswap" => ram.zswap = Size::try_from(val)?,
-                    "Zswapped" => ram.zswapped = Size::try_from(val)?,
-                    "Dirty" => ram.dirty = Size::try_from(val)?,
-                    "Writeback" => ram.writeback = Size::try_from(val)?,
-                    "AnonPages" => ram.anon_pages = Size::try_from(val)?,
-                    "Mapped" => ram.mapped = Size::try_from(val)?,
-                    "Shmem" => ram.shmem = Size::try_from(val)?,
-                    "KReclaimable" => ram.kreclaimable = Size::try_from(val)?,
-                    "Slab" => ram.slab = Size::try_from(val)?,
-                    "SReclaimable" => ram.sreclaimable = Size::try_from(val)?,
-                    "SUnreclaim" => ram.sunreclaim = Size::try_from(val)?,
-                    "KernelStack" => ram.kernel_stack = Size::try_from(val)?,
-                    "PageTables" => ram.page_tables = Size::try_from(val)?,
-                    "SecPageTables" => ram.sec_page_tables = Size::try_from(val)?,
-                    "NFS_Unstable" => ram.nfs_unstable = Size::try_from(val)?,
-                    "Bounce" => ram.bounce = Size::try_from(val)?,
-                    "WritebackTmp" => ram.writeback_tmp = Size::try_from(val)?,
-                    "CommitLimit" => ram.commit_limit = Size::try_from(val)?,
-                    "Committed_AS" => ram.commited_as = Size::try_from(val)?,
-                    "VmallocTotal" => ram.vmalloc_total = Size::try_from(val)?,
-                    "VmallocUsed" => ram.vmalloc_used = Size::try_from(val)?,
-                    "VmallocChunk" => ram.vmalloc_chunk = Size::try_from(val)?,
-                    "Percpu" => ram.percpu = Size::try_from(val)?,
-                    "HardwareCorrupted" => ram.hardware_corrupted = Size::try_from(val)?,
-                    "AnonHugePages" => ram.anon_huge_pages = Size::try_from(val)?,
-                    "ShmemHugePages" => ram.shmem_huge_pages = Size::try_from(val)?,
-                    "ShmemPmdMapped" => ram.shmem_pmd_mapped = Size::try_from(val)?,
+                    "MemTotal" => ram.total = size(val),
+                    "MemFree" => ram.free = size(val),
+                    "MemAvailable" => ram.available = size(val),
+                    "Buffers" => ram.buffers = size(val),
+                    "Cached" => ram.cached = size(val),
+                    "SwapCached" => ram.swap_cached = size(val),
+                    "Active" => ram.active = size(val),
+                    "Inactive" => ram.inactive = size(val),
+                    "Active(anon)" => ram.active_anon = size(val),
+                    "Inactive(anon)" => ram.inactive_anon = size(val),
+                    "Active(file)" => ram.active_file = size(val),
+                    "Inactive(file)" => ram.inactive_file = size(val),
+                    "Unevictable" => ram.unevictable = size(val),
+                    "Mlocked" => ram.mlocked = size(val),
+                    "SwapTotal" => ram.swap_total = size(val),
+                    "SwapFree" => ram.swap_free = size(val),
+                    "Zswap" => ram.zswap = size(val),
+                    "Zswapped" => ram.zswapped = size(val),
+                    "Dirty" => ram.dirty = size(val),
+                    "Writeback" => ram.writeback = size(val),
+                    "AnonPages" => ram.anon_pages = size(val),
+                    "Mapped" => ram.mapped = size(val),
+                    "Shmem" => ram.shmem = size(val),
+                    "KReclaimable" => ram.kreclaimable = size(val),
+                    "Slab" => ram.slab = size(val),
+                    "SReclaimable" => ram.sreclaimable = size(val),
+                    "SUnreclaim" => ram.sunreclaim = size(val),
+                    "KernelStack" => ram.kernel_stack = size(val),
+                    "PageTables" => ram.page_tables = size(val),
+                    "SecPageTables" => ram.sec_page_tables = size(val),
+                    "NFS_Unstable" => ram.nfs_unstable = size(val),
+                    "Bounce" => ram.bounce = size(val),
+                    "WritebackTmp" => ram.writeback_tmp = size(val),
+                    "CommitLimit" => ram.commit_limit = size(val),
+                    "Committed_AS" => ram.commited_as = size(val),
+                    "VmallocTotal" => ram.vmalloc_total = size(val),
+                    "VmallocUsed" => ram.vmalloc_used = size(val),
+                    "VmallocChunk" => ram.vmalloc_chunk = size(val),
+                    "Percpu" => ram.percpu = size(val),
+                    "HardwareCorrupted" => ram.hardware_corrupted = size(val),
+                    "AnonHugePages" => ram.anon_huge_pages = size(val),
+                    "ShmemHugePages" => ram.shmem_huge_pages = size(val),
+                    "ShmemPmdMapped" => ram.shmem_pmd_mapped = size(val),
                     "CmaTotal" => ram.cma_total = Size::try_from(val).ok(),
                     "CmaFree" => ram.cma_free = Size::try_from(val).ok(),
-                    "FileHugePages" => ram.file_huge_pages = Size::try_from(val)?,
-                    "FilePmdMapped" => ram.file_pmd_mapped = Size::try_from(val)?,
-                    "Unaccepted" => ram.unaccepted = Size::try_from(val)?,
-                    "HugePages_Total" => ram.huge_pages_total = val.parse()?,
-                    "HugePages_Free" => ram.huge_pages_free = val.parse()?,
-                    "HugePages_Rsvd" => ram.huge_pages_rsvd = val.parse()?,
-                    "HugePages_Surp" => ram.huge_pages_surp = val.parse()?,
-                    "Hugepagesize" => ram.huge_page_size = Size::try_from(val)?,
-                    "Hugetlb" => ram.huge_tlb = Size::try_from(val)?,
-                    "DirectMap4k" => ram.direct_map_4k = Size::try_from(val)?,
-                    "DirectMap2M" => ram.direct_map_2m = Size::try_from(val)?,
-                    "DirectMap1G" => ram.direct_map_1g = Size::try_from(val)?,
+                    "FileHugePages" => ram.file_huge_pages = size(val),
+                    "FilePmdMapped" => ram.file_pmd_mapped = size(val),
+                    "Unaccepted" => ram.unaccepted = size(val),
+                    "HugePages_Total" => ram.huge_pages_total = val.parse().unwrap_or_default(),
+                    "HugePages_Free" => ram.huge_pages_free = val.parse().unwrap_or_default(),
+                    "HugePages_Rsvd" => ram.huge_pages_rsvd = val.parse().unwrap_or_default(),
+                    "HugePages_Surp" => ram.huge_pages_surp = val.parse().unwrap_or_default(),
+                    "Hugepagesize" => ram.huge_page_size = size(val),
+                    "Hugetlb" => ram.huge_tlb = size(val),
+                    "DirectMap4k" => ram.direct_map_4k = size(val),
+                    "DirectMap2M" => ram.direct_map_2m = size(val),
+                    "DirectMap1G" => ram.direct_map_1g = size(val),
                     _ => continue,
                 },
                 _ => {}
             }
         }
-        Ok(ram)
+        ram
     }
 
     pub fn used_ram(&self, base: u8) -> Size {
@@ -318,12 +335,92 @@ impl RAM {
 
         Some(used_ram as f64 / total_ram as f64 * 100.)
     }
+
+    /// Splits used memory into the memory of applications, buffers
+    /// and page cache (in percents of total RAM)
+    pub fn usage_breakdown(&self) -> Option<RAMBreakdown> {
+        let total = self.total.get_bytes2()? as f64;
+        if total == 0. {
+            return None;
+        }
+        let free = self.free.get_bytes2()? as f64;
+        let buffers = self.buffers.get_bytes2()? as f64;
+        let cached = self.cached.get_bytes2()? as f64;
+        let apps = (total - free - buffers - cached).max(0.);
+
+        Some(RAMBreakdown {
+            apps: apps / total * 100.,
+            buffers: buffers / total * 100.,
+            cached: cached / total * 100.,
+        })
+    }
 }
 
 impl ToJson for RAM {}
 
+/// Used RAM split by its consumers (in percents of total RAM)
+#[derive(Debug, Deserialize, Serialize, Default, Clone, Copy)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RAMBreakdown {
+    /// Memory used by applications (can't be reclaimed without
+    /// swapping)
+    pub apps: f64,
+
+    /// Memory used by buffers
+    pub buffers: f64,
+
+    /// Page cache (reclaimable)
+    pub cached: f64,
+}
+
+impl ToPlainText for RAM {
+    fn to_plain(&self) -> String {
+        let mut s = String::from("\nInformation about RAM\n");
+        s += &print_val("Total", &self.total);
+        s += &print_val("Free", &self.free);
+        s += &print_val("Available", &self.available);
+        s += &print_val("Buffers", &self.buffers);
+        s += &print_val("Cached", &self.cached);
+        s += &print_val("Swap cached", &self.swap_cached);
+        s += &print_val("Active", &self.active);
+        s += &print_val("Inactive", &self.inactive);
+        s += &print_val("Swap total", &self.swap_total);
+        s += &print_val("Swap free", &self.swap_free);
+        s += &print_val("Dirty", &self.dirty);
+        s += &print_val("Writeback", &self.writeback);
+        s += &print_val("Shared memory", &self.shmem);
+        s += &print_val("Slab", &self.slab);
+
+        s
+    }
+}
+
+impl ToMarkdown for RAM {
+    fn to_markdown(&self) -> String {
+        let mut s = String::from("## RAM\n\n");
+        s += &md_table_header();
+        s += &md_val("Total", &self.total);
+        s += &md_val("Free", &self.free);
+        s += &md_val("Available", &self.available);
+        s += &md_val("Buffers", &self.buffers);
+        s += &md_val("Cached", &self.cached);
+        s += &md_val("Swap cached", &self.swap_cached);
+        s += &md_val("Active", &self.active);
+        s += &md_val("Inactive", &self.inactive);
+        s += &md_val("Swap total", &self.swap_total);
+        s += &md_val("Swap free", &self.swap_free);
+        s += &md_val("Dirty", &self.dirty);
+        s += &md_val("Writeback", &self.writeback);
+        s += &md_val("Shared memory", &self.shmem);
+        s += &md_val("Slab", &self.slab);
+
+        s
+    }
+}
+
 /// Information about swap files or partitions
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Swaps {
     pub swaps: Vec<Swap>,
 }
@@ -332,7 +429,9 @@ impl Swaps {
     pub fn new() -> Result<Self> {
         let mut swaps = vec![];
 
-        let data = read_to_string("/proc/swaps")?;
+        // `/proc/swaps` is missing if the kernel is built without
+        // swap support
+        let data = read_optional("/proc/swaps").unwrap_or_default();
         let items = data.lines().skip(1);
 
         for swap in items {
@@ -345,13 +444,14 @@ impl Swaps {
 
 impl ToJson for Swaps {}
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Swap {
     /// Path to the file or partition
     pub filename: String,
 
-    /// Type
-    pub swap_type: String,
+    /// Type of the swap device
+    pub kind: SwapKind,
 
     /// Swap size
     pub size: Size,
@@ -360,7 +460,7 @@ pub struct Swap {
     pub used: Size,
 
     /// Priority of this swap file/partition
-    pub priority: i8,
+    pub priority: i32,
 }
 
 impl Swap {
@@ -381,9 +481,10 @@ impl TryFrom<&str> for Swap {
             return Err(anyhow!("Format of the \"{value}\" string is incorrect!"));
         }
 
+        let filename = data[0].to_string();
         Ok(Self {
-            filename: data[0].to_string(),
-            swap_type: data[1].to_string(),
+            kind: SwapKind::new(&filename, data[1]),
+            filename,
             size: Size::KB(data[2].parse()?),
             used: Size::KB(data[3].parse()?),
             priority: data[4].parse()?,
@@ -392,7 +493,194 @@ impl TryFrom<&str> for Swap {
 }
 impl ToJson for Swap {}
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SwapKind {
+    /// Swap partition on the disk
+    Partition,
+
+    /// Swap file
+    File,
+
+    /// Compressed swap in RAM (`/dev/zram*`)
+    Zram,
+
+    /// Unknown value of the "Type" column
+    Unknown,
+}
+
+impl SwapKind {
+    /// Detects the swap kind by its path and the "Type" column of
+    /// `/proc/swaps`. `zram` devices are reported as partitions, so
+    /// they are checked in `/sys/block/` additionally
+    fn new(filename: &str, swap_type: &str) -> Self {
+        match swap_type {
+            "partition" => match filename.strip_prefix("/dev/") {
+                Some(dev)
+                    if dev.starts_with("zram") && Path::new("/sys/block/").join(dev).exists() =>
+                {
+                    Self::Zram
+                }
+                _ => Self::Partition,
+            },
+            "file" => Self::File,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for SwapKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Partition => "partition",
+                Self::File => "file",
+                Self::Zram => "zram",
+                Self::Unknown => "unknown",
+            }
+        )
+    }
+}
+
+/// Transparent huge pages (THP) mode
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ThpMode {
+    /// THP are used for all suitable memory regions
+    Always,
+
+    /// THP are used only for regions marked with `madvise(MADV_HUGEPAGE)`
+    Madvise,
+
+    /// THP are disabled
+    Never,
+}
+
+impl ThpMode {
+    /// Returns `None` if the kernel is built without THP support
+    pub fn new() -> Option<Self> {
+        let modes = read_to_string("/sys/kernel/mm/transparent_hugepage/enabled").ok()?;
+        match parse_selected(&modes)?.as_str() {
+            "always" => Some(Self::Always),
+            "madvise" => Some(Self::Madvise),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ThpMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Always => "always",
+                Self::Madvise => "madvise",
+                Self::Never => "never",
+            }
+        )
+    }
+}
+
+/// Information about all `zram` devices
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Zram {
+    pub devices: Vec<ZramDevice>,
+}
+
+impl Zram {
+    pub fn new() -> Result<Self> {
+        let mut devices = Vec::new();
+        let base_path = Path::new("/sys/block/");
+        for dir in base_path.read_dir()? {
+            let dir = dir?;
+            let name = dir.file_name().to_string_lossy().to_string();
+            if name.starts_with("zram") {
+                devices.push(ZramDevice::new(name, &dir.path())?);
+            }
+        }
+        devices.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(Self { devices })
+    }
+}
+
+impl ToJson for Zram {}
+
+/// Compressed RAM block device (`/sys/block/zram*`)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ZramDevice {
+    /// Device name (e.g. `zram0`)
+    pub name: String,
+
+    /// Selected compression algorithm (e.g. `lz4`, `zstd`)
+    pub algorithm: Option<String>,
+
+    /// Maximum size of uncompressed data
+    pub disksize: Size,
+
+    /// Size of uncompressed data stored in this device
+    pub orig_data_size: Size,
+
+    /// Size of compressed data stored in this device
+    pub compr_data_size: Size,
+
+    /// Amount of memory allocated for this device including allocator
+    /// fragmentation and metadata overhead
+    pub mem_used_total: Size,
+}
+
+impl ZramDevice {
+    fn new(name: String, dir: &Path) -> Result<Self> {
+        let algorithm = read_to_string(dir.join("comp_algorithm"))
+            .ok()
+            .and_then(|algs| parse_selected(&algs));
+        let disksize = read_to_string(dir.join("disksize"))?.trim().parse()?;
+
+        // orig_data_size compr_data_size mem_used_total mem_limit ...
+        let mm_stat = read_to_string(dir.join("mm_stat"))?;
+        let mut stat = mm_stat
+            .split_whitespace()
+            .map(|val| val.parse::<u64>().ok());
+        let mut next = || stat.next().flatten().map(Size::B).unwrap_or_default();
+
+        Ok(Self {
+            name,
+            algorithm,
+            disksize: Size::B(disksize),
+            orig_data_size: next(),
+            compr_data_size: next(),
+            mem_used_total: next(),
+        })
+    }
+
+    /// Returns compression ratio (`orig_data_size / compr_data_size`).
+    /// `None` if the device is empty
+    pub fn ratio(&self) -> Option<f64> {
+        let orig = self.orig_data_size.get_bytes2()?;
+        let compr = self.compr_data_size.get_bytes2()?;
+        if compr == 0 {
+            return None;
+        }
+        Some(orig as f64 / compr as f64)
+    }
+}
+
+/// Returns the selected value from the `sysfs` files listing all
+/// possible values (e.g. `lzo lzo-rle [lz4] zstd`)
+fn parse_selected(values: &str) -> Option<String> {
+    values
+        .split_whitespace()
+        .find_map(|val| val.strip_prefix('[')?.strip_suffix(']'))
+        .map(|val| val.to_string())
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Zswap {
     pub accept_threshold_percent: Option<u8>,
     pub compressor: Option<String>,
@@ -406,10 +694,6 @@ pub struct Zswap {
     pub compression_ratio: Option<f32>,
 }
 
-fn get_page_size() -> u64 {
-    unsafe { libc::sysconf(libc::_SC_PAGESIZE) as u64 }
-}
-
 impl Zswap {
     pub fn new() -> Self {
         let rdbg = |f: &str| read_to_string(Path::new("/sys/kernel/debug/zswap/").join(f)).ok();
@@ -453,9 +737,55 @@ impl Zswap {
             same_filled_pages_enabled,
             zpool,
             pool_total_size: pool_total_size.and_then(|pts| Some(Size::B(pts))),
-            stored_pages_size: stored_page_size
-                .and_then(|sps| Some(Size::B(sps * get_page_size()))),
+            stored_pages_size: stored_page_size.and_then(|sps| Some(Size::B(sps * page_size()))),
             compression_ratio,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selected_test() {
+        assert_eq!(
+            parse_selected("lzo lzo-rle [lz4] zstd\n").as_deref(),
+            Some("lz4")
+        );
+        assert_eq!(
+            parse_selected("always [madvise] never").as_deref(),
+            Some("madvise")
+        );
+        assert_eq!(parse_selected("lzo zstd"), None);
+    }
+
+    #[test]
+    fn zram_ratio_test() {
+        let mut dev = ZramDevice {
+            name: "zram0".to_string(),
+            algorithm: None,
+            disksize: Size::B(8192),
+            orig_data_size: Size::B(4096),
+            compr_data_size: Size::B(1024),
+            mem_used_total: Size::B(1536),
+        };
+        assert_eq!(dev.ratio(), Some(4.));
+        dev.compr_data_size = Size::B(0);
+        assert_eq!(dev.ratio(), None);
+    }
+
+    #[test]
+    fn truncated_meminfo_test() {
+        let ram = RAM::from_str(
+            "MemTotal:       16303372 kB
+MemFree:        garbage kB
+HugePages_Total:    oops
+MemAvail",
+        );
+        assert_eq!(ram.total.get_bytes2(), Some(16303372 * 1024));
+        assert!(matches!(ram.free, Size::None));
+        assert_eq!(ram.huge_pages_total, 0);
+        assert!(matches!(ram.available, Size::None));
+    }
+}
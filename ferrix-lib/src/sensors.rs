@@ -0,0 +1,237 @@
+/* sensors.rs
+ *
+ * Copyright 2026 Michail Krasnov <mskrasnov07@ya.ru>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Get information about hardware sensors (temperatures, fans and
+//! voltages) from `/sys/class/hwmon/`
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{read_dir, read_to_string},
+    path::Path,
+};
+
+use crate::traits::ToJson;
+
+/// Information about all hardware monitoring chips
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Sensors {
+    pub chips: Vec<Chip>,
+}
+
+impl Sensors {
+    pub fn new() -> Result<Self> {
+        let mut chips = Vec::new();
+        let base_path = Path::new("/sys/class/hwmon/");
+        if !base_path.is_dir() {
+            // No sensors at all (e.g. in virtual machines)
+            return Ok(Self { chips });
+        }
+
+        for dir in read_dir(base_path)? {
+            let dir = dir?.path();
+            chips.push(Chip::new(&dir)?);
+        }
+        // `hwmon10` should go after `hwmon9`
+        chips.sort_by_key(|chip| {
+            chip.hwmon
+                .trim_start_matches("hwmon")
+                .parse::<u32>()
+                .unwrap_or(u32::MAX)
+        });
+        Ok(Self { chips })
+    }
+}
+
+impl ToJson for Sensors {}
+
+/// Hardware monitoring chip (`/sys/class/hwmon/hwmon*`)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Chip {
+    /// Name of the `hwmon` directory (e.g. `hwmon0`)
+    pub hwmon: String,
+
+    /// Chip (driver) name (e.g. `coretemp`, `thinkpad`)
+    pub name: Option<String>,
+    pub sensors: Vec<Sensor>,
+}
+
+impl Chip {
+    fn new(dir: &Path) -> Result<Self> {
+        let hwmon = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let name = read(dir, "name");
+
+        let mut sensors = Vec::new();
+        for file in read_dir(dir)? {
+            let file = file?.file_name();
+            let Some((kind, idx)) = parse_input_name(&file.to_string_lossy()) else {
+                continue;
+            };
+            let prefix = format!("{}{idx}", kind.prefix());
+            let Some(input) = read_value(dir, &format!("{prefix}_input"), kind) else {
+                continue;
+            };
+
+            sensors.push(Sensor {
+                kind,
+                index: idx,
+                label: read(dir, &format!("{prefix}_label")),
+                input,
+                min: read_value(dir, &format!("{prefix}_min"), kind),
+                max: read_value(dir, &format!("{prefix}_max"), kind),
+                crit: read_value(dir, &format!("{prefix}_crit"), kind),
+            });
+        }
+        sensors.sort_by_key(|sensor| (sensor.kind, sensor.index));
+
+        Ok(Self {
+            hwmon,
+            name,
+            sensors,
+        })
+    }
+}
+
+/// Single sensor of the chip
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Sensor {
+    pub kind: SensorKind,
+
+    /// Number of the sensor (e.g. `1` for `temp1_input`)
+    pub index: u32,
+
+    /// Sensor label (e.g. `Core 0`)
+    pub label: Option<String>,
+
+    /// Current value in [`SensorKind::unit()`] units
+    pub input: f64,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+
+    /// Critical value
+    pub crit: Option<f64>,
+}
+
+impl Sensor {
+    /// Returns the sensor label or its name (e.g. `temp1`) if the
+    /// label isn't provided by the driver
+    pub fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.to_string(),
+            None => format!("{}{}", self.kind.prefix(), self.index),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SensorKind {
+    /// Temperature (°C)
+    Temperature,
+
+    /// Fan speed (RPM)
+    Fan,
+
+    /// Voltage (V)
+    Voltage,
+}
+
+impl SensorKind {
+    fn prefix(&self) -> &'static str {
+        match self {
+            Self::Temperature => "temp",
+            Self::Fan => "fan",
+            Self::Voltage => "in",
+        }
+    }
+
+    pub fn unit(&self) -> &'static str {
+        match self {
+            Self::Temperature => "°C",
+            Self::Fan => "RPM",
+            Self::Voltage => "V",
+        }
+    }
+
+    /// Temperatures are reported in millidegrees and voltages in
+    /// millivolts
+    fn divider(&self) -> f64 {
+        match self {
+            Self::Temperature | Self::Voltage => 1000.,
+            Self::Fan => 1.,
+        }
+    }
+}
+
+/// Parses `hwmon` input file name (e.g. `temp1_input`) into the
+/// sensor kind and its number
+fn parse_input_name(file: &str) -> Option<(SensorKind, u32)> {
+    let name = file.strip_suffix("_input")?;
+    let (kind, idx) = if let Some(idx) = name.strip_prefix("temp") {
+        (SensorKind::Temperature, idx)
+    } else if let Some(idx) = name.strip_prefix("fan") {
+        (SensorKind::Fan, idx)
+    } else if let Some(idx) = name.strip_prefix("in") {
+        (SensorKind::Voltage, idx)
+    } else {
+        return None;
+    };
+    Some((kind, idx.parse().ok()?))
+}
+
+fn read(dir: &Path, file: &str) -> Option<String> {
+    read_to_string(dir.join(file))
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
+fn read_value(dir: &Path, file: &str, kind: SensorKind) -> Option<f64> {
+    read(dir, file)?
+        .parse::<f64>()
+        .ok()
+        .map(|val| val / kind.divider())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_name_test() {
+        assert_eq!(
+            parse_input_name("temp1_input"),
+            Some((SensorKind::Temperature, 1))
+        );
+        assert_eq!(parse_input_name("fan2_input"), Some((SensorKind::Fan, 2)));
+        assert_eq!(
+            parse_input_name("in0_input"),
+            Some((SensorKind::Voltage, 0))
+        );
+        assert_eq!(parse_input_name("temp1_label"), None);
+        assert_eq!(parse_input_name("intrusion0_input"), None);
+        assert_eq!(parse_input_name("power1_input"), None);
+    }
+}
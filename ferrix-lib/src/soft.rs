@@ -22,12 +22,18 @@
 
 use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
-use std::{fmt::Display, path::Path, process::Command};
+use std::{
+    fmt::Display,
+    fs::{read_dir, read_to_string},
+    path::Path,
+    process::Command,
+};
 
 use crate::traits::ToJson;
 
 /// Type of installed Linux distro packaging system or concrete package
 #[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum PkgType {
     /// System with `dpkg` package manager only
     Deb,
@@ -38,6 +44,12 @@ pub enum PkgType {
     /// System with `dpkg` and `rpm` package managers
     DebRpm, // If deb and rpm package managers is installed
 
+    /// Package installed with `pacman`
+    Pacman,
+
+    /// Flatpak application or runtime
+    Flatpak,
+
     /// Unknown and/or unsupported Linux distro
     Other,
 }
@@ -52,22 +64,21 @@ impl PkgType {
         "/usr/local/sbin/",
     ];
 
-    fn is_deb() -> bool {
+    fn has_binary(name: &str) -> bool {
         for dir in Self::BINARY_PATHES {
-            if Path::new(*dir).join("dpkg-query").exists() {
+            if Path::new(*dir).join(name).exists() {
                 return true;
             }
         }
         false
     }
 
+    fn is_deb() -> bool {
+        Self::has_binary("dpkg-query")
+    }
+
     fn is_rpm() -> bool {
-        for dir in Self::BINARY_PATHES {
-            if Path::new(*dir).join("rpm").exists() {
-                return true;
-            }
-        }
-        false
+        Self::has_binary("rpm")
     }
 
     /// Detect Linux packaging system
@@ -107,15 +118,79 @@ impl Display for PkgType {
                 Self::Deb => "deb",
                 Self::Rpm => "rpm",
                 Self::DebRpm => "deb+rpm",
+                Self::Pacman => "pacman",
+                Self::Flatpak => "flatpak",
                 Self::Other => "unknown",
             }
         )
     }
 }
 
+/// Package manager which installed packages can be listed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum PackageManager {
+    /// `dpkg` (Debian, Ubuntu, etc.)
+    Dpkg,
+
+    /// `rpm` (Fedora, openSUSE, etc.)
+    Rpm,
+
+    /// `pacman` (Arch Linux and derivatives)
+    Pacman,
+
+    /// Flatpak applications and runtimes
+    Flatpak,
+}
+
+impl PackageManager {
+    const DPKG_STATUS: &str = "/var/lib/dpkg/status";
+    const PACMAN_LOCAL: &str = "/var/lib/pacman/local";
+
+    pub const ALL: &[Self] = &[Self::Dpkg, Self::Rpm, Self::Pacman, Self::Flatpak];
+
+    /// Checks whether this package manager is present in the system
+    pub fn is_available(&self) -> bool {
+        match self {
+            Self::Dpkg => Path::new(Self::DPKG_STATUS).is_file(),
+            Self::Rpm => PkgType::is_rpm(),
+            Self::Pacman => Path::new(Self::PACMAN_LOCAL).is_dir(),
+            Self::Flatpak => PkgType::has_binary("flatpak"),
+        }
+    }
+
+    /// Detect all package managers present in the system
+    pub fn detect() -> Vec<Self> {
+        Self::ALL
+            .iter()
+            .filter(|mgr| mgr.is_available())
+            .copied()
+            .collect()
+    }
+}
+
+impl Display for PackageManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Dpkg => "dpkg",
+                Self::Rpm => "rpm",
+                Self::Pacman => "pacman",
+                Self::Flatpak => "flatpak",
+            }
+        )
+    }
+}
+
 /// List of installed software
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct InstalledPackages {
+    /// Package manager which installed these packages
+    pub manager: PackageManager,
+
     /// Package list
     pub packages: Vec<Package>,
 }
@@ -123,63 +198,225 @@ pub struct InstalledPackages {
 impl ToJson for InstalledPackages {}
 
 impl InstalledPackages {
-    pub fn get() -> Result<Self> {
-        let pkg_type = PkgType::detect();
-
-        match pkg_type {
-            PkgType::Deb => Self::get_deb_packages(),
-            PkgType::Rpm => Self::get_rpm_packages(),
-            PkgType::DebRpm => {
-                let mut deb = Self::get_deb_packages()?;
-                let mut rpm = Self::get_rpm_packages()?;
-                deb.packages.append(&mut rpm.packages);
-
-                Ok(deb)
+    /// Get installed packages from every detected package manager
+    ///
+    /// Package managers whose databases can't be read are skipped.
+    /// An error is returned only if none of them can be read
+    pub fn get() -> Result<Vec<Self>> {
+        let managers = PackageManager::detect();
+        if managers.is_empty() {
+            return Err(anyhow!(
+                "Unsupported packaging system type! Supports only `dpkg`, `rpm`, `pacman` and `flatpak`."
+            ));
+        }
+
+        let mut lists = Vec::with_capacity(managers.len());
+        let mut errors = Vec::new();
+        for manager in managers {
+            match Self::new(manager) {
+                Ok(list) => lists.push(list),
+                Err(why) => errors.push(format!("{manager}: {why}")),
             }
-            PkgType::Other => Err(anyhow!(
-                "Unsupported packaging system type! Supports only `deb` and `rpm` package types."
-            )),
         }
+        if lists.is_empty() {
+            return Err(anyhow!(
+                "Failed to get installed packages:\n{}",
+                errors.join("\n")
+            ));
+        }
+        Ok(lists)
+    }
+
+    /// Get packages installed with the selected package manager
+    pub fn new(manager: PackageManager) -> Result<Self> {
+        let packages = match manager {
+            PackageManager::Dpkg => Self::get_deb_packages()?,
+            PackageManager::Rpm => Self::get_rpm_packages()?,
+            PackageManager::Pacman => Self::get_pacman_packages()?,
+            PackageManager::Flatpak => Self::get_flatpak_packages()?,
+        };
+        Ok(Self { manager, packages })
     }
 
-    fn command(args: &[&str]) -> Result<Self> {
+    /// Number of installed packages
+    pub fn count(&self) -> usize {
+        self.packages.len()
+    }
+
+    /// Case-insensitive search over package names and summaries
+    pub fn search(&self, query: &str) -> Vec<&Package> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return self.packages.iter().collect();
+        }
+
+        self.packages
+            .iter()
+            .filter(|pkg| {
+                pkg.name.to_lowercase().contains(&query)
+                    || pkg
+                        .summary
+                        .as_ref()
+                        .is_some_and(|summary| summary.to_lowercase().contains(&query))
+            })
+            .collect()
+    }
+
+    /// Returns packages ordered by installed size (largest first).
+    /// Packages with unknown size are placed at the end of the list
+    pub fn sort_by_size(&self) -> Vec<&Package> {
+        let mut packages = self.packages.iter().collect::<Vec<_>>();
+        packages.sort_by_key(|p| std::cmp::Reverse(p.installed_size));
+        packages
+    }
+
+    fn command(args: &[&str]) -> Result<String> {
         let pkglist = Command::new("/bin/env").args(args).output()?;
-        let pkglist_stdout = String::from_utf8(pkglist.stdout)?;
-        let mut packages = Vec::new();
+        Ok(String::from_utf8(pkglist.stdout)?)
+    }
+
+    fn get_deb_packages() -> Result<Vec<Package>> {
+        let status = read_to_string(PackageManager::DPKG_STATUS)?;
+        Ok(parse_dpkg_status(&status))
+    }
+
+    fn get_rpm_packages() -> Result<Vec<Package>> {
+        let pkglist = Self::command(&[
+            "rpm",
+            "-qa",
+            // The argument isn't passed through a shell, so it's not
+            // quoted: quotes would get into the output
+            "--queryformat=<RPM>\t%{NAME}\t%{VERSION}\t%{ARCH}\t%{SIZE}\t%{SUMMARY}\n",
+        ])?;
+
+        Ok(pkglist
+            .lines()
+            .filter_map(|line| Package::try_from(line.trim()).ok())
+            .collect())
+    }
 
-        for pkg_str in pkglist_stdout.lines().map(|line| line.trim()) {
-            let package = Package::try_from(pkg_str);
-            if let Ok(pkg) = package {
+    fn get_pacman_packages() -> Result<Vec<Package>> {
+        let mut packages = Vec::new();
+        for dir in read_dir(PackageManager::PACMAN_LOCAL)? {
+            let desc = dir?.path().join("desc");
+            if let Ok(desc) = read_to_string(desc)
+                && let Some(pkg) = parse_pacman_desc(&desc)
+            {
                 packages.push(pkg);
             }
         }
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
 
-        Ok(Self { packages })
+        Ok(packages)
     }
 
-    fn get_deb_packages() -> Result<Self> {
-        Self::command(&[
-            "dpkg-query",
-            "-W",
-            "-f='<DEB>\t${Package}\t${Version}\t${Architecture}\n",
-        ])
+    fn get_flatpak_packages() -> Result<Vec<Package>> {
+        let pkglist = Self::command(&[
+            "flatpak",
+            "list",
+            "--columns=application,version,arch,description",
+        ])?;
+
+        Ok(pkglist
+            .lines()
+            .filter_map(|line| {
+                let mut chunks = line.split('\t').map(|s| s.trim().to_string());
+                let name = chunks.next().filter(|name| !name.is_empty())?;
+                Some(Package {
+                    name,
+                    version: chunks.next().unwrap_or_default(),
+                    arch: chunks.next().unwrap_or_default(),
+                    pkg_type: PkgType::Flatpak,
+                    summary: chunks.next().filter(|s| !s.is_empty()),
+                    installed_size: None,
+                })
+            })
+            .collect())
     }
+}
 
-    fn get_rpm_packages() -> Result<Self> {
-        Self::command(&[
-            "rpm",
-            "-qa",
-            "--queryformat='<RPM>\t%{NAME}\t%{VERSION}\t%{ARCH}\n'",
-        ])
+/// Parses the contents of `/var/lib/dpkg/status` file
+fn parse_dpkg_status(status: &str) -> Vec<Package> {
+    let mut packages = Vec::new();
+
+    for stanza in status.split("\n\n") {
+        let (mut name, mut version, mut arch) = (None, None, None);
+        let (mut summary, mut installed_size) = (None, None);
+        let mut installed = false;
+
+        for line in stanza.lines() {
+            if let Some((key, val)) = line.split_once(": ") {
+                match key {
+                    "Package" => name = Some(val.trim().to_string()),
+                    "Version" => version = Some(val.trim().to_string()),
+                    "Architecture" => arch = Some(val.trim().to_string()),
+                    "Status" => installed = val.trim().ends_with(" installed"),
+                    "Description" => summary = Some(val.trim().to_string()),
+                    // Size in KiB
+                    "Installed-Size" => {
+                        installed_size = val.trim().parse::<u64>().ok().map(|s| s * 1024)
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if let (true, Some(name), Some(version)) = (installed, name, version) {
+            packages.push(Package {
+                name,
+                version,
+                arch: arch.unwrap_or_default(),
+                pkg_type: PkgType::Deb,
+                summary,
+                installed_size,
+            });
+        }
     }
+
+    packages
+}
+
+/// Parses the contents of `/var/lib/pacman/local/<pkg>/desc` file
+fn parse_pacman_desc(desc: &str) -> Option<Package> {
+    let (mut name, mut version, mut arch) = (None, None, None);
+    let (mut summary, mut size) = (None, None);
+    let mut lines = desc.lines();
+
+    while let Some(line) = lines.next() {
+        let field = match line.trim() {
+            "%NAME%" => &mut name,
+            "%VERSION%" => &mut version,
+            "%ARCH%" => &mut arch,
+            "%DESC%" => &mut summary,
+            "%SIZE%" => &mut size,
+            _ => continue,
+        };
+        *field = lines.next().map(|s| s.trim().to_string());
+    }
+
+    Some(Package {
+        name: name?,
+        version: version?,
+        arch: arch.unwrap_or_default(),
+        pkg_type: PkgType::Pacman,
+        summary,
+        installed_size: size.and_then(|s| s.parse().ok()),
+    })
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Package {
     pub name: String,
     pub version: String,
     pub arch: String,
     pub pkg_type: PkgType,
+
+    /// Short package description
+    pub summary: Option<String>,
+
+    /// Installed size (in bytes)
+    pub installed_size: Option<u64>,
 }
 
 impl TryFrom<&str> for Package {
@@ -187,7 +424,8 @@ impl TryFrom<&str> for Package {
 
     fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
         // dbg!(value);
-        let mut chunks = value.trim().split('\t').map(|s| s.trim().replace("'", ""));
+        // Apostrophes are kept since they may be a part of the summary
+        let mut chunks = value.trim().split('\t').map(|s| s.trim().to_string());
 
         match (chunks.next(), chunks.next(), chunks.next(), chunks.next()) {
             (Some(pkg), Some(name), Some(ver), Some(arch)) => Ok(Self {
@@ -195,6 +433,8 @@ impl TryFrom<&str> for Package {
                 name: name,
                 version: ver,
                 arch: arch,
+                installed_size: chunks.next().and_then(|size| size.parse().ok()),
+                summary: chunks.next().filter(|summary| !summary.is_empty()),
             }),
             _ => Err(anyhow!("String \"{value}\" has incorrect format!")),
         }
@@ -205,6 +445,72 @@ impl TryFrom<&str> for Package {
 mod tests {
     use super::*;
 
+    #[test]
+    fn dpkg_status_test() {
+        let status = "Package: bash
+Status: install ok installed
+Architecture: amd64
+Version: 5.2.15-2+b7
+
+Package: removed-pkg
+Status: deinstall ok config-files
+Architecture: all
+Version: 1.0
+";
+        let pkgs = parse_dpkg_status(status);
+        assert_eq!(pkgs.len(), 1);
+        assert_eq!(pkgs[0].name, "bash");
+        assert_eq!(pkgs[0].version, "5.2.15-2+b7");
+        assert_eq!(pkgs[0].arch, "amd64");
+    }
+
+    #[test]
+    fn pkg_search_test() {
+        let status = "Package: bash
+Status: install ok installed
+Installed-Size: 7000
+Version: 5.2.15-2+b7
+Description: GNU Bourne Again SHell
+
+Package: coreutils
+Status: install ok installed
+Installed-Size: 18000
+Version: 9.1-1
+Description: GNU core utilities
+";
+        let pkgs = InstalledPackages {
+            manager: PackageManager::Dpkg,
+            packages: parse_dpkg_status(status),
+        };
+        assert_eq!(pkgs.search("SHELL").len(), 1);
+        assert_eq!(pkgs.search("gnu").len(), 2);
+        assert_eq!(pkgs.search("").len(), 2);
+        assert_eq!(pkgs.sort_by_size()[0].name, "coreutils");
+        assert_eq!(pkgs.sort_by_size()[0].installed_size, Some(18000 * 1024));
+    }
+
+    #[test]
+    fn rpm_package_test() {
+        let pkg = Package::try_from(
+            "<RPM>\tbash\t5.2.26\tx86_64\t8314310\tThe GNU Bourne Again shell's\n",
+        )
+        .unwrap();
+        assert_eq!(pkg.name, "bash");
+        assert_eq!(pkg.arch, "x86_64");
+        assert_eq!(pkg.installed_size, Some(8314310));
+        assert_eq!(pkg.summary.as_deref(), Some("The GNU Bourne Again shell's"));
+        assert!(Package::try_from("<RPM>\tbash").is_err());
+    }
+
+    #[test]
+    fn pacman_desc_test() {
+        let desc = "%NAME%\nbash\n\n%VERSION%\n5.2.037-5\n\n%ARCH%\nx86_64\n";
+        let pkg = parse_pacman_desc(desc).unwrap();
+        assert_eq!(pkg.name, "bash");
+        assert_eq!(pkg.version, "5.2.037-5");
+        assert_eq!(pkg.arch, "x86_64");
+    }
+
     #[test]
     fn pkg_list_test() {
         let pkgs = InstalledPackages::get();
@@ -0,0 +1,157 @@
+/* summary.rs
+ *
+ * Copyright 2026 Michail Krasnov <mskrasnov07@ya.ru>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Lightweight one-glance snapshot of the system state
+//!
+//! Unlike [`crate::Ferrix`], [`Summary`] reads only a few cheap
+//! `/proc` and `/sys` files and doesn't need `root` permissions.
+//!
+//! ## Example
+//! ```no-test
+//! use ferrix_lib::summary::Summary;
+//! use ferrix_lib::traits::ToPlainText;
+//!
+//! let summary = Summary::new()?;
+//! println!("{}", summary.to_plain());
+//! ```
+
+use anyhow::Result;
+use serde::Serialize;
+use std::thread;
+
+use crate::{
+    battery::BatInfo,
+    cpu::{Processors, Stat, USAGE_SAMPLE_INTERVAL},
+    parts::FileSystemStats,
+    ram::RAM,
+    sys::{Kernel, Uptime, get_hostname},
+    traits::{ToJson, ToPlainText, print_opt_val, print_val},
+    utils::Size,
+};
+
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Summary {
+    pub hostname: Option<String>,
+
+    /// Kernel version
+    pub kernel: Option<String>,
+
+    /// Uptime in seconds
+    pub uptime: Option<f32>,
+
+    /// Model name of the first CPU
+    pub cpu_model: Option<String>,
+
+    /// Total CPU usage (in percents)
+    pub cpu_usage: Option<f32>,
+
+    pub mem_used: Size,
+    pub mem_total: Size,
+    pub swap_used: Size,
+    pub swap_total: Size,
+
+    /// Used space of the root (`/`) filesystem
+    pub root_used: Option<Size>,
+
+    /// Total size of the root (`/`) filesystem
+    pub root_total: Option<Size>,
+
+    /// Total charge of all batteries (in percents). `None` if there
+    /// is no battery
+    pub battery: Option<f32>,
+}
+
+impl Summary {
+    /// Gathers the snapshot. Blocks for [`USAGE_SAMPLE_INTERVAL`] to
+    /// compute the CPU usage
+    pub fn new() -> Result<Self> {
+        let prev = Stat::new().ok();
+
+        let ram = RAM::new()?;
+        let bytes = |size: Size| size.get_bytes2().unwrap_or(0);
+        let mem_total = bytes(ram.total);
+        let swap_total = bytes(ram.swap_total);
+        let root = FileSystemStats::from_path("/").ok();
+
+        let cpu_model = Processors::new()
+            .ok()
+            .and_then(|proc| proc.entries.into_iter().next())
+            .and_then(|cpu| cpu.model_name);
+
+        let cpu_usage = prev.and_then(|prev| {
+            thread::sleep(USAGE_SAMPLE_INTERVAL);
+            Stat::new().ok().map(|curr| curr.total_usage(&prev))
+        });
+
+        Ok(Self {
+            hostname: get_hostname(),
+            kernel: Kernel::new().ok().and_then(|kernel| kernel.version),
+            uptime: Uptime::new().ok().map(|uptime| uptime.0),
+            cpu_model,
+            cpu_usage,
+            mem_used: Size::B(mem_total.saturating_sub(bytes(ram.available))),
+            mem_total: Size::B(mem_total),
+            swap_used: Size::B(swap_total.saturating_sub(bytes(ram.swap_free))),
+            swap_total: Size::B(swap_total),
+            root_used: root.map(|fs| fs.used_size()),
+            root_total: root.map(|fs| fs.total_size()),
+            battery: BatInfo::new().ok().and_then(|bat| bat.total_percentage()),
+        })
+    }
+}
+
+impl ToJson for Summary {}
+
+impl ToPlainText for Summary {
+    fn to_plain(&self) -> String {
+        let used_total = |used: Size, total: Size| {
+            format!(
+                "{} / {}",
+                used.human(2).unwrap_or_default(),
+                total.human(2).unwrap_or_default()
+            )
+        };
+
+        let mut s = String::from("\nSystem summary\n");
+        s += &print_opt_val("Host name", &self.hostname);
+        s += &print_opt_val("Kernel", &self.kernel);
+        s += &print_opt_val(
+            "Uptime",
+            &self.uptime.map(|uptime| format!("{uptime:.0} seconds")),
+        );
+        s += &print_opt_val("CPU", &self.cpu_model);
+        s += &print_opt_val(
+            "CPU usage",
+            &self.cpu_usage.map(|usage| format!("{usage:.1}%")),
+        );
+        s += &print_val("Memory", &used_total(self.mem_used, self.mem_total));
+        s += &print_val("Swap", &used_total(self.swap_used, self.swap_total));
+        if let (Some(used), Some(total)) = (self.root_used, self.root_total) {
+            s += &print_val("Root filesystem", &used_total(used, total));
+        }
+        s += &print_opt_val(
+            "Battery",
+            &self.battery.map(|percent| format!("{percent:.0}%")),
+        );
+
+        s
+    }
+}
@@ -25,17 +25,29 @@ use crate::{traits::*, utils::Size};
 use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 use std::env::{var, vars};
+use std::fmt::Display;
+use std::fs::{read_dir, read_link};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
 /// A structure containing all collected information about
 /// installed system
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Sys {
     /// Machine ID
     pub machine_id: Option<String>,
 
-    /// Timezone
+    /// Timezone (e.g. `Europe/Moscow`)
     pub timezone: Option<String>,
 
+    /// Is the hardware clock (RTC) kept in UTC. `None` if the system
+    /// has no RTC (e.g. in containers)
+    pub rtc_utc: Option<bool>,
+
+    /// Wall-clock time when the system was booted
+    pub boot_time: Option<SystemTime>,
+
     /// Environment variables for current user
     pub env_vars: Vec<(String, String)>,
 
@@ -50,20 +62,34 @@ pub struct Sys {
 
     /// Host name
     pub hostname: Option<HostName>,
+
+    /// Kernel command line
+    pub cmdline: Option<String>,
+
+    /// Parsed kernel command line (boot parameters) in `key[=value]`
+    /// format
+    pub cmdline_params: Vec<(String, Option<String>)>,
     // /// Current locale
     // pub locale: Locale,
 }
 
 impl Sys {
     pub fn new() -> Result<Self> {
+        let uptime = Uptime::new()?;
         Ok(Self {
             machine_id: read_to_string("/etc/machine-id").ok(),
-            timezone: read_to_string("/etc/timezone").ok(),
+            timezone: get_timezone(),
+            rtc_utc: get_rtc_utc(),
+            boot_time: uptime.boot_time(),
             env_vars: get_env_vars(),
-            uptime: Uptime::new()?,
+            uptime,
             loadavg: LoadAVG::new()?,
             shells: get_shells()?,
             hostname: get_hostname(),
+            cmdline: get_cmdline(),
+            cmdline_params: get_cmdline()
+                .map(|cmdline| parse_cmdline(&cmdline))
+                .unwrap_or_default(),
             // locale: todo!(),
         })
     }
@@ -77,8 +103,32 @@ impl Sys {
 
 impl ToJson for Sys {}
 
+impl ToPlainText for Sys {
+    fn to_plain(&self) -> String {
+        let mut s = String::from("\nInformation about installed system\n");
+        s += &print_opt_val("Host name", &self.hostname);
+        s += &print_opt_val("Machine ID", &self.machine_id.as_ref().map(|id| id.trim()));
+        s += &print_opt_val("Timezone", &self.timezone);
+        s += &print_opt_val(
+            "RTC time",
+            &self.rtc_utc.map(|utc| if utc { "UTC" } else { "local" }),
+        );
+        s += &print_opt_val(
+            "Boot time",
+            &self.boot_time.and_then(crate::utils::local_time_string),
+        );
+        s += &print_val("Shells", &self.shells.join(", "));
+        s += &print_opt_val("Kernel command line", &self.cmdline);
+        s += &self.uptime.to_plain();
+        s += &self.loadavg.to_plain();
+
+        s
+    }
+}
+
 /// Information about Linux kernel
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Kernel {
     /// All data about kernel
     pub uname: Option<String>, // /proc/version
@@ -87,7 +137,7 @@ pub struct Kernel {
     pub cmdline: Option<String>, // /proc/cmdline
 
     /// Kernel architecture
-    pub arch: Option<String>, // /proc/sys/kernel/arch
+    pub arch: Option<String>, // /proc/sys/kernel/arch or uname(2)
 
     /// Kernel version
     pub version: Option<String>, // /proc/sys/kernel/osrelease
@@ -95,6 +145,17 @@ pub struct Kernel {
     /// Kernel build info
     pub build_info: Option<String>, // /proc/sys/kernel/version
 
+    /// Kernel build date (e.g. `Fri Aug 30 12:02:04 UTC 2024`)
+    pub build_date: Option<String>,
+
+    /// Compiler used to build the kernel (e.g. `gcc (GCC) 14.2.1
+    /// 20240910`)
+    pub compiler: Option<String>,
+
+    /// Is the kernel config available in `/proc/config.gz` or
+    /// `/boot/config-<version>`
+    pub has_config: bool,
+
     /// Max processes count
     pub pid_max: u32, // /proc/sys/kernel/pid_max
 
@@ -110,12 +171,29 @@ pub struct Kernel {
 
 impl Kernel {
     pub fn new() -> Result<Self> {
+        let uname = read_to_string("/proc/version").ok();
+        let version = read_to_string("/proc/sys/kernel/osrelease").ok();
+        let build_info = read_to_string("/proc/sys/kernel/version").ok();
+        let has_config = Path::new("/proc/config.gz").exists()
+            || version
+                .as_ref()
+                .is_some_and(|ver| Path::new(&format!("/boot/config-{ver}")).exists());
+
         Ok(Self {
-            uname: read_to_string("/proc/version").ok(),
+            compiler: uname.as_deref().and_then(parse_compiler),
+            build_date: build_info
+                .as_deref()
+                .or(uname.as_deref())
+                .and_then(parse_build_date),
+            uname,
             cmdline: read_to_string("/proc/cmdline").ok(),
-            arch: read_to_string("/proc/sys/kernel/arch").ok(),
-            version: read_to_string("/proc/sys/kernel/osrelease").ok(),
-            build_info: read_to_string("/proc/sys/kernel/version").ok(),
+            // `/proc/sys/kernel/arch` appeared only in Linux 6.1
+            arch: read_to_string("/proc/sys/kernel/arch")
+                .ok()
+                .or_else(uname_machine),
+            version,
+            build_info,
+            has_config,
             pid_max: read_to_string("/proc/sys/kernel/pid_max")?.parse()?,
             threads_max: read_to_string("/proc/sys/kernel/threads-max")?.parse()?,
             user_events_max: match read_to_string("/proc/sys/kernel/user_events_max").ok() {
@@ -132,10 +210,83 @@ impl Kernel {
 
 impl ToJson for Kernel {}
 
-/// Information about installed distro from `/etc/os-release`
+/// Returns top-level parenthesized groups of `/proc/version` (e.g.
+/// `linux@archlinux` and `gcc (GCC) 14.2.1, GNU ld 2.43.0`)
+fn paren_groups(version: &str) -> Vec<&str> {
+    let mut groups = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in version.char_indices() {
+        match c {
+            '(' => {
+                if depth == 0 {
+                    start = i + 1;
+                }
+                depth += 1;
+            }
+            ')' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    groups.push(&version[start..i]);
+                }
+            }
+            _ => {}
+        }
+    }
+    groups
+}
+
+/// Parses the compiler from `/proc/version`. The linker (e.g.
+/// `GNU ld ...`) is separated by a comma in modern kernels
+fn parse_compiler(version: &str) -> Option<String> {
+    let group = *paren_groups(version).get(1)?;
+    let mut depth = 0;
+    let end = group
+        .char_indices()
+        .find(|&(_, c)| {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            c == ',' && depth == 0
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(group.len());
+    let compiler = group[..end].trim();
+    (!compiler.is_empty()).then(|| compiler.to_string())
+}
+
+/// Parses the build date from `/proc/sys/kernel/version` (e.g.
+/// `#1 SMP PREEMPT_DYNAMIC Thu, 12 Sep 2024 17:21:02 +0000`). The
+/// date starts with the day of week
+fn parse_build_date(build_info: &str) -> Option<String> {
+    const DAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+    let day = build_info
+        .split_whitespace()
+        .find(|word| DAYS.contains(&word.trim_end_matches(',')))?;
+    // `day` is a subslice of `build_info`
+    let idx = day.as_ptr() as usize - build_info.as_ptr() as usize;
+    Some(build_info[idx..].trim().to_string())
+}
+
+/// Returns the machine hardware name from `uname(2)`
+fn uname_machine() -> Option<String> {
+    let mut uts: libc::utsname = unsafe { std::mem::zeroed() };
+    if unsafe { libc::uname(&mut uts) } != 0 {
+        return None;
+    }
+    let machine = unsafe { std::ffi::CStr::from_ptr(uts.machine.as_ptr()) };
+    Some(machine.to_string_lossy().to_string())
+}
+
+/// Information about installed distro from `/etc/os-release` (or
+/// `/usr/lib/os-release` if the first one is missing)
 ///
 /// > Information from *[freedesktop](https://www.freedesktop.org/software/systemd/man/249/os-release.html)* portal.
-#[derive(Debug, Serialize, Default, Clone)]
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct OsRelease {
     /// The operating system name without a version component
     ///
@@ -222,27 +373,107 @@ pub struct OsRelease {
     ///
     /// See [systemd-sysext(8)](https://www.freedesktop.org/software/systemd/man/249/systemd-sysext.html#) for more information
     pub sysext_level: Option<String>,
+
+    /// A suggested presentation color when showing the OS name on the
+    /// console (ANSI escape sequence parameters, e.g. `0;38;2;60;110;180`)
+    pub ansi_color: Option<String>,
 }
 
+/// `os-release` locations in the order of priority. `/etc/os-release`
+/// may be missing on immutable (e.g. `ostree`-based) distros
+const OS_RELEASE_PATHS: [&str; 2] = ["/etc/os-release", "/usr/lib/os-release"];
+
+/// Directories with distro logos (icons named by the `LOGO` key)
+const LOGO_DIRS: [&str; 4] = [
+    "/usr/share/pixmaps",
+    "/usr/share/icons/hicolor/scalable/apps",
+    "/usr/share/icons/hicolor/256x256/apps",
+    "/usr/share/icons/hicolor/128x128/apps",
+];
+
 impl OsRelease {
     pub fn new() -> Result<Self> {
-        let chunks = get_chunks_osrelease(read_to_string("/etc/os-release")?);
+        let contents = match read_to_string(OS_RELEASE_PATHS[0]) {
+            Ok(contents) => contents,
+            Err(_) => read_to_string(OS_RELEASE_PATHS[1])?,
+        };
         let mut osr = Self::default();
-        for chunk in chunks {
+        for chunk in get_chunks_osrelease(contents) {
             parse_osrelease(&mut osr, chunk);
         }
         Ok(osr)
     }
+
+    /// Returns the OS name or `Linux` if it isn't set
+    pub fn name(&self) -> &str {
+        if self.name.is_empty() {
+            "Linux"
+        } else {
+            &self.name
+        }
+    }
+
+    pub fn pretty_name(&self) -> Option<&str> {
+        self.pretty_name.as_deref()
+    }
+
+    pub fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Returns identifiers of the related distros (e.g. `["rhel",
+    /// "fedora"]` for CentOS)
+    pub fn id_like(&self) -> Vec<&str> {
+        match &self.id_like {
+            Some(id_like) => id_like.split_whitespace().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn home_url(&self) -> Option<&str> {
+        self.home_url.as_deref()
+    }
+
+    pub fn logo(&self) -> Option<&str> {
+        self.logo.as_deref()
+    }
+
+    pub fn ansi_color(&self) -> Option<&str> {
+        self.ansi_color.as_deref()
+    }
+
+    /// Searches the logo icon file (`*.svg` or `*.png`) in the
+    /// standard icons directories
+    pub fn logo_path(&self) -> Option<PathBuf> {
+        let logo = self.logo()?;
+        LOGO_DIRS.iter().find_map(|dir| {
+            ["svg", "png"]
+                .iter()
+                .map(|ext| Path::new(dir).join(format!("{logo}.{ext}")))
+                .find(|path| path.is_file())
+        })
+    }
 }
 
 impl ToJson for OsRelease {}
 
+/// Splits `os-release` lines into keys and values. Empty values are
+/// returned as `None`; values may contain `=` (e.g. in URLs)
 fn get_chunks_osrelease(contents: String) -> Vec<(Option<String>, Option<String>)> {
     contents
         .lines()
-        .map(|item| {
-            let mut items = item.split('=').map(sanitize_str);
-            (items.next(), items.next())
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, val)| {
+            let val = sanitize_str(val);
+            (
+                Some(sanitize_str(key)),
+                if val.is_empty() { None } else { Some(val) },
+            )
         })
         .collect::<Vec<_>>()
 }
@@ -273,6 +504,7 @@ fn parse_osrelease(osr: &mut OsRelease, chunk: (Option<String>, Option<String>))
                 "LOGO" => osr.logo = Some(val.to_string()),
                 "DEFAULT_HOSTNAME" => osr.default_hostname = Some(val.to_string()),
                 "SYSEXT_LEVEL" => osr.sysext_level = Some(val.to_string()),
+                "ANSI_COLOR" => osr.ansi_color = Some(val.to_string()),
                 _ => {}
             }
         }
@@ -281,7 +513,8 @@ fn parse_osrelease(osr: &mut OsRelease, chunk: (Option<String>, Option<String>))
 }
 
 /// System uptime
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Uptime(
     /// Uptime
     pub f32,
@@ -298,6 +531,11 @@ impl Uptime {
             _ => Err(anyhow!("`/proc/uptime` file format is incorrect!")),
         }
     }
+
+    /// Wall-clock time when the system was booted
+    pub fn boot_time(&self) -> Option<SystemTime> {
+        SystemTime::now().checked_sub(Duration::from_secs_f32(self.0))
+    }
 }
 
 impl ToPlainText for Uptime {
@@ -310,7 +548,8 @@ impl ToPlainText for Uptime {
 }
 
 /// System load (average)
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct LoadAVG(
     /// 1minute
     pub f32,
@@ -329,6 +568,23 @@ impl LoadAVG {
             _ => Err(anyhow!("`/proc/loadavg` file format is incorrect!")),
         }
     }
+
+    /// Returns the 1, 5 and 15 minutes load divided by the number of
+    /// logical CPUs. `1.0` means that all CPUs are busy
+    pub fn saturation(&self, logical_cpus: usize) -> (f64, f64, f64) {
+        let cpus = logical_cpus.max(1) as f64;
+        (
+            self.0 as f64 / cpus,
+            self.1 as f64 / cpus,
+            self.2 as f64 / cpus,
+        )
+    }
+
+    /// Are there more runnable tasks than logical CPUs (in the last
+    /// minute)
+    pub fn is_overloaded(&self, logical_cpus: usize) -> bool {
+        self.saturation(logical_cpus).0 > 1.0
+    }
 }
 
 impl ToPlainText for LoadAVG {
@@ -343,7 +599,8 @@ impl ToPlainText for LoadAVG {
 }
 
 /// Information about users
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Users {
     pub users: Vec<User>,
 }
@@ -351,21 +608,48 @@ pub struct Users {
 impl ToJson for Users {}
 
 impl Users {
+    /// Groups of the users aren't resolved here. Use
+    /// [`Users::resolve_groups()`] with already loaded [`Groups`]
     pub fn new() -> Result<Self> {
+        // Missing `/etc/shells` means that no shell is valid
+        let shells = get_shells().unwrap_or_default();
         let mut users = vec![];
         for user in read_to_string("/etc/passwd")?.lines() {
             match User::try_from(user) {
-                Ok(user) => users.push(user),
+                Ok(mut user) => {
+                    user.shell_is_valid = shells.contains(&user.login_shell);
+                    users.push(user);
+                }
                 Err(_) => continue,
             }
         }
 
         Ok(Self { users })
     }
+
+    /// Fills [`User::groups`] with the primary group and all groups
+    /// listing the user as a member
+    pub fn resolve_groups(&mut self, groups: &Groups) {
+        for user in &mut self.users {
+            user.groups = groups
+                .groups
+                .iter()
+                .filter(|group| group.gid == user.gid)
+                .chain(
+                    groups
+                        .groups
+                        .iter()
+                        .filter(|group| group.gid != user.gid && group.users.contains(&user.name)),
+                )
+                .map(|group| group.name.clone())
+                .collect();
+        }
+    }
 }
 
 /// Information about followed user
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct User {
     /// User's login name (case-sensitive, 1-32 characters)
     pub name: String,
@@ -401,6 +685,14 @@ pub struct User {
     /// If set to `/usr/sbin/nologin` or `/bin/false`, the user cannot
     /// log in
     pub login_shell: String,
+
+    /// Is the login shell listed in `/etc/shells`. `false` for service
+    /// accounts (e.g. with `nologin` shell)
+    pub shell_is_valid: bool,
+
+    /// Names of the groups of the user (the primary group goes first).
+    /// Empty until [`Users::resolve_groups()`] is called
+    pub groups: Vec<String>,
 }
 
 impl TryFrom<&str> for User {
@@ -426,6 +718,8 @@ impl TryFrom<&str> for User {
             },
             home_dir: sanitize_str(&chunks[5]),
             login_shell: sanitize_str(&chunks[6]),
+            shell_is_valid: false,
+            groups: Vec::new(),
         })
     }
 }
@@ -438,13 +732,17 @@ impl ToPlainText for User {
         s += &print_opt_val("GECOS", &self.gecos);
         s += &print_val("Home directory", &self.home_dir);
         s += &print_val("Login shell", &self.login_shell);
+        if !self.groups.is_empty() {
+            s += &print_val("Groups", &self.groups.join(", "));
+        }
 
         s
     }
 }
 
 /// Information about groups
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Groups {
     pub groups: Vec<Group>,
 }
@@ -465,7 +763,8 @@ impl Groups {
 }
 
 /// Information about followed group
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Group {
     /// Group name
     pub name: String,
@@ -507,6 +806,127 @@ impl TryFrom<&str> for Group {
     }
 }
 
+/// `utmp` files with currently logged in users
+const UTMP_PATHS: [&str; 2] = ["/run/utmp", "/var/run/utmp"];
+
+/// Login history
+const WTMP_PATH: &str = "/var/log/wtmp";
+
+/// Size of the glibc `struct utmp` record. It's the same on 32- and
+/// 64-bit systems because the login time is always stored as two
+/// 32-bit fields
+const UTMP_RECORD_SIZE: usize = 384;
+
+/// `ut_type` of the normal login session
+const USER_PROCESS: i16 = 7;
+
+/// Login sessions from `utmp` and `wtmp`
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Sessions {
+    /// Currently logged in users
+    pub active: Vec<Session>,
+
+    /// The last login of every user (newest first)
+    pub last_logins: Vec<Session>,
+}
+
+impl Sessions {
+    /// Unreadable `utmp` or `wtmp` (e.g. on systems without utmp
+    /// support or without read permissions) gives an empty list
+    pub fn new() -> Result<Self> {
+        let active = UTMP_PATHS
+            .iter()
+            .find_map(|path| std::fs::read(path).ok())
+            .map(|data| parse_utmp(&data))
+            .unwrap_or_default();
+
+        // `wtmp` is append-only, so later records override earlier ones
+        let mut last_logins: Vec<Session> = Vec::new();
+        for session in std::fs::read(WTMP_PATH)
+            .map(|data| parse_utmp(&data))
+            .unwrap_or_default()
+        {
+            match last_logins
+                .iter_mut()
+                .find(|last| last.user == session.user)
+            {
+                Some(last) => *last = session,
+                None => last_logins.push(session),
+            }
+        }
+        last_logins.sort_by_key(|l| std::cmp::Reverse(l.login_time));
+
+        Ok(Self {
+            active,
+            last_logins,
+        })
+    }
+}
+
+impl ToJson for Sessions {}
+
+/// Login session of the user
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Session {
+    /// User name
+    pub user: String,
+
+    /// Terminal name (e.g. `tty1`, `pts/0`)
+    pub tty: String,
+
+    /// Remote host. `None` for local sessions
+    pub host: Option<String>,
+
+    /// PID of the login process
+    pub pid: i32,
+    pub login_time: SystemTime,
+}
+
+impl Session {
+    /// Parses the `struct utmp` record. Returns `None` for records
+    /// which aren't user sessions (boot time, runlevel, etc.)
+    fn from_record(record: &[u8]) -> Option<Self> {
+        if record.len() < UTMP_RECORD_SIZE {
+            return None;
+        }
+        let i32_at = |offset: usize| {
+            i32::from_ne_bytes(record[offset..offset + 4].try_into().unwrap_or_default())
+        };
+
+        if i16::from_ne_bytes([record[0], record[1]]) != USER_PROCESS {
+            return None;
+        }
+        let user = utmp_str(&record[44..76]);
+        if user.is_empty() {
+            return None;
+        }
+        let host = utmp_str(&record[76..332]);
+        let secs = u64::try_from(i32_at(340)).ok()?;
+
+        Some(Self {
+            user,
+            tty: utmp_str(&record[8..40]),
+            host: (!host.is_empty()).then_some(host),
+            pid: i32_at(4),
+            login_time: SystemTime::UNIX_EPOCH + Duration::from_secs(secs),
+        })
+    }
+}
+
+fn parse_utmp(data: &[u8]) -> Vec<Session> {
+    data.chunks_exact(UTMP_RECORD_SIZE)
+        .filter_map(Session::from_record)
+        .collect()
+}
+
+/// Converts NUL-padded `utmp` string field
+fn utmp_str(field: &[u8]) -> String {
+    let len = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..len]).trim().to_string()
+}
+
 /// List of installed console shells
 pub type Shells = Vec<String>;
 
@@ -531,8 +951,196 @@ pub fn get_hostname() -> Option<HostName> {
     }
 }
 
+const SYSCTL_DIR: &str = "/proc/sys";
+
+/// Errors of reading kernel parameters from `/proc/sys`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SysctlError {
+    /// Incorrect key (e.g. empty or containing `..`)
+    InvalidKey(String),
+
+    /// There is no such kernel parameter
+    NotFound(String),
+
+    /// Not enough permissions to read this parameter
+    PermissionDenied(String),
+
+    /// Other I/O error
+    Io(String),
+}
+
+impl Display for SysctlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidKey(key) => write!(f, "Invalid sysctl key: \"{key}\""),
+            Self::NotFound(key) => write!(f, "Unknown sysctl key: \"{key}\""),
+            Self::PermissionDenied(key) => write!(f, "Permission denied to read \"{key}\""),
+            Self::Io(why) => write!(f, "Failed to read sysctl: {why}"),
+        }
+    }
+}
+
+impl std::error::Error for SysctlError {}
+
+impl SysctlError {
+    fn from_io(key: &str, err: std::io::Error) -> Self {
+        match err.kind() {
+            std::io::ErrorKind::NotFound => Self::NotFound(key.to_string()),
+            std::io::ErrorKind::PermissionDenied => Self::PermissionDenied(key.to_string()),
+            _ => Self::Io(err.to_string()),
+        }
+    }
+}
+
+/// Converts the dotted key (`net.ipv4.ip_forward`) to the path in
+/// `/proc/sys`. Like `sysctl(8)`, `/` in the key is treated as a dot
+/// in the file name (`net.ipv4.conf.enp0s3/100.forwarding`)
+fn sysctl_path(key: &str) -> Result<PathBuf, SysctlError> {
+    let key = key.trim();
+    let path = key
+        .chars()
+        .map(|c| match c {
+            '.' => '/',
+            '/' => '.',
+            c => c,
+        })
+        .collect::<String>();
+
+    if path.is_empty() || path.split('/').any(|part| part.is_empty() || part == "..") {
+        return Err(SysctlError::InvalidKey(key.to_string()));
+    }
+    Ok(Path::new(SYSCTL_DIR).join(path))
+}
+
+fn sysctl_key(path: &Path) -> Option<String> {
+    let rel = path.strip_prefix(SYSCTL_DIR).ok()?;
+    Some(
+        rel.to_string_lossy()
+            .chars()
+            .map(|c| match c {
+                '/' => '.',
+                '.' => '/',
+                c => c,
+            })
+            .collect(),
+    )
+}
+
+/// Reads the kernel parameter (e.g. `vm.swappiness`)
+pub fn sysctl(key: &str) -> Result<String, SysctlError> {
+    let path = sysctl_path(key)?;
+    std::fs::read_to_string(&path)
+        .map(|val| val.trim().to_string())
+        .map_err(|why| SysctlError::from_io(key, why))
+}
+
+/// Reads all kernel parameters in the subtree (e.g. `net.ipv4`).
+/// Unreadable (write-only or protected) parameters are skipped
+pub fn sysctl_all(prefix: &str) -> Result<Vec<(String, String)>, SysctlError> {
+    let root = if prefix.trim().is_empty() {
+        PathBuf::from(SYSCTL_DIR)
+    } else {
+        sysctl_path(prefix)?
+    };
+
+    if root.is_file() {
+        return Ok(vec![(prefix.trim().to_string(), sysctl(prefix)?)]);
+    }
+
+    let mut params = Vec::new();
+    let mut dirs = vec![root];
+    while let Some(dir) = dirs.pop() {
+        let entries = read_dir(&dir).map_err(|why| SysctlError::from_io(prefix, why))?;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if let Some(key) = sysctl_key(&path)
+                && let Ok(val) = std::fs::read_to_string(&path)
+            {
+                params.push((key, val.trim().to_string()));
+            }
+        }
+    }
+    params.sort_by(|a, b| a.0.cmp(&b.0));
+
+    Ok(params)
+}
+
+/// Timezone from `/etc/timezone` or the `/etc/localtime` symlink
+/// target (e.g. `/usr/share/zoneinfo/Europe/Moscow`)
+pub fn get_timezone() -> Option<String> {
+    if let Some(tz) = std::fs::read_to_string("/etc/timezone")
+        .ok()
+        .and_then(|tz| parse_timezone(&tz))
+    {
+        return Some(tz);
+    }
+
+    let target = read_link("/etc/localtime").ok()?;
+    let target = target.to_string_lossy();
+    target.split_once("zoneinfo/").map(|(_, tz)| tz.to_string())
+}
+
+/// `/etc/timezone` contains the timezone name followed by a newline
+fn parse_timezone(contents: &str) -> Option<String> {
+    let tz = contents.trim();
+    (!tz.is_empty()).then(|| tz.to_string())
+}
+
+/// Checks whether the hardware clock is kept in UTC (reads the third
+/// line of `/etc/adjtime`; UTC is the default if the file is absent)
+pub fn get_rtc_utc() -> Option<bool> {
+    if !Path::new("/sys/class/rtc/rtc0").exists() {
+        return None;
+    }
+
+    match read_to_string("/etc/adjtime") {
+        Ok(adjtime) => match adjtime.lines().nth(2).map(|l| l.trim()) {
+            Some("LOCAL") => Some(false),
+            _ => Some(true),
+        },
+        Err(_) => Some(true),
+    }
+}
+
+/// Kernel command line
+pub fn get_cmdline() -> Option<String> {
+    read_to_string("/proc/cmdline")
+        .ok()
+        .map(|cmdline| cmdline.trim().to_string())
+}
+
+/// Splits the kernel command line into `key[=value]` tokens. Values
+/// may be quoted (`key="some value"`)
+pub fn parse_cmdline(cmdline: &str) -> Vec<(String, Option<String>)> {
+    let mut params = Vec::new();
+    let mut token = String::new();
+    let mut in_quotes = false;
+
+    for c in cmdline.trim().chars().chain(std::iter::once(' ')) {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => {
+                if !token.is_empty() {
+                    params.push(match token.split_once('=') {
+                        Some((key, val)) => (key.to_string(), Some(val.to_string())),
+                        None => (token.clone(), None),
+                    });
+                    token.clear();
+                }
+            }
+            c => token.push(c),
+        }
+    }
+
+    params
+}
+
 /// Information about current locale
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Locale {}
 
 fn sanitize_str(s: &str) -> String {
@@ -541,6 +1149,7 @@ fn sanitize_str(s: &str) -> String {
 
 /// Linux kernel modules list
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct KModules {
     pub modules: Vec<Module>,
 }
@@ -552,14 +1161,50 @@ impl KModules {
         let mut modules = Vec::new();
 
         for s in contents {
-            modules.push(Module::try_from(s)?);
+            let mut module = Module::try_from(s)?;
+            module.parameters = module_parameters(&module.name);
+            modules.push(module);
         }
 
         Ok(Self { modules })
     }
+
+    /// Returns modules which use the `name` module
+    pub fn used_by(&self, name: &str) -> Vec<&Module> {
+        self.modules
+            .iter()
+            .find(|module| module.name == name)
+            .map(|module| {
+                self.modules
+                    .iter()
+                    .filter(|m| module.used_by.contains(&m.name))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Reads parameters of the module from `/sys/module/<name>/parameters/`.
+/// Values of some parameters are readable only by `root`
+fn module_parameters(name: &str) -> Vec<(String, Option<String>)> {
+    let Ok(dir) = read_dir(Path::new("/sys/module").join(name).join("parameters")) else {
+        return Vec::new();
+    };
+    let mut params = dir
+        .filter_map(|entry| entry.ok())
+        .map(|entry| {
+            (
+                entry.file_name().to_string_lossy().to_string(),
+                read_to_string(entry.path()).ok(),
+            )
+        })
+        .collect::<Vec<_>>();
+    params.sort_by(|a, b| a.0.cmp(&b.0));
+    params
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Module {
     /// The name of the loaded kernel module
     pub name: String,
@@ -570,9 +1215,8 @@ pub struct Module {
     /// Number of times the module is currently in use or loaded
     pub instances: usize,
 
-    /// A comma-separated list of other modules that this module
-    /// depends on
-    pub dependencies: String,
+    /// Other modules which use (depend on) this module
+    pub used_by: Vec<String>,
 
     /// The current state of the module
     pub state: String,
@@ -581,6 +1225,10 @@ pub struct Module {
     /// always be present or fully detailed depending on the kernel
     /// version and configuration)
     pub memory_addrs: String,
+
+    /// Module parameters and their values (`None` if the value isn't
+    /// readable)
+    pub parameters: Vec<(String, Option<String>)>,
 }
 
 impl TryFrom<&str> for Module {
@@ -599,7 +1247,7 @@ impl TryFrom<&str> for Module {
                 Some(name),
                 Some(size),
                 Some(instances),
-                Some(dependencies),
+                Some(used_by),
                 Some(state),
                 Some(memory_addrs),
             ) => {
@@ -610,9 +1258,17 @@ impl TryFrom<&str> for Module {
                     name: name.to_string(),
                     size: Size::B(size),
                     instances,
-                    dependencies: dependencies.to_string(),
+                    // "-" if the module isn't used by other modules.
+                    // The list ends with a comma: "nvidia_modeset,nvidia_uvm,"
+                    // and may contain flags (e.g. "[permanent]")
+                    used_by: used_by
+                        .split(',')
+                        .filter(|name| !name.is_empty() && *name != "-" && !name.starts_with('['))
+                        .map(|name| name.to_string())
+                        .collect(),
                     state: state.to_string(),
                     memory_addrs: memory_addrs.to_string(),
+                    parameters: Vec::new(),
                 })
             }
             _ => Err(anyhow!("Unknown field: \"{value}\"")),
@@ -635,3 +1291,134 @@ pub fn get_env_vars() -> Vec<(String, String)> {
     vars.sort_by_key(|v| v.0.clone());
     vars
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timezone_test() {
+        assert_eq!(
+            parse_timezone("Europe/Moscow\n"),
+            Some("Europe/Moscow".to_string())
+        );
+        assert_eq!(parse_timezone("\n"), None);
+    }
+
+    #[test]
+    fn utmp_test() {
+        let mut record = vec![0u8; UTMP_RECORD_SIZE];
+        record[0..2].copy_from_slice(&USER_PROCESS.to_ne_bytes());
+        record[4..8].copy_from_slice(&1234i32.to_ne_bytes());
+        record[8..13].copy_from_slice(b"pts/0");
+        record[44..48].copy_from_slice(b"user");
+        record[76..87].copy_from_slice(b"192.168.0.2");
+        record[340..344].copy_from_slice(&1_700_000_000i32.to_ne_bytes());
+
+        // Boot time record must be skipped
+        let mut boot = vec![0u8; UTMP_RECORD_SIZE];
+        boot[0..2].copy_from_slice(&2i16.to_ne_bytes());
+        boot[44..50].copy_from_slice(b"reboot");
+        record.extend(boot);
+
+        let sessions = parse_utmp(&record);
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].user, "user");
+        assert_eq!(sessions[0].tty, "pts/0");
+        assert_eq!(sessions[0].host.as_deref(), Some("192.168.0.2"));
+        assert_eq!(sessions[0].pid, 1234);
+        assert_eq!(
+            sessions[0].login_time,
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+        );
+    }
+
+    #[test]
+    fn module_test() {
+        let module = Module::try_from(
+            "nvidia 14381056 5 nvidia_modeset,nvidia_uvm, Live 0x0000000000000000 (POE)",
+        )
+        .unwrap();
+        assert_eq!(module.used_by, vec!["nvidia_modeset", "nvidia_uvm"]);
+
+        let module = Module::try_from("crc32_pclmul 12288 0 - Live 0x0000000000000000").unwrap();
+        assert!(module.used_by.is_empty());
+    }
+
+    #[test]
+    fn proc_version_test() {
+        let arch = "Linux version 6.10.10-arch1-1 (linux@archlinux) (gcc (GCC) 14.2.1 20240910, GNU ld (GNU Binutils) 2.43.0) #1 SMP PREEMPT_DYNAMIC Thu, 12 Sep 2024 17:21:02 +0000";
+        assert_eq!(
+            parse_compiler(arch).as_deref(),
+            Some("gcc (GCC) 14.2.1 20240910")
+        );
+        assert_eq!(
+            parse_build_date(arch).as_deref(),
+            Some("Thu, 12 Sep 2024 17:21:02 +0000")
+        );
+
+        let el7 = "Linux version 3.10.0-1160.el7.x86_64 (mockbuild@kbuilder) (gcc version 4.8.5 20150623 (Red Hat 4.8.5-44) (GCC) ) #1 SMP Mon Oct 19 16:18:59 UTC 2020";
+        assert_eq!(
+            parse_compiler(el7).as_deref(),
+            Some("gcc version 4.8.5 20150623 (Red Hat 4.8.5-44) (GCC)")
+        );
+        assert_eq!(
+            parse_build_date(el7).as_deref(),
+            Some("Mon Oct 19 16:18:59 UTC 2020")
+        );
+        assert_eq!(parse_compiler("Linux version 6.1.0"), None);
+    }
+
+    #[test]
+    fn osrelease_test() {
+        let contents = "# comment\nNAME=\"Fedora Linux\"\nVERSION_ID=\nHOME_URL=\"https://example.org/?a=b\"\nANSI_COLOR=\"0;38;2;60;110;180\"\nID_LIKE=\"rhel fedora\"\n";
+        let mut osr = OsRelease::default();
+        for chunk in get_chunks_osrelease(contents.to_string()) {
+            parse_osrelease(&mut osr, chunk);
+        }
+        assert_eq!(osr.name(), "Fedora Linux");
+        assert_eq!(osr.version_id(), None);
+        assert_eq!(osr.home_url(), Some("https://example.org/?a=b"));
+        assert_eq!(osr.ansi_color(), Some("0;38;2;60;110;180"));
+        assert_eq!(osr.id_like(), vec!["rhel", "fedora"]);
+        assert_eq!(OsRelease::default().name(), "Linux");
+    }
+
+    #[test]
+    fn cmdline_test() {
+        let param = |key: &str, val: Option<&str>| (key.to_string(), val.map(String::from));
+        let cases = [
+            ("", vec![]),
+            ("   \n", vec![]),
+            (
+                "quiet splash",
+                vec![param("quiet", None), param("splash", None)],
+            ),
+            (
+                "root=UUID=1234-abcd ro",
+                vec![param("root", Some("UUID=1234-abcd")), param("ro", None)],
+            ),
+            (
+                "acpi_osi=\"Windows 2020\"  nomodeset\n",
+                vec![
+                    param("acpi_osi", Some("Windows 2020")),
+                    param("nomodeset", None),
+                ],
+            ),
+            ("init=", vec![param("init", Some(""))]),
+        ];
+        for (cmdline, expected) in cases {
+            assert_eq!(parse_cmdline(cmdline), expected, "cmdline: {cmdline:?}");
+        }
+    }
+
+    #[test]
+    fn loadavg_saturation_test() {
+        let lavg = LoadAVG(6., 2., 1.);
+        assert_eq!(lavg.saturation(4), (1.5, 0.5, 0.25));
+        assert!(lavg.is_overloaded(4));
+        assert!(!lavg.is_overloaded(8));
+        // Unknown number of CPUs
+        assert_eq!(lavg.saturation(0).0, 6.);
+    }
+}
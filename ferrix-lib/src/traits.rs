@@ -43,6 +43,34 @@ pub fn print_val<T: Display>(param: &str, value: &T) -> String {
     format!("\t{param}: {value}\n")
 }
 
+/// A trait for converting structure data to Markdown for bug reports
+/// and forum posts
+pub trait ToMarkdown {
+    /// Convert structure fields to Markdown `String`
+    fn to_markdown(&self) -> String;
+}
+
+/// Header of the GitHub-style key/value table
+pub fn md_table_header() -> String {
+    String::from("| Parameter | Value |\n|---|---|\n")
+}
+
+fn md_escape(s: &str) -> String {
+    s.trim().replace('|', "\\|").replace('\n', " ")
+}
+
+pub fn md_opt_val<T: Display>(param: &str, value: &Option<T>) -> String {
+    if let Some(value) = value {
+        md_val(param, value)
+    } else {
+        String::new()
+    }
+}
+
+pub fn md_val<T: Display>(param: &str, value: &T) -> String {
+    format!("| {param} | {} |\n", md_escape(&value.to_string()))
+}
+
 /// A trate with functions for converting data from a structure or
 /// other object to the JSON format
 pub trait ToJson {
@@ -66,3 +94,20 @@ pub trait ToJson {
         Ok(s)
     }
 }
+
+/// A trait with functions for converting data from a structure or
+/// other object to the YAML format
+pub trait ToYaml {
+    /// Convert object data to YAML format
+    fn to_yaml(&self) -> Result<String>
+    where
+        Self: Serialize,
+    {
+        let s = serde_yaml::to_string(&self)?;
+        Ok(s)
+    }
+}
+
+/// Every type which can be converted to JSON can also be converted
+/// to YAML
+impl<T: ToJson + Serialize> ToYaml for T {}
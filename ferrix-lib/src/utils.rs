@@ -20,12 +20,17 @@
 
 //! Utilities and helpers
 
-use std::{fmt::Display, path::Path};
+use std::{
+    fmt::Display,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Copy)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum Size {
     B(u64),
     KB(f32),
@@ -114,6 +119,57 @@ impl Size {
             _ => None,
         }
     }
+
+    /// Returns human-readable size with binary units (KiB, MiB, ...)
+    /// and `precision` digits after the point. See [`fmt_bytes()`]
+    pub fn human(&self, precision: usize) -> Option<String> {
+        self.get_bytes2().map(|bytes| fmt_bytes(bytes, precision))
+    }
+
+    /// Returns human-readable size with decimal units (kB, MB, ...).
+    /// See [`fmt_bytes10()`]
+    pub fn human10(&self, precision: usize) -> Option<String> {
+        self.get_bytes2().map(|bytes| fmt_bytes10(bytes, precision))
+    }
+}
+
+/// Binary units used by [`fmt_bytes()`]
+const BIN_UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+/// Decimal (SI) units used by [`fmt_bytes10()`]
+const DEC_UNITS: [&str; 5] = ["B", "kB", "MB", "GB", "TB"];
+
+/// Formats the number of bytes with binary units (KiB, MiB, ...) and
+/// `precision` digits after the point. Values less than 1 KiB are
+/// printed in bytes without fractional part
+pub fn fmt_bytes(bytes: u64, precision: usize) -> String {
+    fmt_units(bytes, precision, 1024., &BIN_UNITS)
+}
+
+/// Formats the number of bytes with decimal units (kB, MB, ...) like
+/// disk vendors do. See [`fmt_bytes()`]
+pub fn fmt_bytes10(bytes: u64, precision: usize) -> String {
+    fmt_units(bytes, precision, 1000., &DEC_UNITS)
+}
+
+fn fmt_units(bytes: u64, precision: usize, div: f64, units: &[&str]) -> String {
+    let mut num = bytes as f64;
+    let mut unit = 0;
+    while num >= div && unit < units.len() - 1 {
+        num /= div;
+        unit += 1;
+    }
+    if unit == 0 {
+        return format!("{bytes} B");
+    }
+
+    // 1023.999 KiB should be printed as "1.00 MiB", not "1024.00 KiB"
+    let scale = 10f64.powi(precision as i32);
+    if (num * scale).round() / scale >= div && unit < units.len() - 1 {
+        num /= div;
+        unit += 1;
+    }
+    format!("{num:.precision$} {}", units[unit])
 }
 
 impl TryFrom<&str> for Size {
@@ -158,11 +214,47 @@ impl Display for Size {
     }
 }
 
+/// Reads the file or returns `None` if it is missing or can't be
+/// read (e.g. some `/proc` files are hidden in containers)
+pub fn read_optional<P: AsRef<Path>>(path: P) -> Option<String> {
+    std::fs::read_to_string(path).ok()
+}
+
 pub fn read_to_string<P: AsRef<Path>>(path: P) -> Result<String> {
     let c = std::fs::read_to_string(path)?.trim().to_string();
     Ok(c)
 }
 
+/// Returns the size of memory page in bytes
+pub fn page_size() -> u64 {
+    unsafe { libc::sysconf(libc::_SC_PAGESIZE) as u64 }
+}
+
+/// Returns the number of clock ticks per second (the unit of CPU
+/// times in `/proc`)
+pub fn clock_ticks() -> u64 {
+    unsafe { libc::sysconf(libc::_SC_CLK_TCK) as u64 }
+}
+
+/// Formats the time in the local timezone (`YYYY-MM-DD hh:mm:ss`)
+pub fn local_time_string(time: SystemTime) -> Option<String> {
+    let secs = time.duration_since(UNIX_EPOCH).ok()?.as_secs() as libc::time_t;
+    let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+    if unsafe { libc::localtime_r(&secs, &mut tm) }.is_null() {
+        return None;
+    }
+
+    Some(format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        tm.tm_year + 1900,
+        tm.tm_mon + 1,
+        tm.tm_mday,
+        tm.tm_hour,
+        tm.tm_min,
+        tm.tm_sec
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,4 +266,39 @@ mod tests {
         let s2 = Size::KB(1.);
         assert_eq!(s2.get_bytes2().unwrap(), 1024);
     }
+
+    #[test]
+    fn fmt_bytes_test() {
+        assert_eq!(fmt_bytes(0, 2), "0 B");
+        assert_eq!(fmt_bytes(1023, 2), "1023 B");
+        assert_eq!(fmt_bytes(1024, 2), "1.00 KiB");
+        assert_eq!(fmt_bytes(1536, 1), "1.5 KiB");
+        assert_eq!(fmt_bytes(1024 * 1024 - 1, 2), "1.00 MiB");
+        assert_eq!(fmt_bytes(3 * 1024u64.pow(3), 0), "3 GiB");
+        assert_eq!(fmt_bytes(2048 * 1024u64.pow(4), 2), "2048.00 TiB");
+        assert_eq!(Size::KB(8.).human(2).as_deref(), Some("8.00 KiB"));
+        assert_eq!(Size::None.human(2), None);
+    }
+
+    #[test]
+    fn fmt_bytes10_test() {
+        assert_eq!(fmt_bytes10(999, 2), "999 B");
+        assert_eq!(fmt_bytes10(1000, 2), "1.00 kB");
+        assert_eq!(fmt_bytes10(1024, 2), "1.02 kB");
+        assert_eq!(fmt_bytes10(999_999, 2), "1.00 MB");
+        assert_eq!(fmt_bytes10(500_107_862_016, 1), "500.1 GB");
+        // `Size::KB` is 1024 bytes as in `/proc/meminfo`
+        assert_eq!(Size::KB(8.).human10(2).as_deref(), Some("8.19 kB"));
+    }
+
+    #[test]
+    fn local_time_string_test() {
+        let time = local_time_string(UNIX_EPOCH + std::time::Duration::from_secs(86400 * 365));
+        assert_eq!(time.map(|t| t.len()), Some(19));
+    }
+
+    #[test]
+    fn read_optional_test() {
+        assert_eq!(read_optional("/nonexistent/ferrix/file"), None);
+    }
 }
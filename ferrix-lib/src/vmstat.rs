@@ -22,12 +22,13 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::fs::read_to_string;
 
 use crate::traits::ToJson;
+use crate::utils::{Size, page_size, read_optional};
 
 /// Virtual memory statistics
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct VmStat {
     pub nr_free_pages: Option<usize>,
     pub nr_zone_inactive_anon: Option<usize>,
@@ -203,8 +204,14 @@ pub struct VmStat {
 }
 
 impl VmStat {
+    /// Returns empty statistics if `/proc/vmstat` isn't available
     pub fn new() -> Result<Self> {
-        let chunks = read_to_string("/proc/vmstat")?;
+        Ok(read_optional("/proc/vmstat")
+            .map(|chunks| Self::from_str(&chunks))
+            .unwrap_or_default())
+    }
+
+    fn from_str(chunks: &str) -> Self {
         let chunks = chunks
             .lines()
             .map(|item| {
@@ -216,7 +223,10 @@ impl VmStat {
         for chunk in chunks {
             match chunk {
                 (Some(key), Some(val)) => {
-                    let value = val.parse::<usize>()?;
+                    // Malformed values are skipped
+                    let Ok(value) = val.parse::<usize>() else {
+                        continue;
+                    };
                     match key {
                         "nr_free_pages" => vmstat.nr_free_pages = Some(value),
                         "nr_zone_inactive_anon" => vmstat.nr_zone_inactive_anon = Some(value),
@@ -421,8 +431,344 @@ impl VmStat {
                 _ => {}
             }
         }
-        Ok(vmstat)
+        vmstat
+    }
+
+    /// Returns only the commonly useful figures. Page counts are
+    /// converted to bytes
+    pub fn summary(&self) -> VmStatSummary {
+        let ps = page_size();
+        let bytes = |pages: Option<usize>| pages.map(|p| Size::B(p as u64 * ps));
+
+        VmStatSummary {
+            free: bytes(self.nr_free_pages),
+            anon_pages: bytes(self.nr_anon_pages),
+            file_pages: bytes(self.nr_file_pages),
+            dirty: bytes(self.nr_dirty),
+            writeback: bytes(self.nr_writeback),
+            swap_in: bytes(self.pswpin),
+            swap_out: bytes(self.pswpout),
+            major_faults: self.pgmajfault,
+            oom_kill: self.oom_kill,
+        }
     }
 }
 
 impl ToJson for VmStat {}
+
+/// The most useful virtual memory statistics
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VmStatSummary {
+    /// Free memory
+    pub free: Option<Size>,
+
+    /// Anonymous memory (not backed by files)
+    pub anon_pages: Option<Size>,
+
+    /// Page cache
+    pub file_pages: Option<Size>,
+
+    /// Memory waiting to get written back to the disk
+    pub dirty: Option<Size>,
+
+    /// Memory which is actively being written back to the disk
+    pub writeback: Option<Size>,
+
+    /// Total amount of data swapped in since boot
+    pub swap_in: Option<Size>,
+
+    /// Total amount of data swapped out since boot
+    pub swap_out: Option<Size>,
+
+    /// Number of major page faults since boot
+    pub major_faults: Option<usize>,
+
+    /// Number of times the OOM killer was invoked
+    pub oom_kill: Option<usize>,
+}
+
+impl ToJson for VmStatSummary {}
+
+/// Generates [`VmStatDelta`], [`VmStatRate`] and the methods for
+/// computing them, so the list of fields is written only once
+macro_rules! impl_vmstat_delta {
+    ($($field:ident),* $(,)?) => {
+        /// Difference between two [`VmStat`] snapshots
+        ///
+        /// Counters like `pgfault` or `pswpin` are only meaningful as
+        /// rates, so use [`VmStatDelta::per_second()`] to get them
+        #[derive(Debug, Serialize, Deserialize, Default, Clone)]
+        #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+        pub struct VmStatDelta {
+            $(pub $field: Option<usize>,)*
+        }
+
+        /// Per-second rates of the [`VmStat`] counters
+        #[derive(Debug, Serialize, Deserialize, Default, Clone)]
+        #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+        pub struct VmStatRate {
+            $(pub $field: Option<f64>,)*
+        }
+
+        impl VmStat {
+            /// Computes difference between `self` (current snapshot)
+            /// and `prev` (previous snapshot)
+            ///
+            /// The field is `None` when it is missing in either
+            /// snapshot. Values which went down (e.g. after a counter
+            /// reset) give `0`
+            pub fn delta(&self, prev: &VmStat) -> VmStatDelta {
+                VmStatDelta {
+                    $($field: match (self.$field, prev.$field) {
+                        (Some(cur), Some(prev)) => Some(cur.saturating_sub(prev)),
+                        _ => None,
+                    },)*
+                }
+            }
+        }
+
+        impl VmStatDelta {
+            /// Converts the delta to per-second rates. `secs` is the
+            /// time elapsed between two snapshots
+            pub fn per_second(&self, secs: f64) -> VmStatRate {
+                if secs <= 0. {
+                    return VmStatRate::default();
+                }
+                VmStatRate {
+                    $($field: self.$field.map(|d| d as f64 / secs),)*
+                }
+            }
+        }
+
+        impl ToJson for VmStatDelta {}
+        impl ToJson for VmStatRate {}
+    };
+}
+
+impl_vmstat_delta!(
+    nr_free_pages,
+    nr_zone_inactive_anon,
+    nr_zone_active_anon,
+    nr_zone_inactive_file,
+    nr_zone_active_file,
+    nr_zone_unevictable,
+    nr_zone_write_pending,
+    nr_mlock,
+    nr_bounce,
+    nr_zspages,
+    nr_free_cma,
+    numa_hit,
+    numa_miss,
+    numa_foreign,
+    numa_interleave,
+    numa_local,
+    numa_other,
+    nr_inactive_anon,
+    nr_active_anon,
+    nr_inactive_file,
+    nr_active_file,
+    nr_unevictable,
+    nr_slab_reclaimable,
+    nr_slab_unreclaimable,
+    nr_isolated_anon,
+    nr_isolated_file,
+    workingset_nodes,
+    workingset_refault_anon,
+    workingset_activate_anon,
+    workingset_activate_file,
+    workingset_restore_anon,
+    workingset_restore_file,
+    workingset_nodereclaim,
+    nr_anon_pages,
+    nr_mapped,
+    nr_file_pages,
+    nr_dirty,
+    nr_writeback,
+    nr_writeback_temp,
+    nr_shmem,
+    nr_shmem_hugepages,
+    nr_shmem_pmdmapped,
+    nr_file_hugepages,
+    nr_file_pmdmapped,
+    nr_anon_transparent_hugepages,
+    nr_vmscan_write,
+    nr_vmscan_immediate_reclaim,
+    nr_dirtied,
+    nr_written,
+    nr_throttled_written,
+    nr_kernel_misc_reclaimable,
+    nr_foll_pin_acquired,
+    nr_foll_pin_released,
+    nr_kernel_stack,
+    nr_page_table_pages,
+    nr_sec_page_table_pages,
+    nr_swapcached,
+    pgpromote_success,
+    pgpromote_candidate,
+    nr_dirty_threshold,
+    nr_dirty_background_threshold,
+    pgpgin,
+    pgpgout,
+    pswpin,
+    pswpout,
+    pgalloc_dma,
+    pgalloc_dma32,
+    pgalloc_normal,
+    pgalloc_movable,
+    pgalloc_device,
+    allocstall_dma,
+    allocstall_dma32,
+    allocstall_normal,
+    allocstall_movable,
+    allocstall_device,
+    pgskip_dma,
+    pgskip_dma32,
+    pgskip_normal,
+    pgskip_movable,
+    pgskip_device,
+    pgfree,
+    pgactivate,
+    pgdeactivate,
+    pglazyfree,
+    pgfault,
+    pgmajfault,
+    pglazyfreed,
+    pgrefill,
+    pgreuse,
+    pgsteal_kswapd,
+    pgsteal_direct,
+    pgdemote_kswapd,
+    pgdemote_direct,
+    pgscan_kswapd,
+    pgscan_direct,
+    pgscan_direct_throttle,
+    pgscan_anon,
+    pgscan_file,
+    pgsteal_anon,
+    pgsteal_file,
+    zone_reclaim_failed,
+    pginodesteal,
+    slabs_scanned,
+    kswapd_inodesteal,
+    kswapd_low_wmark_hit_quickly,
+    kswapd_high_wmark_hit_quickly,
+    pageoutrun,
+    pgrotated,
+    drop_pagecache,
+    drop_slab,
+    oom_kill,
+    numa_pte_updates,
+    numa_huge_pte_updates,
+    numa_hint_faults,
+    numa_hint_faults_local,
+    numa_pages_migrated,
+    pgmigrate_success,
+    pgmigrate_fail,
+    thp_migration_success,
+    thp_migration_fail,
+    thp_migration_split,
+    compact_migrate_scanned,
+    compact_free_scanned,
+    compact_isolated,
+    compact_stall,
+    compact_fail,
+    compact_success,
+    compact_daemon_wake,
+    compact_daemon_migrate_scanned,
+    compact_daemon_free_scanned,
+    htlb_buddy_alloc_success,
+    htlb_buddy_alloc_fail,
+    unevictable_pgs_culled,
+    unevictable_pgs_scanned,
+    unevictable_pgs_rescued,
+    unevictable_pgs_mlocked,
+    unevictable_pgs_munlocked,
+    unevictable_pgs_cleared,
+    unevictable_pgs_stranded,
+    thp_fault_alloc,
+    thp_fault_fallback,
+    thp_fault_fallback_charge,
+    thp_collapse_alloc,
+    thp_collapse_alloc_failed,
+    thp_file_alloc,
+    thp_file_fallback,
+    thp_file_mapped,
+    thp_split_page,
+    thp_split_page_failed,
+    thp_deferred_split_page,
+    thp_split_pmd,
+    thp_scan_exceed_none_pte,
+    thp_scan_exceed_swap_pte,
+    thp_scan_exceed_share_pte,
+    thp_split_pud,
+    thp_zero_page_alloc,
+    thp_zero_page_alloc_failed,
+    thp_swpout,
+    thp_swpout_fallback,
+    balloon_inflate,
+    balloon_deflate,
+    balloon_migrate,
+    swap_ra,
+    swap_ra_hit,
+    ksm_swpin_copy,
+    cow_ksm,
+    zswpin,
+    zswpout,
+    direct_map_level2_splits,
+    direct_map_level3_splits,
+    nr_unstable,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncated_vmstat_test() {
+        let vmstat = VmStat::from_str("nr_free_pages 1024\nnr_mlock oops\npgmajfault");
+        assert_eq!(vmstat.nr_free_pages, Some(1024));
+        assert_eq!(vmstat.nr_mlock, None);
+        assert_eq!(vmstat.pgmajfault, None);
+    }
+
+    #[test]
+    fn delta_test() {
+        let prev = VmStat::from_str("pgfault 1000\npgmajfault 10\noom_kill 5\npswpin 7");
+        let curr = VmStat::from_str("pgfault 1600\npgmajfault 10\noom_kill 2\npswpout 3");
+
+        let delta = curr.delta(&prev);
+        assert_eq!(delta.pgfault, Some(600));
+        assert_eq!(delta.pgmajfault, Some(0));
+        // The counter went down, so the delta is saturated at 0
+        assert_eq!(delta.oom_kill, Some(0));
+        // Missing in either snapshot
+        assert_eq!(delta.pswpin, None);
+        assert_eq!(delta.pswpout, None);
+
+        let rate = delta.per_second(2.);
+        assert_eq!(rate.pgfault, Some(300.));
+        assert_eq!(rate.pswpin, None);
+        assert_eq!(delta.per_second(0.).pgfault, None);
+    }
+
+    #[test]
+    fn summary_test() {
+        let vmstat = VmStat::from_str(
+            "nr_free_pages 100\nnr_anon_pages 20\nnr_dirty 3\npswpout 4\npgmajfault 42\noom_kill 1",
+        );
+        let ps = page_size();
+        let summary = vmstat.summary();
+        let bytes = |size: Option<Size>| size.and_then(|size| size.get_bytes2());
+
+        assert_eq!(bytes(summary.free), Some(100 * ps));
+        assert_eq!(bytes(summary.anon_pages), Some(20 * ps));
+        assert_eq!(bytes(summary.dirty), Some(3 * ps));
+        assert_eq!(bytes(summary.swap_out), Some(4 * ps));
+        assert!(summary.file_pages.is_none());
+        assert!(summary.swap_in.is_none());
+        // Counters of events aren't converted to bytes
+        assert_eq!(summary.major_faults, Some(42));
+        assert_eq!(summary.oom_kill, Some(1));
+    }
+}
@@ -26,12 +26,10 @@ use std::fs::{read_dir, read_to_string};
 
 use crate::traits::ToJson;
 
-pub type Name = String;
-pub type Description = String;
-
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Vulnerabilities {
-    pub list: Vec<(Name, Description)>,
+    pub list: Vec<Vuln>,
 }
 
 const VULN_FILES_DIR: &str = "/sys/devices/system/cpu/vulnerabilities/";
@@ -49,13 +47,89 @@ impl Vulnerabilities {
                 };
                 let description = read_to_string(&file)?;
 
-                list.push((name.to_string(), description));
+                list.push(Vuln::new(&name, &description));
             }
         }
-        list.sort_by_key(|l| l.0.clone());
+        list.sort_by_key(|l| l.name.clone());
 
         Ok(Self { list })
     }
+
+    /// Returns vulnerabilities which aren't mitigated
+    pub fn affected(&self) -> Vec<&Vuln> {
+        self.list
+            .iter()
+            .filter(|vuln| vuln.status == Status::Vulnerable)
+            .collect()
+    }
 }
 
 impl ToJson for Vulnerabilities {}
+
+/// Information about one CPU vulnerability
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Vuln {
+    /// Vulnerability name (e.g. `retbleed`)
+    pub name: String,
+
+    /// Full text reported by the kernel (e.g. `Mitigation: PTI`)
+    pub description: String,
+
+    /// Parsed vulnerability status
+    pub status: Status,
+}
+
+impl Vuln {
+    pub fn new(name: &str, description: &str) -> Self {
+        let description = description.trim();
+        Self {
+            name: name.trim().to_string(),
+            description: description.to_string(),
+            status: Status::from(description),
+        }
+    }
+
+    /// Mitigation text without the `Mitigation: ` prefix (e.g. `PTI`)
+    pub fn mitigation(&self) -> Option<&str> {
+        match self.status {
+            Status::Mitigated => self
+                .description
+                .split_once(':')
+                .map(|(_, mitigation)| mitigation.trim()),
+            _ => None,
+        }
+    }
+}
+
+/// Status of the CPU vulnerability
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Status {
+    /// The CPU is not affected by this vulnerability
+    NotAffected,
+
+    /// The CPU is affected and the vulnerability isn't mitigated
+    Vulnerable,
+
+    /// The CPU is affected but the kernel mitigates the vulnerability
+    Mitigated,
+
+    /// The kernel can't determine the status (e.g. depends on hypervisor)
+    Unknown,
+}
+
+impl From<&str> for Status {
+    fn from(value: &str) -> Self {
+        let value = value.trim().to_lowercase();
+        if value.starts_with("not affected") {
+            Self::NotAffected
+        } else if value.starts_with("mitigation") {
+            Self::Mitigated
+        } else if value.starts_with("vulnerable") {
+            Self::Vulnerable
+        } else {
+            Self::Unknown
+        }
+    }
+}